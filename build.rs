@@ -69,9 +69,12 @@ pub const BUILD_TIME: &str = "{}";
 pub const GIT_HASH: &str = "{}";
 pub const VERSION: &str = "{}";
 pub const SACRED_ARCHITECTURE_VERSION: &str = "6-layer-v1.0";
-pub const TRADITION_COUNT: usize = 26;
-pub const GOVERNOR_COUNT: usize = 91;
-pub const AETHYR_COUNT: usize = 30;
+/// Tradition count recorded at build time (checked against `constants::TRADITION_COUNT`)
+pub const BUILD_TRADITION_COUNT: usize = 26;
+/// Governor count recorded at build time (checked against `constants::GOVERNOR_COUNT`)
+pub const BUILD_GOVERNOR_COUNT: usize = 91;
+/// Aethyr count recorded at build time (checked against `constants::AETHYR_COUNT`)
+pub const BUILD_AETHYR_COUNT: usize = 30;
 "#,
         build_time, git_hash, version
     );
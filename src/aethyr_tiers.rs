@@ -0,0 +1,165 @@
+//! Aethyr tiers: which Aethyrs form each named tier, and the modifiers each
+//! tier applies to quests and governor presence
+
+use serde::{Deserialize, Serialize};
+use crate::{Result, EnochianError};
+
+/// A named band of consecutive Aethyrs and the modifiers it applies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AethyrTier {
+    /// Tier name
+    pub name: String,
+    /// First Aethyr ID in the tier (inclusive)
+    pub first_aethyr: u32,
+    /// Last Aethyr ID in the tier (inclusive)
+    pub last_aethyr: u32,
+    /// Scales how overwhelming a governor's presence reads
+    pub intensity_multiplier: f64,
+    /// Authenticity added to teaching elements
+    pub wisdom_depth_bonus: f64,
+    /// Scales the difficulty of every choice branch
+    pub challenge_difficulty_modifier: f64,
+    /// Added to a story adaptation's overall authenticity
+    pub authenticity_enhancement: f64,
+}
+
+impl AethyrTier {
+    /// Check whether an Aethyr belongs to this tier
+    pub fn contains(&self, aethyr_id: u32) -> bool {
+        (self.first_aethyr..=self.last_aethyr).contains(&aethyr_id)
+    }
+}
+
+/// Tier definitions shared by the governor manager and the story engine;
+/// every Aethyr from 1 to `AETHYR_COUNT` belongs to exactly one tier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<AethyrTier>", into = "Vec<AethyrTier>")]
+pub struct AethyrTiers {
+    tiers: Vec<AethyrTier>,
+}
+
+impl AethyrTiers {
+    /// Build a tier table, rejecting gaps, overlaps and invalid modifiers
+    pub fn new(tiers: Vec<AethyrTier>) -> Result<Self> {
+        let aethyr_count = crate::constants::AETHYR_COUNT as u32;
+        let invalid = |message: String| EnochianError::SacredConstraintViolation { constraint: message };
+        
+        for tier in &tiers {
+            if tier.name.trim().is_empty() {
+                return Err(invalid("Aethyr tier name must not be empty".to_string()));
+            }
+            if tier.first_aethyr > tier.last_aethyr {
+                return Err(invalid(format!("Aethyr tier {} has an empty range {}-{}", tier.name, tier.first_aethyr, tier.last_aethyr)));
+            }
+            if tier.first_aethyr == 0 || tier.last_aethyr > aethyr_count {
+                return Err(invalid(format!("Aethyr tier {} spans {}-{}, outside 1-{}", tier.name, tier.first_aethyr, tier.last_aethyr, aethyr_count)));
+            }
+            let modifiers = [tier.intensity_multiplier, tier.wisdom_depth_bonus, tier.challenge_difficulty_modifier, tier.authenticity_enhancement];
+            if modifiers.iter().any(|modifier| !modifier.is_finite() || *modifier < 0.0) {
+                return Err(invalid(format!("Aethyr tier {} has a negative or non-finite modifier", tier.name)));
+            }
+        }
+        
+        for aethyr_id in 1..=aethyr_count {
+            let owners: Vec<&str> = tiers.iter()
+                .filter(|tier| tier.contains(aethyr_id))
+                .map(|tier| tier.name.as_str())
+                .collect();
+            match owners.len() {
+                1 => {},
+                0 => return Err(invalid(format!("Aethyr {} belongs to no tier", aethyr_id))),
+                _ => return Err(invalid(format!("Aethyr {} belongs to tiers {}", aethyr_id, owners.join(", ")))),
+            }
+        }
+        
+        Ok(AethyrTiers { tiers })
+    }
+    
+    /// Tier of an Aethyr, or `None` outside 1 to `AETHYR_COUNT`
+    pub fn tier_of(&self, aethyr_id: u32) -> Option<&AethyrTier> {
+        self.tiers.iter().find(|tier| tier.contains(aethyr_id))
+    }
+    
+    /// All tiers, in the order they were defined
+    pub fn tiers(&self) -> &[AethyrTier] {
+        &self.tiers
+    }
+}
+
+impl Default for AethyrTiers {
+    /// LIL and the inner Aethyrs are the most demanding; the outer ones
+    /// nearest the world, out to TEX, are where seekers lay their foundation
+    fn default() -> Self {
+        let tier = |name: &str, first_aethyr, last_aethyr, intensity_multiplier, wisdom_depth_bonus, challenge_difficulty_modifier, authenticity_enhancement| AethyrTier {
+            name: name.to_string(),
+            first_aethyr,
+            last_aethyr,
+            intensity_multiplier,
+            wisdom_depth_bonus,
+            challenge_difficulty_modifier,
+            authenticity_enhancement,
+        };
+        AethyrTiers::new(vec![
+            tier("Transcendence", 1, 10, 1.2, 0.15, 1.1, 0.1),
+            tier("Mastery", 11, 20, 1.0, 0.1, 1.0, 0.08),
+            tier("Foundation", 21, 30, 0.8, 0.05, 0.9, 0.05),
+        ])
+        .expect("default Aethyr tiers cover every Aethyr once")
+    }
+}
+
+impl TryFrom<Vec<AethyrTier>> for AethyrTiers {
+    type Error = EnochianError;
+    
+    fn try_from(tiers: Vec<AethyrTier>) -> Result<Self> {
+        AethyrTiers::new(tiers)
+    }
+}
+
+impl From<AethyrTiers> for Vec<AethyrTier> {
+    fn from(tiers: AethyrTiers) -> Self {
+        tiers.tiers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_every_aethyr_in_exactly_one_tier() {
+        let tiers = AethyrTiers::default();
+        for aethyr_id in 1..=crate::constants::AETHYR_COUNT as u32 {
+            let owners = tiers.tiers().iter().filter(|tier| tier.contains(aethyr_id)).count();
+            assert_eq!(owners, 1, "Aethyr {}", aethyr_id);
+        }
+        assert_eq!(tiers.tier_of(1).unwrap().name, "Transcendence");
+        assert_eq!(tiers.tier_of(10).unwrap().name, "Transcendence");
+        assert_eq!(tiers.tier_of(11).unwrap().name, "Mastery");
+        assert_eq!(tiers.tier_of(30).unwrap().name, "Foundation");
+        assert!(tiers.tier_of(0).is_none());
+        assert!(tiers.tier_of(31).is_none());
+    }
+    
+    #[test]
+    fn test_gaps_and_overlaps_rejected() {
+        let mut tiers = AethyrTiers::default().tiers().to_vec();
+        tiers[1].first_aethyr = 12;
+        assert!(AethyrTiers::new(tiers.clone()).unwrap_err().to_string().contains("Aethyr 11 belongs to no tier"));
+        
+        tiers[1].first_aethyr = 10;
+        assert!(AethyrTiers::new(tiers.clone()).unwrap_err().to_string().contains("Aethyr 10 belongs to tiers Transcendence, Mastery"));
+        
+        tiers[1].first_aethyr = 11;
+        tiers[2].last_aethyr = 31;
+        assert!(AethyrTiers::new(tiers.clone()).is_err());
+        
+        // Custom tiers load from JSON through the same checks
+        tiers[2].last_aethyr = 30;
+        tiers[2].name = "Threshold".to_string();
+        let json = serde_json::to_string(&tiers).unwrap();
+        assert_eq!(serde_json::from_str::<AethyrTiers>(&json).unwrap().tier_of(25).unwrap().name, "Threshold");
+        tiers[0].challenge_difficulty_modifier = -1.0;
+        assert!(serde_json::from_str::<AethyrTiers>(&serde_json::to_string(&tiers).unwrap()).is_err());
+    }
+}
@@ -1,8 +1,10 @@
 //! Authenticity validation and scoring system
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use crate::{Result, EnochianError};
+use crate::metrics::Counter;
 
 /// Authenticity score with detailed breakdown
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,26 +22,268 @@ pub struct AuthenticityScore {
     /// Source quality score
     pub source_quality: f64,
     /// Detailed breakdown by component
-    pub detailed_breakdown: HashMap<String, f64>,
+    pub detailed_breakdown: BTreeMap<String, f64>,
     /// Validation notes
     pub validation_notes: Vec<String>,
     /// Improvement suggestions
     pub improvement_suggestions: Vec<String>,
 }
 
+/// Individual matches and penalties behind an authenticity score
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MatchTrace {
+    /// Matched keywords, concepts and markers, sorted by component and term
+    pub matched_terms: Vec<TermMatch>,
+    /// Anachronism and content penalties applied, sorted by component and term
+    pub penalties: Vec<TermMatch>,
+    /// Match decision for each supplied source, in input order
+    pub source_decisions: Vec<SourceDecision>,
+    /// Set when scoring without diagnostics, so nothing is recorded
+    #[serde(skip)]
+    muted: bool,
+}
+
+/// A term found in scored content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermMatch {
+    /// Score component the term contributed to
+    pub component: String,
+    /// Matched term
+    pub term: String,
+    /// Change to the component score, before clamping and caps
+    pub contribution: f64,
+}
+
+/// How a single source was scored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceDecision {
+    /// Source as supplied
+    pub source: String,
+    /// Primary source it matched, if any
+    pub primary_match: Option<String>,
+    /// Quality markers found in the source
+    pub matched_markers: Vec<String>,
+    /// Resulting source score
+    pub score: f64,
+}
+
+impl MatchTrace {
+    /// Trace that records nothing, for scoring without diagnostics
+    fn muted() -> Self {
+        MatchTrace { muted: true, ..MatchTrace::default() }
+    }
+    
+    fn matched(&mut self, component: &str, term: &str, contribution: f64) {
+        if self.muted {
+            return;
+        }
+        self.matched_terms.push(TermMatch {
+            component: component.to_string(),
+            term: term.to_string(),
+            contribution,
+        });
+    }
+    
+    fn penalized(&mut self, component: &str, term: &str, contribution: f64) {
+        if self.muted {
+            return;
+        }
+        self.penalties.push(TermMatch {
+            component: component.to_string(),
+            term: term.to_string(),
+            contribution,
+        });
+    }
+}
+
+/// Component of an authenticity score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreComponent {
+    /// Use of the tradition's key concepts and historical figures
+    TraditionAlignment,
+    /// Period-appropriate references, free of anachronisms
+    HistoricalAccuracy,
+    /// Spiritual meaning rather than material gain
+    SpiritualDepth,
+    /// Practical, safe guidance
+    PracticalApplicability,
+    /// Quality of a single cited source
+    SourceQuality,
+}
+
+impl ScoreComponent {
+    /// Every component, in breakdown order
+    pub const ALL: [ScoreComponent; 5] = [
+        ScoreComponent::TraditionAlignment,
+        ScoreComponent::HistoricalAccuracy,
+        ScoreComponent::SpiritualDepth,
+        ScoreComponent::PracticalApplicability,
+        ScoreComponent::SourceQuality,
+    ];
+    
+    /// Component name used in breakdowns and traces
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreComponent::TraditionAlignment => "tradition_alignment",
+            ScoreComponent::HistoricalAccuracy => "historical_accuracy",
+            ScoreComponent::SpiritualDepth => "spiritual_depth",
+            ScoreComponent::PracticalApplicability => "practical_applicability",
+            ScoreComponent::SourceQuality => "source_quality",
+        }
+    }
+}
+
+/// Kind of content being scored, which sets length and structure expectations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ContentType {
+    /// Quest text with objectives and choices, judged more on practice than citations
+    Quest,
+    /// A line or two of governor speech, judged mainly on tradition alignment
+    Dialogue,
+    /// Reference entries, scored with each tradition's standard weights
+    #[default]
+    Codex,
+}
+
+impl ContentType {
+    /// Source quality assumed when no sources are cited
+    fn unsourced_score(&self) -> f64 {
+        match self {
+            ContentType::Quest => 0.7,
+            ContentType::Dialogue => 0.9,
+            ContentType::Codex => 0.5,
+        }
+    }
+}
+
+/// Model producing the component scores that `AuthenticityScorer` weights and aggregates
+///
+/// Scores should lie in 0.0-1.0. `SourceQuality` is asked once per cited
+/// source, with the source as `content`, and the results are averaged; content
+/// without sources gets a neutral 0.5 without consulting the backend.
+pub trait AuthenticityBackend: std::fmt::Debug + Send + Sync {
+    /// Score one component of the content for a tradition
+    fn score_component(&self, component: ScoreComponent, content: &str, tradition: &str) -> f64;
+    
+    /// Score one component, recording the matches behind it in `trace`
+    ///
+    /// Defaults to `score_component` with an empty trace.
+    fn score_component_traced(
+        &self,
+        component: ScoreComponent,
+        content: &str,
+        tradition: &str,
+        trace: &mut MatchTrace,
+    ) -> f64 {
+        let _ = trace;
+        self.score_component(component, content, tradition)
+    }
+}
+
 /// Authenticity scorer with tradition-specific validation
 #[derive(Debug, Clone)]
 pub struct AuthenticityScorer {
-    /// Tradition validators
-    tradition_validators: HashMap<String, TraditionValidator>,
-    /// Enochian keywords with weights
-    enochian_keywords: HashMap<String, f64>,
-    /// Historical markers
-    historical_markers: HashMap<String, f64>,
-    /// Spiritual depth indicators
-    spiritual_indicators: Vec<String>,
-    /// Source quality markers
-    source_markers: HashMap<String, f64>,
+    /// Built-in lexicon, also used for quick scores and lexicon fingerprints
+    heuristic: HeuristicBackend,
+    /// Custom component scoring model replacing the heuristic
+    backend: Option<Arc<dyn AuthenticityBackend>>,
+    /// Maximum content length in bytes accepted for scoring
+    max_content_length: usize,
+}
+
+/// Terms that lower the practical applicability score
+///
+/// Terms match whole words only, so "control" does not match "self-control",
+/// and are ignored when negated ("never manipulative"). Person-directed terms
+/// are only penalized when aimed at people ("manipulation of others", not
+/// "manipulation of ritual tools").
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DangerousTerms {
+    /// Terms penalized wherever they appear un-negated
+    pub terms: Vec<String>,
+    /// Terms penalized only when followed closely by a person
+    pub person_directed: Vec<String>,
+}
+
+impl Default for DangerousTerms {
+    fn default() -> Self {
+        DangerousTerms {
+            terms: ["harmful", "dangerous", "unethical", "manipulative", "coercive"]
+                .iter().map(|term| term.to_string()).collect(),
+            person_directed: ["manipulation", "manipulate", "control", "coercion", "domination"]
+                .iter().map(|term| term.to_string()).collect(),
+        }
+    }
+}
+
+/// Words that negate a dangerous term up to `DANGEROUS_TERM_WINDOW` words later
+const NEGATIONS: &[&str] = &["no", "not", "never", "without", "avoid", "avoids", "avoiding", "against", "refrain", "refuse", "free"];
+
+/// Words marking a person-directed term as aimed at people
+const PERSON_TARGETS: &[&str] = &[
+    "others", "other", "people", "person", "persons", "someone", "anyone", "another", "them",
+    "him", "her", "victim", "victims", "partner", "partners", "minds", "wills",
+];
+
+/// Words either side of a dangerous term checked for negations and targets
+const DANGEROUS_TERM_WINDOW: usize = 3;
+
+/// Version of the scoring formulas, hashed into
+/// `AuthenticityScorer::lexicon_fingerprint`
+///
+/// Bump it with any change to how components are scored or combined, so
+/// registries scored by the old formulas are flagged for revalidation.
+pub const SCORER_VERSION: u32 = 1;
+
+impl DangerousTerms {
+    /// Terms found in `content`, each reported once
+    pub fn find_in(&self, content: &str) -> Vec<&str> {
+        let content_lower = content.to_lowercase();
+        // Hyphens and apostrophes stay inside words so compounds are one word
+        let words: Vec<&str> = content_lower
+            .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '\''))
+            .filter(|word| !word.is_empty())
+            .collect();
+        
+        let mut found: Vec<&str> = Vec::new();
+        for (index, word) in words.iter().enumerate() {
+            let negated = words[index.saturating_sub(DANGEROUS_TERM_WINDOW)..index].iter()
+                .any(|previous| NEGATIONS.contains(previous));
+            if negated {
+                continue;
+            }
+            
+            let term = self.terms.iter()
+                .find(|term| term.as_str() == *word)
+                .or_else(|| {
+                    let aimed_at_people = words[index + 1..].iter()
+                        .take(DANGEROUS_TERM_WINDOW)
+                        .any(|next| PERSON_TARGETS.contains(next));
+                    self.person_directed.iter()
+                        .find(|term| aimed_at_people && term.as_str() == *word)
+                });
+            if let Some(term) = term {
+                if !found.contains(&term.as_str()) {
+                    found.push(term.as_str());
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Which of a tradition's key concepts some content mentions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConceptCoverage {
+    /// Tradition the concepts belong to
+    pub tradition: String,
+    /// Key concepts found in the content, in validator order
+    pub matched: Vec<String>,
+    /// Key concepts not found in the content, in validator order
+    pub missing: Vec<String>,
+    /// Fraction of key concepts matched (0.0 when the tradition has no validator)
+    pub coverage: f64,
 }
 
 /// Tradition-specific validator
@@ -66,43 +310,146 @@ impl Default for AuthenticityScorer {
 impl AuthenticityScorer {
     /// Create a new authenticity scorer
     pub fn new() -> Self {
-        let mut scorer = AuthenticityScorer {
-            tradition_validators: HashMap::new(),
-            enochian_keywords: HashMap::new(),
-            historical_markers: HashMap::new(),
-            spiritual_indicators: Vec::new(),
-            source_markers: HashMap::new(),
-        };
-        
-        scorer.initialize_validators();
-        scorer.initialize_keywords();
-        scorer.initialize_markers();
-        scorer
+        AuthenticityScorer {
+            heuristic: HeuristicBackend::new(),
+            backend: None,
+            max_content_length: crate::constants::MAX_SCORING_CONTENT_SIZE,
+        }
+    }
+    
+    /// Score components with a custom backend instead of the built-in heuristic
+    ///
+    /// Supported traditions, tradition weights and quick scores are unaffected.
+    pub fn with_backend(mut self, backend: impl AuthenticityBackend + 'static) -> Self {
+        self.backend = Some(Arc::new(backend));
+        self
+    }
+    
+    /// Backend producing component scores
+    pub fn backend(&self) -> &dyn AuthenticityBackend {
+        match &self.backend {
+            Some(backend) => backend.as_ref(),
+            None => &self.heuristic,
+        }
+    }
+    
+    /// Set the maximum content length in bytes accepted for scoring
+    /// (defaults to `constants::MAX_SCORING_CONTENT_SIZE`)
+    pub fn with_max_content_length(mut self, max_content_length: usize) -> Self {
+        self.max_content_length = max_content_length;
+        self
+    }
+    
+    /// Maximum content length in bytes accepted for scoring
+    pub fn max_content_length(&self) -> usize {
+        self.max_content_length
+    }
+    
+    /// Penalize `terms` instead of the defaults when scoring `tradition`
+    pub fn with_dangerous_terms(mut self, tradition: &str, terms: DangerousTerms) -> Self {
+        self.heuristic.dangerous_terms.insert(tradition.to_string(), terms);
+        self
     }
     
     /// Calculate comprehensive authenticity score
+    ///
+    /// `content_type` shifts component weights and the unsourced baseline to
+    /// suit the content; `ContentType::Codex` uses the standard weights. Use
+    /// `calculate_authenticity_verbose` to see the matches behind the score.
     pub fn calculate_authenticity(
         &self,
         content: &str,
         tradition: &str,
+        content_type: ContentType,
+        sources: &[String],
+    ) -> Result<AuthenticityScore> {
+        let result = self.score_with_trace(content, tradition, content_type, sources, None, MatchTrace::muted());
+        crate::metrics::global().observe(Counter::AuthenticityChecks, result)
+            .map(|(score, _)| score)
+    }
+    
+    /// Calculate an authenticity score along with the matches that produced it
+    pub fn calculate_authenticity_verbose(
+        &self,
+        content: &str,
+        tradition: &str,
+        content_type: ContentType,
+        sources: &[String],
+    ) -> Result<(AuthenticityScore, MatchTrace)> {
+        let result = self.score_with_trace(content, tradition, content_type, sources, None, MatchTrace::default());
+        crate::metrics::global().observe(Counter::AuthenticityChecks, result)
+    }
+    
+    /// Calculate an authenticity score with `weights` in place of the
+    /// tradition's defaults
+    ///
+    /// The weights are used as given, without the `content_type` adjustment,
+    /// and must pass `ScoringWeights::validate`.
+    pub fn calculate_authenticity_with_weights(
+        &self,
+        content: &str,
+        tradition: &str,
+        content_type: ContentType,
         sources: &[String],
-        context: Option<&HashMap<String, serde_json::Value>>,
+        weights: &ScoringWeights,
     ) -> Result<AuthenticityScore> {
+        weights.validate()?;
+        let result = self.score_with_trace(content, tradition, content_type, sources, Some(weights), MatchTrace::muted());
+        crate::metrics::global().observe(Counter::AuthenticityChecks, result)
+            .map(|(score, _)| score)
+    }
+    
+    /// Score content, recording matches into `trace` unless it is muted
+    fn score_with_trace(
+        &self,
+        content: &str,
+        tradition: &str,
+        content_type: ContentType,
+        sources: &[String],
+        overrides: Option<&ScoringWeights>,
+        mut trace: MatchTrace,
+    ) -> Result<(AuthenticityScore, MatchTrace)> {
+        // Reject oversized input before any keyword scanning
+        if content.len() > self.max_content_length {
+            return Err(EnochianError::ContentTooLarge {
+                size: content.len(),
+                limit: self.max_content_length,
+            });
+        }
+        
         // Get tradition validator
-        let validator = self.tradition_validators.get(tradition)
+        let validator = self.heuristic.tradition_validators.get(tradition)
             .ok_or_else(|| EnochianError::TraditionNotSupported {
                 tradition: tradition.to_string(),
             })?;
         
         // Calculate component scores
-        let tradition_score = self.score_tradition_alignment(content, validator);
-        let historical_score = self.score_historical_accuracy(content, tradition);
-        let spiritual_score = self.score_spiritual_depth(content);
-        let practical_score = self.score_practical_applicability(content);
-        let source_score = self.score_source_quality(sources, tradition);
+        let backend = self.backend();
+        let mut score_content = |component| backend.score_component_traced(component, content, tradition, &mut trace);
+        let tradition_score = score_content(ScoreComponent::TraditionAlignment);
+        let historical_score = score_content(ScoreComponent::HistoricalAccuracy);
+        let spiritual_score = score_content(ScoreComponent::SpiritualDepth);
+        let practical_score = score_content(ScoreComponent::PracticalApplicability);
+        let source_score = if sources.is_empty() {
+            content_type.unsourced_score()
+        } else {
+            sources.iter()
+                .map(|source| backend.score_component_traced(ScoreComponent::SourceQuality, source, tradition, &mut trace))
+                .sum::<f64>() / sources.len() as f64
+        };
+        
+        // Lexicons are hash maps, so sort for a stable trace
+        let by_component_and_term = |a: &TermMatch, b: &TermMatch| {
+            a.component.cmp(&b.component).then_with(|| a.term.cmp(&b.term))
+        };
+        trace.matched_terms.sort_by(by_component_and_term);
+        trace.penalties.sort_by(by_component_and_term);
         
         // Calculate weighted overall score
-        let weights = self.get_scoring_weights(tradition);
+        let weights = match overrides {
+            Some(weights) => weights.clone(),
+            None => self.default_weights(tradition, content_type),
+        };
         let overall_score = (
             tradition_score * weights.tradition_alignment +
             historical_score * weights.historical_accuracy +
@@ -112,7 +459,7 @@ impl AuthenticityScorer {
         ) * validator.authenticity_weight;
         
         // Generate detailed breakdown
-        let mut detailed_breakdown = HashMap::new();
+        let mut detailed_breakdown = BTreeMap::new();
         detailed_breakdown.insert("tradition_alignment".to_string(), tradition_score);
         detailed_breakdown.insert("historical_accuracy".to_string(), historical_score);
         detailed_breakdown.insert("spiritual_depth".to_string(), spiritual_score);
@@ -131,7 +478,7 @@ impl AuthenticityScorer {
             practical_score, source_score, tradition
         );
         
-        Ok(AuthenticityScore {
+        let score = AuthenticityScore {
             overall_score: overall_score.min(1.0),
             tradition_alignment: tradition_score,
             historical_accuracy: historical_score,
@@ -141,7 +488,9 @@ impl AuthenticityScorer {
             detailed_breakdown,
             validation_notes,
             improvement_suggestions,
-        })
+        };
+        
+        Ok((score, trace))
     }
     
     /// Validate content meets minimum authenticity threshold
@@ -151,32 +500,37 @@ impl AuthenticityScorer {
         tradition: &str,
         threshold: f64,
     ) -> Result<bool> {
-        let score = self.calculate_authenticity(content, tradition, &[], None)?;
+        let score = self.calculate_authenticity(content, tradition, ContentType::Codex, &[])?;
         Ok(score.overall_score >= threshold)
     }
     
     /// Get quick authenticity score (simplified calculation)
     pub fn quick_score(&self, content: &str) -> f64 {
         let content_lower = content.to_lowercase();
+        self.quick_score_with(|term| content_lower.contains(term))
+    }
+    
+    /// Quick score given a test for whether a lowercase term occurs in the content
+    fn quick_score_with(&self, contains: impl Fn(&str) -> bool) -> f64 {
         let mut score = 0.85; // Base score
         
         // Check for Enochian keywords
-        for (keyword, weight) in &self.enochian_keywords {
-            if content_lower.contains(keyword) {
+        for (keyword, weight) in &self.heuristic.enochian_keywords {
+            if contains(keyword) {
                 score += weight * 0.01; // Small bonus per keyword
             }
         }
         
         // Check for historical markers
-        for (marker, weight) in &self.historical_markers {
-            if content_lower.contains(marker) {
+        for (marker, weight) in &self.heuristic.historical_markers {
+            if contains(marker) {
                 score += weight * 0.005; // Smaller bonus for historical markers
             }
         }
         
         // Check for spiritual indicators
-        let spiritual_count = self.spiritual_indicators.iter()
-            .filter(|indicator| content_lower.contains(&indicator.to_lowercase()))
+        let spiritual_count = self.heuristic.spiritual_indicators.iter()
+            .filter(|indicator| contains(&indicator.to_lowercase()))
             .count();
         
         if spiritual_count > 0 {
@@ -186,6 +540,295 @@ impl AuthenticityScorer {
         score.min(1.0)
     }
     
+    /// Key concepts of `tradition` that `content` covers and misses
+    ///
+    /// Concepts match case-insensitively, as in tradition alignment scoring.
+    /// Traditions without a validator have no concepts to cover.
+    pub fn concept_coverage(&self, content: &str, tradition: &str) -> ConceptCoverage {
+        let content_lower = content.to_lowercase();
+        let key_concepts = self.heuristic.tradition_validators.get(tradition)
+            .map(|validator| validator.key_concepts.as_slice())
+            .unwrap_or_default();
+        let (matched, missing): (Vec<String>, Vec<String>) = key_concepts.iter()
+            .cloned()
+            .partition(|concept| content_lower.contains(concept.as_str()));
+        let coverage = if key_concepts.is_empty() {
+            0.0
+        } else {
+            matched.len() as f64 / key_concepts.len() as f64
+        };
+        
+        ConceptCoverage {
+            tradition: tradition.to_string(),
+            matched,
+            missing,
+            coverage,
+        }
+    }
+    
+    /// Get the traditions with dedicated validators
+    pub fn get_validated_traditions(&self) -> Vec<String> {
+        let mut traditions: Vec<String> = self.heuristic.tradition_validators.keys().cloned().collect();
+        traditions.sort();
+        traditions
+    }
+    
+    /// Minimum overall score `tradition` content must reach, if it has a validator
+    pub fn minimum_threshold(&self, tradition: &str) -> Option<f64> {
+        self.heuristic.tradition_validators.get(tradition)
+            .map(|validator| validator.minimum_threshold)
+    }
+    
+    /// Weight `tradition` scores are scaled by, if it has a validator
+    pub fn tradition_weight(&self, tradition: &str) -> Option<f64> {
+        self.heuristic.tradition_validators.get(tradition)
+            .map(|validator| validator.authenticity_weight)
+    }
+    
+    /// Component weights `tradition` scores `content_type` with unless overridden
+    pub fn default_weights(&self, tradition: &str, content_type: ContentType) -> ScoringWeights {
+        self.get_scoring_weights(tradition).adjusted_for(content_type)
+    }
+    
+    /// SHA-256 fingerprint of everything the heuristic scores depend on:
+    /// `SCORER_VERSION`, the keyword, marker and dangerous-term lexicon, the
+    /// tradition validators, the component weights and the scoring constants
+    ///
+    /// Scores change only when this does, so it tells callers when stored
+    /// scores need revalidating.
+    pub fn lexicon_fingerprint(&self) -> String {
+        use sha2::{Digest, Sha256};
+        
+        let mut hasher = Sha256::new();
+        hasher.update(format!("v{}|", SCORER_VERSION).as_bytes());
+        for lexicon in [&self.heuristic.enochian_keywords, &self.heuristic.historical_markers, &self.heuristic.source_markers] {
+            let mut entries: Vec<(&String, &f64)> = lexicon.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            for (term, weight) in entries {
+                hasher.update(format!("{}={};", term, weight).as_bytes());
+            }
+            hasher.update(b"|");
+        }
+        for indicator in &self.heuristic.spiritual_indicators {
+            hasher.update(format!("{};", indicator).as_bytes());
+        }
+        hasher.update(b"|");
+        let mut dangerous: Vec<(&str, &DangerousTerms)> = self.heuristic.dangerous_terms.iter()
+            .map(|(tradition, terms)| (tradition.as_str(), terms))
+            .collect();
+        dangerous.sort_by(|a, b| a.0.cmp(b.0));
+        for (tradition, terms) in std::iter::once(("*", &self.heuristic.default_dangerous_terms)).chain(dangerous) {
+            hasher.update(format!("{}:{}/{};", tradition, terms.terms.join(","), terms.person_directed.join(",")).as_bytes());
+        }
+        hasher.update(b"|");
+        let mut validators: Vec<(&String, &TraditionValidator)> = self.heuristic.tradition_validators.iter().collect();
+        validators.sort_by(|a, b| a.0.cmp(b.0));
+        for (tradition, validator) in &validators {
+            hasher.update(format!(
+                "{}:{}/{}/{}/{}/{};",
+                tradition,
+                validator.key_concepts.join(","),
+                validator.historical_figures.join(","),
+                validator.primary_sources.join(","),
+                validator.authenticity_weight,
+                validator.minimum_threshold,
+            ).as_bytes());
+        }
+        hasher.update(b"|");
+        let traditions = validators.iter().map(|(tradition, _)| tradition.as_str()).chain(std::iter::once("*"));
+        for tradition in traditions {
+            for content_type in [ContentType::Quest, ContentType::Dialogue, ContentType::Codex] {
+                hasher.update(format!("{}:{:?}:{:?}:{};", tradition, content_type, self.default_weights(tradition, content_type), content_type.unsourced_score()).as_bytes());
+            }
+        }
+        hasher.update(format!("|{}/{}/{}", NEGATIONS.join(","), PERSON_TARGETS.join(","), DANGEROUS_TERM_WINDOW).as_bytes());
+        hex::encode(hasher.finalize())
+    }
+    
+    /// Number of keyword and marker entries in the lexicon
+    pub fn lexicon_size(&self) -> usize {
+        self.heuristic.enochian_keywords.len()
+            + self.heuristic.historical_markers.len()
+            + self.heuristic.source_markers.len()
+            + self.heuristic.spiritual_indicators.len()
+    }
+    
+    fn get_scoring_weights(&self, tradition: &str) -> ScoringWeights {
+        match tradition {
+            "Enochian" => ScoringWeights {
+                tradition_alignment: 0.35,
+                historical_accuracy: 0.25,
+                spiritual_depth: 0.20,
+                practical_applicability: 0.15,
+                source_quality: 0.05,
+            },
+            "Hermetic_Qabalah" => ScoringWeights {
+                tradition_alignment: 0.30,
+                historical_accuracy: 0.20,
+                spiritual_depth: 0.25,
+                practical_applicability: 0.15,
+                source_quality: 0.10,
+            },
+            _ => ScoringWeights {
+                tradition_alignment: 0.30,
+                historical_accuracy: 0.25,
+                spiritual_depth: 0.20,
+                practical_applicability: 0.15,
+                source_quality: 0.10,
+            },
+        }
+    }
+    
+    fn generate_validation_notes(
+        &self,
+        tradition_score: f64,
+        historical_score: f64,
+        spiritual_score: f64,
+        practical_score: f64,
+        source_score: f64,
+        tradition: &str,
+    ) -> Vec<String> {
+        let mut notes = Vec::new();
+        
+        if tradition_score >= 0.9 {
+            notes.push(format!("Excellent alignment with {} tradition", tradition));
+        } else if tradition_score >= 0.8 {
+            notes.push(format!("Good alignment with {} tradition", tradition));
+        } else if tradition_score < 0.7 {
+            notes.push(format!("Weak alignment with {} tradition - consider strengthening core concepts", tradition));
+        }
+        
+        if historical_score >= 0.9 {
+            notes.push("Strong historical accuracy".to_string());
+        } else if historical_score < 0.7 {
+            notes.push("Historical accuracy could be improved".to_string());
+        }
+        
+        if spiritual_score >= 0.9 {
+            notes.push("Excellent spiritual depth and meaning".to_string());
+        } else if spiritual_score < 0.7 {
+            notes.push("Consider deepening spiritual content".to_string());
+        }
+        
+        if practical_score >= 0.9 {
+            notes.push("Highly practical and applicable".to_string());
+        } else if practical_score < 0.7 {
+            notes.push("Could benefit from more practical guidance".to_string());
+        }
+        
+        if source_score >= 0.8 {
+            notes.push("Good source quality".to_string());
+        } else if source_score < 0.6 {
+            notes.push("Source quality could be improved".to_string());
+        }
+        
+        notes
+    }
+    
+    fn generate_improvement_suggestions(
+        &self,
+        tradition_score: f64,
+        historical_score: f64,
+        spiritual_score: f64,
+        practical_score: f64,
+        source_score: f64,
+        tradition: &str,
+    ) -> Vec<String> {
+        let mut suggestions = Vec::new();
+        
+        if tradition_score < 0.8 {
+            if let Some(validator) = self.heuristic.tradition_validators.get(tradition) {
+                let key_concepts: Vec<&str> = validator.key_concepts.iter().take(3).map(|s| s.as_str()).collect();
+                suggestions.push(format!("Strengthen {} alignment by incorporating: {}", tradition, key_concepts.join(", ")));
+            }
+        }
+        
+        if historical_score < 0.8 {
+            suggestions.push("Improve historical accuracy with period-appropriate references".to_string());
+        }
+        
+        if spiritual_score < 0.8 {
+            suggestions.push("Deepen spiritual content with more meaningful insights".to_string());
+        }
+        
+        if practical_score < 0.8 {
+            suggestions.push("Add more practical guidance and safe methods".to_string());
+        }
+        
+        if source_score < 0.7 {
+            suggestions.push("Include references to primary sources and scholarly works".to_string());
+        }
+        
+        suggestions
+    }
+}
+
+/// Keyword-heuristic scoring model used when no custom backend is configured
+#[derive(Debug, Clone)]
+pub struct HeuristicBackend {
+    /// Tradition validators
+    tradition_validators: HashMap<String, TraditionValidator>,
+    /// Enochian keywords with weights
+    enochian_keywords: HashMap<String, f64>,
+    /// Historical markers
+    historical_markers: HashMap<String, f64>,
+    /// Spiritual depth indicators
+    spiritual_indicators: Vec<String>,
+    /// Source quality markers
+    source_markers: HashMap<String, f64>,
+    /// Dangerous terms by tradition, for traditions not using the defaults
+    dangerous_terms: HashMap<String, DangerousTerms>,
+    /// Dangerous terms for every other tradition
+    default_dangerous_terms: DangerousTerms,
+}
+
+impl Default for HeuristicBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthenticityBackend for HeuristicBackend {
+    fn score_component(&self, component: ScoreComponent, content: &str, tradition: &str) -> f64 {
+        self.score_component_traced(component, content, tradition, &mut MatchTrace::muted())
+    }
+    
+    fn score_component_traced(
+        &self,
+        component: ScoreComponent,
+        content: &str,
+        tradition: &str,
+        trace: &mut MatchTrace,
+    ) -> f64 {
+        match component {
+            ScoreComponent::TraditionAlignment => self.score_tradition_alignment(content, tradition, trace),
+            ScoreComponent::HistoricalAccuracy => self.score_historical_accuracy(content, trace),
+            ScoreComponent::SpiritualDepth => self.score_spiritual_depth(content, trace),
+            ScoreComponent::PracticalApplicability => self.score_practical_applicability(content, tradition, trace),
+            ScoreComponent::SourceQuality => self.score_source(content, tradition, trace),
+        }
+    }
+}
+
+impl HeuristicBackend {
+    /// Create the heuristic backend with the built-in lexicon
+    pub fn new() -> Self {
+        let mut backend = HeuristicBackend {
+            tradition_validators: HashMap::new(),
+            enochian_keywords: HashMap::new(),
+            historical_markers: HashMap::new(),
+            spiritual_indicators: Vec::new(),
+            source_markers: HashMap::new(),
+            dangerous_terms: HashMap::new(),
+            default_dangerous_terms: DangerousTerms::default(),
+        };
+        
+        backend.initialize_validators();
+        backend.initialize_keywords();
+        backend.initialize_markers();
+        backend
+    }
+    
     fn initialize_validators(&mut self) {
         // Enochian validator
         self.tradition_validators.insert("Enochian".to_string(), TraditionValidator {
@@ -314,23 +957,28 @@ impl AuthenticityScorer {
         self.source_markers.insert("traditional practice".to_string(), 1.5);
     }
     
-    fn score_tradition_alignment(&self, content: &str, validator: &TraditionValidator) -> f64 {
+    fn score_tradition_alignment(&self, content: &str, tradition: &str, trace: &mut MatchTrace) -> f64 {
         let content_lower = content.to_lowercase();
-        let word_count = content_lower.split_whitespace().count().max(1);
+        let (key_concepts, historical_figures) = match self.tradition_validators.get(tradition) {
+            Some(validator) => (validator.key_concepts.as_slice(), validator.historical_figures.as_slice()),
+            None => (&[][..], &[][..]),
+        };
         
         // Score key concepts
         let mut concept_score = 0.0;
-        for concept in &validator.key_concepts {
+        for concept in key_concepts {
             if content_lower.contains(concept) {
-                concept_score += 1.0 / validator.key_concepts.len() as f64;
+                concept_score += 1.0 / key_concepts.len() as f64;
+                trace.matched("tradition_alignment", concept, 0.3 / key_concepts.len() as f64);
             }
         }
         
         // Score historical figures
         let mut figure_score = 0.0;
-        for figure in &validator.historical_figures {
+        for figure in historical_figures {
             if content_lower.contains(figure) {
-                figure_score += 1.0 / validator.historical_figures.len() as f64;
+                figure_score += 1.0 / historical_figures.len() as f64;
+                trace.matched("tradition_alignment", figure, 0.1 / historical_figures.len() as f64);
             }
         }
         
@@ -342,7 +990,7 @@ impl AuthenticityScorer {
         (base_score + concept_bonus + figure_bonus).min(1.0)
     }
     
-    fn score_historical_accuracy(&self, content: &str, tradition: &str) -> f64 {
+    fn score_historical_accuracy(&self, content: &str, trace: &mut MatchTrace) -> f64 {
         let content_lower = content.to_lowercase();
         let mut score = 0.7; // Base historical score
         
@@ -350,6 +998,7 @@ impl AuthenticityScorer {
         for (marker, weight) in &self.historical_markers {
             if content_lower.contains(marker) {
                 score += weight * 0.02;
+                trace.matched("historical_accuracy", marker, weight * 0.02);
             }
         }
         
@@ -358,20 +1007,25 @@ impl AuthenticityScorer {
         for anachronism in &anachronisms {
             if content_lower.contains(anachronism) {
                 score -= 0.1;
+                trace.penalized("historical_accuracy", anachronism, -0.1);
             }
         }
         
         score.max(0.0).min(1.0)
     }
     
-    fn score_spiritual_depth(&self, content: &str) -> f64 {
+    fn score_spiritual_depth(&self, content: &str, trace: &mut MatchTrace) -> f64 {
         let content_lower = content.to_lowercase();
         let mut score = 0.6; // Base spiritual score
         
         // Check for spiritual depth indicators
-        let depth_count = self.spiritual_indicators.iter()
+        let depth_matches: Vec<&String> = self.spiritual_indicators.iter()
             .filter(|indicator| content_lower.contains(&indicator.to_lowercase()))
-            .count();
+            .collect();
+        for indicator in &depth_matches {
+            trace.matched("spiritual_depth", indicator, 0.05);
+        }
+        let depth_count = depth_matches.len();
         
         if depth_count > 0 {
             let depth_bonus = (depth_count as f64 * 0.05).min(0.3);
@@ -383,13 +1037,14 @@ impl AuthenticityScorer {
         for term in &materialistic_terms {
             if content_lower.contains(term) {
                 score -= 0.1;
+                trace.penalized("spiritual_depth", term, -0.1);
             }
         }
         
         score.max(0.0).min(1.0)
     }
     
-    fn score_practical_applicability(&self, content: &str) -> f64 {
+    fn score_practical_applicability(&self, content: &str, tradition: &str, trace: &mut MatchTrace) -> f64 {
         let content_lower = content.to_lowercase();
         let mut score = 0.7; // Base practical score
         
@@ -397,6 +1052,7 @@ impl AuthenticityScorer {
         let practical_terms = ["practice", "method", "technique", "exercise", "meditation", "study"];
         let practical_count = practical_terms.iter()
             .filter(|term| content_lower.contains(*term))
+            .inspect(|term| trace.matched("practical_applicability", term, 0.04))
             .count();
         
         if practical_count > 0 {
@@ -407,180 +1063,585 @@ impl AuthenticityScorer {
         let safety_terms = ["safe", "ethical", "responsible", "balanced", "grounded"];
         let safety_count = safety_terms.iter()
             .filter(|term| content_lower.contains(*term))
+            .inspect(|term| trace.matched("practical_applicability", term, 0.02))
             .count();
         
         if safety_count > 0 {
             score += (safety_count as f64 * 0.02).min(0.1);
         }
         
-        // Penalty for dangerous content
-        let dangerous_terms = ["harmful", "dangerous", "unethical", "manipulative", "coercive"];
-        for term in &dangerous_terms {
-            if content_lower.contains(term) {
-                score -= 0.2;
-            }
+        // Penalty for dangerous content, judged in context
+        let dangerous_terms = self.dangerous_terms.get(tradition).unwrap_or(&self.default_dangerous_terms);
+        for term in dangerous_terms.find_in(content) {
+            score -= 0.2;
+            trace.penalized("practical_applicability", term, -0.2);
         }
         
         score.max(0.0).min(1.0)
     }
     
-    fn score_source_quality(&self, sources: &[String], tradition: &str) -> f64 {
-        if sources.is_empty() {
-            return 0.5; // Neutral score for no sources
-        }
-        
+    fn score_source(&self, source: &str, tradition: &str, trace: &mut MatchTrace) -> f64 {
         let validator = self.tradition_validators.get(tradition);
-        let primary_sources = validator.map(|v| &v.primary_sources).unwrap_or(&vec![]);
+        let primary_sources = validator.map(|v| v.primary_sources.as_slice()).unwrap_or(&[]);
         
-        let mut total_score = 0.0;
-        let mut total_weight = 0.0;
+        let source_lower = source.to_lowercase();
+        let mut source_score = 0.3; // Base source score
+        let mut primary_match = None;
         
-        for source in sources {
-            let source_lower = source.to_lowercase();
-            let mut source_score = 0.3; // Base source score
-            
-            // Check if it's a primary source
-            for primary in primary_sources {
-                if primary.to_lowercase().contains(&source_lower) || source_lower.contains(&primary.to_lowercase()) {
-                    source_score = 1.0;
-                    break;
-                }
+        // Check if it's a primary source
+        for primary in primary_sources {
+            if primary.to_lowercase().contains(&source_lower) || source_lower.contains(&primary.to_lowercase()) {
+                source_score = 1.0;
+                primary_match = Some(primary.clone());
+                break;
             }
-            
-            // Check for source quality markers
-            for (marker, weight) in &self.source_markers {
-                if source_lower.contains(marker) {
-                    source_score += weight * 0.1;
-                }
+        }
+        
+        // Check for source quality markers
+        let mut matched_markers = Vec::new();
+        for (marker, weight) in &self.source_markers {
+            if source_lower.contains(marker) {
+                source_score += weight * 0.1;
+                matched_markers.push(marker.clone());
             }
-            
-            total_score += source_score.min(1.0);
-            total_weight += 1.0;
         }
         
-        if total_weight > 0.0 {
-            total_score / total_weight
-        } else {
-            0.5
+        if !trace.muted {
+            matched_markers.sort();
+            trace.source_decisions.push(SourceDecision {
+                source: source.to_string(),
+                primary_match,
+                matched_markers,
+                score: source_score.min(1.0),
+            });
         }
+        
+        source_score.min(1.0)
     }
-    
-    fn get_scoring_weights(&self, tradition: &str) -> ScoringWeights {
-        match tradition {
-            "Enochian" => ScoringWeights {
-                tradition_alignment: 0.35,
-                historical_accuracy: 0.25,
-                spiritual_depth: 0.20,
-                practical_applicability: 0.15,
-                source_quality: 0.05,
-            },
-            "Hermetic_Qabalah" => ScoringWeights {
-                tradition_alignment: 0.30,
-                historical_accuracy: 0.20,
-                spiritual_depth: 0.25,
-                practical_applicability: 0.15,
-                source_quality: 0.10,
-            },
-            _ => ScoringWeights {
-                tradition_alignment: 0.30,
-                historical_accuracy: 0.25,
-                spiritual_depth: 0.20,
-                practical_applicability: 0.15,
-                source_quality: 0.10,
-            },
+}
+
+/// Edit applied to content scored by `LiveScore`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TextEdit {
+    /// Append text to the end of the content
+    Append {
+        /// Text to append
+        text: String,
+    },
+    /// Replace the characters in `start..end` with new text
+    ReplaceRange {
+        /// First replaced character
+        start: usize,
+        /// Character after the last replaced one
+        end: usize,
+        /// Replacement text
+        text: String,
+    },
+}
+
+/// Quick authenticity score kept up to date across incremental edits
+///
+/// Tracks how often each lexicon term occurs and only re-scans the text
+/// around an edit. Edit positions are located from the nearer end of the
+/// content, so appends and edits near the cursor cost the same however long
+/// the content grows. The score always equals `AuthenticityScorer::quick_score`
+/// of the content.
+#[derive(Debug, Clone)]
+pub struct LiveScore {
+    /// Scorer providing the lexicon
+    scorer: AuthenticityScorer,
+    /// Current content
+    content: String,
+    /// Length of the content in characters
+    char_len: usize,
+    /// Occurrences of each lowercase lexicon term
+    term_counts: HashMap<String, usize>,
+    /// Length in characters of the longest term
+    longest_term: usize,
+}
+
+impl LiveScore {
+    /// Start live scoring empty content with the given scorer
+    pub fn new(scorer: AuthenticityScorer) -> Self {
+        let mut term_counts = HashMap::new();
+        for term in scorer.heuristic.enochian_keywords.keys()
+            .chain(scorer.heuristic.historical_markers.keys())
+            .cloned()
+            .chain(scorer.heuristic.spiritual_indicators.iter().map(|indicator| indicator.to_lowercase()))
+        {
+            term_counts.insert(term, 0);
+        }
+        let longest_term = term_counts.keys().map(|term| term.chars().count()).max().unwrap_or(0);
+        
+        LiveScore {
+            scorer,
+            content: String::new(),
+            char_len: 0,
+            term_counts,
+            longest_term,
         }
     }
     
-    fn generate_validation_notes(
-        &self,
-        tradition_score: f64,
-        historical_score: f64,
-        spiritual_score: f64,
-        practical_score: f64,
-        source_score: f64,
-        tradition: &str,
-    ) -> Vec<String> {
-        let mut notes = Vec::new();
-        
-        if tradition_score >= 0.9 {
-            notes.push(format!("Excellent alignment with {} tradition", tradition));
-        } else if tradition_score >= 0.8 {
-            notes.push(format!("Good alignment with {} tradition", tradition));
-        } else if tradition_score < 0.7 {
-            notes.push(format!("Weak alignment with {} tradition - consider strengthening core concepts", tradition));
+    /// Apply an edit and return the updated score
+    pub fn apply(&mut self, edit: TextEdit) -> Result<f64> {
+        let (start, end, text) = match edit {
+            TextEdit::Append { text } => (self.char_len, self.char_len, text),
+            TextEdit::ReplaceRange { start, end, text } => (start, end, text),
+        };
+        if start > end || end > self.char_len {
+            return Err(EnochianError::Generic {
+                message: format!("Edit range {}..{} outside content", start, end),
+            });
         }
         
-        if historical_score >= 0.9 {
-            notes.push("Strong historical accuracy".to_string());
-        } else if historical_score < 0.7 {
-            notes.push("Historical accuracy could be improved".to_string());
-        }
+        let start_byte = self.byte_offset(start);
+        let end_byte = self.content[start_byte..].char_indices()
+            .nth(end - start)
+            .map_or(self.content.len(), |(i, _)| start_byte + i);
         
-        if spiritual_score >= 0.9 {
-            notes.push("Excellent spiritual depth and meaning".to_string());
-        } else if spiritual_score < 0.7 {
-            notes.push("Consider deepening spiritual content".to_string());
+        let new_len = self.content.len() - (end_byte - start_byte) + text.len();
+        if new_len > self.scorer.max_content_length() {
+            return Err(EnochianError::ContentTooLarge {
+                size: new_len,
+                limit: self.scorer.max_content_length(),
+            });
         }
         
-        if practical_score >= 0.9 {
-            notes.push("Highly practical and applicable".to_string());
-        } else if practical_score < 0.7 {
-            notes.push("Could benefit from more practical guidance".to_string());
-        }
+        // Any occurrence touching the edit lies within one term length of it
+        let pad = self.longest_term;
+        let (window_start, window_end) = self.window(start_byte, end_byte, pad);
+        let old_window = self.content[window_start..window_end].to_lowercase();
+        self.tally(&old_window, false);
         
-        if source_score >= 0.8 {
-            notes.push("Good source quality".to_string());
-        } else if source_score < 0.6 {
-            notes.push("Source quality could be improved".to_string());
+        self.content.replace_range(start_byte..end_byte, &text);
+        self.char_len = self.char_len - (end - start) + text.chars().count();
+        
+        let (window_start, window_end) = self.window(start_byte, start_byte + text.len(), pad);
+        let new_window = self.content[window_start..window_end].to_lowercase();
+        self.tally(&new_window, true);
+        
+        Ok(self.current_score())
+    }
+    
+    /// Score of the current content
+    pub fn current_score(&self) -> f64 {
+        self.scorer.quick_score_with(|term| self.term_counts.get(term).copied().unwrap_or(0) > 0)
+    }
+    
+    /// Current content
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+    
+    /// Scorer providing the lexicon
+    pub fn scorer(&self) -> &AuthenticityScorer {
+        &self.scorer
+    }
+    
+    /// Byte offset of a character index up to `char_len`, counted from
+    /// whichever end of the content is nearer
+    fn byte_offset(&self, index: usize) -> usize {
+        if index <= self.char_len / 2 {
+            self.content.char_indices().nth(index).map_or(self.content.len(), |(i, _)| i)
+        } else if index == self.char_len {
+            self.content.len()
+        } else {
+            self.content.char_indices().rev().nth(self.char_len - index - 1).map_or(0, |(i, _)| i)
+        }
+    }
+    
+    /// Byte range extending `pad` characters either side of `start..end`
+    fn window(&self, start: usize, end: usize, pad: usize) -> (usize, usize) {
+        let before = self.content[..start].char_indices().rev().take(pad).last()
+            .map(|(i, _)| i)
+            .unwrap_or(start);
+        let after = self.content[end..].char_indices().nth(pad)
+            .map(|(i, _)| end + i)
+            .unwrap_or(self.content.len());
+        (before, after)
+    }
+    
+    /// Add or remove the term occurrences found in a lowercase window
+    fn tally(&mut self, window: &str, add: bool) {
+        for (term, count) in self.term_counts.iter_mut() {
+            let found = count_overlapping(window, term);
+            if add {
+                *count += found;
+            } else {
+                *count -= found;
+            }
+        }
+    }
+}
+
+/// Occurrences of a term, including overlapping ones
+fn count_overlapping(haystack: &str, term: &str) -> usize {
+    if term.is_empty() {
+        return 0;
+    }
+    let mut count = 0;
+    let mut from = 0;
+    while let Some(position) = haystack[from..].find(term) {
+        count += 1;
+        from += position + haystack[from + position..].chars().next().map_or(1, char::len_utf8);
+    }
+    count
+}
+
+/// Tolerance on the sum of `ScoringWeights`
+const WEIGHT_SUM_TOLERANCE: f64 = 1e-9;
+
+/// Scoring weights for different components
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringWeights {
+    /// Weight of the tradition alignment score
+    pub tradition_alignment: f64,
+    /// Weight of the historical accuracy score
+    pub historical_accuracy: f64,
+    /// Weight of the spiritual depth score
+    pub spiritual_depth: f64,
+    /// Weight of the practical applicability score
+    pub practical_applicability: f64,
+    /// Weight of the source quality score
+    pub source_quality: f64,
+}
+
+impl ScoringWeights {
+    /// Check every weight lies in 0.0..=1.0 and together they sum to 1.0
+    pub fn validate(&self) -> Result<()> {
+        let weights = [
+            ("tradition_alignment", self.tradition_alignment),
+            ("historical_accuracy", self.historical_accuracy),
+            ("spiritual_depth", self.spiritual_depth),
+            ("practical_applicability", self.practical_applicability),
+            ("source_quality", self.source_quality),
+        ];
+        if let Some((name, weight)) = weights.iter().find(|(_, weight)| !(0.0..=1.0).contains(weight)) {
+            return Err(EnochianError::AuthenticityError {
+                message: format!("scoring weight {} is {}, outside 0.0..=1.0", name, weight),
+            });
+        }
+        let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+        if (total - 1.0).abs() > WEIGHT_SUM_TOLERANCE {
+            return Err(EnochianError::AuthenticityError {
+                message: format!("scoring weights sum to {}, not 1.0", total),
+            });
+        }
+        Ok(())
+    }
+    
+    /// Shift weight away from components the content type isn't expected to
+    /// carry; the weights still sum to the same total
+    fn adjusted_for(self, content_type: ContentType) -> ScoringWeights {
+        match content_type {
+            ContentType::Codex => self,
+            ContentType::Quest => {
+                let shifted = self.source_quality / 2.0;
+                ScoringWeights {
+                    practical_applicability: self.practical_applicability + shifted,
+                    source_quality: self.source_quality - shifted,
+                    ..self
+                }
+            }
+            ContentType::Dialogue => {
+                let historical_shift = self.historical_accuracy / 2.0;
+                let practical_shift = self.practical_applicability / 2.0;
+                ScoringWeights {
+                    tradition_alignment: self.tradition_alignment + self.source_quality + historical_shift + practical_shift,
+                    historical_accuracy: self.historical_accuracy - historical_shift,
+                    practical_applicability: self.practical_applicability - practical_shift,
+                    source_quality: 0.0,
+                    ..self
+                }
+            }
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_verbose_trace_lists_matched_terms() {
+        let scorer = AuthenticityScorer::new();
+        let content = "In 1582 John Dee began scrying the Aethyr through the Watchtower tablets, \
+            a spiritual practice of divine communion. Study this method on a modern computer.";
+        let sources = vec!["Enochian Tablets".to_string(), "A blog post".to_string()];
         
-        notes
+        let (score, trace) = scorer.calculate_authenticity_verbose(content, "Enochian", ContentType::Codex, &sources).unwrap();
+        let plain = scorer.calculate_authenticity(content, "Enochian", ContentType::Codex, &sources).unwrap();
+        assert_eq!(score.overall_score, plain.overall_score);
+        
+        // Plain scoring records nothing
+        let mut muted = MatchTrace::muted();
+        HeuristicBackend::new().score_component_traced(ScoreComponent::TraditionAlignment, content, "Enochian", &mut muted);
+        HeuristicBackend::new().score_component_traced(ScoreComponent::SourceQuality, "Enochian Tablets", "Enochian", &mut muted);
+        assert!(muted.matched_terms.is_empty() && muted.source_decisions.is_empty());
+        
+        let matched = |component: &str| -> Vec<&str> {
+            trace.matched_terms.iter()
+                .filter(|m| m.component == component)
+                .map(|m| m.term.as_str())
+                .collect()
+        };
+        assert_eq!(matched("tradition_alignment"), vec!["aethyr", "john dee", "scrying", "watchtower"]);
+        assert_eq!(matched("historical_accuracy"), vec!["1582"]);
+        assert_eq!(matched("spiritual_depth"), vec!["divine communion", "spiritual practice"]);
+        assert_eq!(matched("practical_applicability"), vec!["method", "practice", "study"]);
+        
+        let penalties: Vec<&str> = trace.penalties.iter().map(|p| p.term.as_str()).collect();
+        assert_eq!(penalties, vec!["computer", "modern"]);
+        
+        assert_eq!(trace.source_decisions.len(), 2);
+        assert!(trace.source_decisions[0].primary_match.is_some());
+        assert!(trace.source_decisions[1].primary_match.is_none());
+        assert_eq!(trace.source_decisions[1].score, 0.3);
     }
     
-    fn generate_improvement_suggestions(
-        &self,
-        tradition_score: f64,
-        historical_score: f64,
-        spiritual_score: f64,
-        practical_score: f64,
-        source_score: f64,
-        tradition: &str,
-    ) -> Vec<String> {
-        let mut suggestions = Vec::new();
+    #[test]
+    fn test_content_length_limit() {
+        let scorer = AuthenticityScorer::new().with_max_content_length(64);
         
-        if tradition_score < 0.8 {
-            if let Some(validator) = self.tradition_validators.get(tradition) {
-                let key_concepts: Vec<&str> = validator.key_concepts.iter().take(3).map(|s| s.as_str()).collect();
-                suggestions.push(format!("Strengthen {} alignment by incorporating: {}", tradition, key_concepts.join(", ")));
+        let at_limit = "a".repeat(64);
+        assert!(scorer.calculate_authenticity(&at_limit, "Enochian", ContentType::Codex, &[]).is_ok());
+        
+        let over_limit = "a".repeat(65);
+        match scorer.calculate_authenticity(&over_limit, "Enochian", ContentType::Codex, &[]) {
+            Err(EnochianError::ContentTooLarge { size, limit }) => {
+                assert_eq!(size, 65);
+                assert_eq!(limit, 64);
             }
+            other => panic!("expected ContentTooLarge, got {:?}", other),
         }
         
-        if historical_score < 0.8 {
-            suggestions.push("Improve historical accuracy with period-appropriate references".to_string());
-        }
+        // Checked before the tradition lookup
+        assert!(matches!(
+            scorer.calculate_authenticity(&over_limit, "Unknown", ContentType::Codex, &[]),
+            Err(EnochianError::ContentTooLarge { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_live_score_matches_full_rescore() {
+        let mut live = LiveScore::new(AuthenticityScorer::new());
+        assert_eq!(live.current_score(), live.scorer().quick_score(""));
         
-        if spiritual_score < 0.8 {
-            suggestions.push("Deepen spiritual content with more meaningful insights".to_string());
+        let edits = vec![
+            TextEdit::Append { text: "In 1582 John Dee ".to_string() },
+            TextEdit::Append { text: "began scry".to_string() },
+            TextEdit::Append { text: "ing the Aethyr.".to_string() },
+            // Split a keyword in two, then repair it
+            TextEdit::ReplaceRange { start: 13, end: 14, text: "_".to_string() },
+            TextEdit::ReplaceRange { start: 13, end: 14, text: "D".to_string() },
+            TextEdit::ReplaceRange { start: 0, end: 8, text: "Ω ".to_string() },
+            TextEdit::Append { text: " A spiritual practice of divine communion.".to_string() },
+            // Edits in the second half are located from the end
+            TextEdit::ReplaceRange { start: 61, end: 67, text: "holy".to_string() },
+            TextEdit::ReplaceRange { start: 61, end: 65, text: "divine".to_string() },
+            TextEdit::Append { text: "Ω".to_string() },
+            TextEdit::ReplaceRange { start: 78, end: 79, text: String::new() },
+            TextEdit::ReplaceRange { start: 2, end: 11, text: String::new() },
+        ];
+        
+        for edit in edits {
+            let score = live.apply(edit).unwrap();
+            let expected = live.scorer().quick_score(live.content());
+            assert!((score - expected).abs() < 1e-12, "{} != {} for {:?}", score, expected, live.content());
         }
+        assert_eq!(live.content(), "Ω began scrying the Aethyr. A spiritual practice of divine communion.");
+    }
+    
+    #[test]
+    fn test_live_score_rejects_bad_edits() {
+        let mut live = LiveScore::new(AuthenticityScorer::new().with_max_content_length(8));
+        live.apply(TextEdit::Append { text: "aethyr".to_string() }).unwrap();
         
-        if practical_score < 0.8 {
-            suggestions.push("Add more practical guidance and safe methods".to_string());
+        assert!(live.apply(TextEdit::ReplaceRange { start: 4, end: 2, text: String::new() }).is_err());
+        assert!(live.apply(TextEdit::ReplaceRange { start: 0, end: 7, text: String::new() }).is_err());
+        assert!(matches!(
+            live.apply(TextEdit::Append { text: "abc".to_string() }),
+            Err(EnochianError::ContentTooLarge { .. })
+        ));
+        assert_eq!(live.content(), "aethyr");
+    }
+    
+    /// Backend returning a fixed score per component
+    #[derive(Debug)]
+    struct FixedBackend;
+    
+    impl AuthenticityBackend for FixedBackend {
+        fn score_component(&self, component: ScoreComponent, _content: &str, _tradition: &str) -> f64 {
+            match component {
+                ScoreComponent::TraditionAlignment => 1.0,
+                ScoreComponent::HistoricalAccuracy => 0.8,
+                ScoreComponent::SpiritualDepth => 0.6,
+                ScoreComponent::PracticalApplicability => 0.4,
+                ScoreComponent::SourceQuality => 0.2,
+            }
         }
+    }
+    
+    #[test]
+    fn test_custom_backend_weighted_aggregation() {
+        let scorer = AuthenticityScorer::new().with_backend(FixedBackend);
+        let sources = vec!["Enochian Tablets".to_string()];
         
-        if source_score < 0.7 {
-            suggestions.push("Include references to primary sources and scholarly works".to_string());
+        let enochian = scorer.calculate_authenticity("anything", "Enochian", ContentType::Codex, &sources).unwrap();
+        assert_eq!(enochian.tradition_alignment, 1.0);
+        assert_eq!(enochian.source_quality, 0.2);
+        let expected = 1.0 * 0.35 + 0.8 * 0.25 + 0.6 * 0.20 + 0.4 * 0.15 + 0.2 * 0.05;
+        assert!((enochian.overall_score - expected).abs() < 1e-12);
+        
+        // Tradition weight still scales the aggregate
+        let qabalah = scorer.calculate_authenticity("anything", "Hermetic_Qabalah", ContentType::Codex, &sources).unwrap();
+        let expected = (1.0 * 0.30 + 0.8 * 0.20 + 0.6 * 0.25 + 0.4 * 0.15 + 0.2 * 0.10) * 0.8;
+        assert!((qabalah.overall_score - expected).abs() < 1e-12);
+        
+        // Without sources the backend is not consulted for source quality
+        let unsourced = scorer.calculate_authenticity("anything", "Enochian", ContentType::Codex, &[]).unwrap();
+        assert_eq!(unsourced.source_quality, 0.5);
+        assert!(scorer.calculate_authenticity("anything", "Unknown", ContentType::Codex, &[]).is_err());
+    }
+    
+    #[test]
+    fn test_heuristic_backend_is_default() {
+        let content = "John Dee recorded the Aethyr in 1582.";
+        let sources = vec!["Enochian Tablets".to_string(), "A blog post".to_string()];
+        let default = AuthenticityScorer::new().calculate_authenticity(content, "Enochian", ContentType::Codex, &sources).unwrap();
+        let explicit = AuthenticityScorer::new().with_backend(HeuristicBackend::new())
+            .calculate_authenticity(content, "Enochian", ContentType::Codex, &sources).unwrap();
+        assert_eq!(default.overall_score, explicit.overall_score);
+        assert_eq!(default.source_quality, 0.65);
+    }
+    
+    #[test]
+    fn test_default_content_length_limit() {
+        let scorer = AuthenticityScorer::new();
+        assert_eq!(scorer.max_content_length(), crate::constants::MAX_SCORING_CONTENT_SIZE);
+        assert_ne!(scorer.max_content_length(), crate::constants::MAX_ORDINALS_SIZE);
+        
+        let over_default = "a".repeat(crate::constants::MAX_SCORING_CONTENT_SIZE + 1);
+        assert!(scorer.calculate_authenticity(&over_default, "Enochian", ContentType::Codex, &[]).is_err());
+    }
+    
+    #[test]
+    fn test_dangerous_terms_respect_context() {
+        let terms = DangerousTerms::default();
+        assert!(terms.find_in("Cultivate self-control through daily meditation").is_empty());
+        assert!(terms.find_in("Careful manipulation of ritual tools").is_empty());
+        assert!(terms.find_in("A practice that is never manipulative or coercive").is_empty());
+        assert_eq!(terms.find_in("The manipulation of others for gain"), vec!["manipulation"]);
+        assert_eq!(terms.find_in("Harmful, harmful rites to control them"), vec!["harmful", "control"]);
+        
+        let practical = |scorer: &AuthenticityScorer, content: &str, tradition: &str| {
+            scorer.backend().score_component(ScoreComponent::PracticalApplicability, content, tradition)
+        };
+        let scorer = AuthenticityScorer::new();
+        let baseline = practical(&scorer, "Meditation practice", "Enochian");
+        assert_eq!(practical(&scorer, "Meditation practice builds self-control", "Enochian"), baseline);
+        assert!((practical(&scorer, "Meditation practice for manipulation of others", "Enochian") - (baseline - 0.2)).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_dangerous_terms_are_configurable_per_tradition() {
+        let banishing = DangerousTerms { terms: vec!["curse".to_string()], person_directed: vec![] };
+        let scorer = AuthenticityScorer::new().with_dangerous_terms("Chaos_Magic", banishing);
+        let practical = |content: &str, tradition: &str| {
+            scorer.backend().score_component(ScoreComponent::PracticalApplicability, content, tradition)
+        };
+        
+        assert!(practical("A curse practice", "Chaos_Magic") < practical("A curse practice", "Enochian"));
+        assert_eq!(practical("A harmful practice", "Chaos_Magic"), practical("A safe practice", "Chaos_Magic") - 0.02);
+    }
+    
+    #[test]
+    fn test_concept_coverage_reports_missing_concepts() {
+        let scorer = AuthenticityScorer::new();
+        let passage = "Through scrying in the Aethyr the Governor revealed its place in the celestial hierarchy.";
+        let coverage = scorer.concept_coverage(passage, "Enochian");
+        
+        assert_eq!(coverage.matched, vec!["aethyr", "governor", "scrying", "celestial hierarchy"]);
+        assert_eq!(coverage.missing, vec!["angelic communication", "watchtower", "enochian language", "spiritual diary"]);
+        assert_eq!(coverage.coverage, 0.5);
+        
+        let unknown = scorer.concept_coverage(passage, "Atlantean");
+        assert!(unknown.matched.is_empty() && unknown.missing.is_empty());
+        assert_eq!(unknown.coverage, 0.0);
+    }
+    
+    #[test]
+    fn test_short_dialogue_not_penalized_for_missing_sources() {
+        let scorer = AuthenticityScorer::new();
+        let line = "I am ABRIOND, Governor of the Aethyr. Approach the Watchtower with reverence, seeker.";
+        let sources = vec!["Enochian Tablets".to_string()];
+        let score = |content_type, sources: &[String]| {
+            scorer.calculate_authenticity(line, "Enochian", content_type, sources).unwrap().overall_score
+        };
+        
+        // A codex entry loses out without citations; dialogue is not expected to cite any
+        assert!(score(ContentType::Codex, &[]) < score(ContentType::Codex, &sources));
+        assert_eq!(score(ContentType::Dialogue, &[]), score(ContentType::Dialogue, &sources));
+        assert!(score(ContentType::Dialogue, &[]) > score(ContentType::Codex, &[]));
+        
+        // Every content type keeps the weights summing to the tradition's total
+        for content_type in [ContentType::Quest, ContentType::Dialogue, ContentType::Codex] {
+            let weights = scorer.get_scoring_weights("Enochian").adjusted_for(content_type);
+            let total = weights.tradition_alignment + weights.historical_accuracy + weights.spiritual_depth
+                + weights.practical_applicability + weights.source_quality;
+            assert!((total - 1.0).abs() < 1e-12);
         }
+    }
+    
+    #[test]
+    fn test_weight_overrides_replace_tradition_defaults() {
+        let scorer = AuthenticityScorer::new();
+        let content = "In 1582 John Dee and Edward Kelley recorded the Aethyr calls at Mortlake, \
+            as the Sloane manuscripts attest.";
+        let defaults = scorer.default_weights("Enochian", ContentType::Quest);
+        assert!(defaults.validate().is_ok());
         
-        suggestions
+        let historical = ScoringWeights {
+            tradition_alignment: 0.10,
+            historical_accuracy: 0.70,
+            spiritual_depth: 0.05,
+            practical_applicability: 0.10,
+            source_quality: 0.05,
+        };
+        let default_score = scorer.calculate_authenticity(content, "Enochian", ContentType::Quest, &[]).unwrap();
+        let historical_score = scorer
+            .calculate_authenticity_with_weights(content, "Enochian", ContentType::Quest, &[], &historical)
+            .unwrap();
+        assert_eq!(historical_score.historical_accuracy, default_score.historical_accuracy);
+        assert_ne!(historical_score.overall_score, default_score.overall_score);
+        
+        // Passing the defaults explicitly changes nothing
+        let explicit = scorer
+            .calculate_authenticity_with_weights(content, "Enochian", ContentType::Quest, &[], &defaults)
+            .unwrap();
+        assert_eq!(explicit.overall_score, default_score.overall_score);
+        
+        let unbalanced = ScoringWeights { historical_accuracy: 0.80, ..historical.clone() };
+        assert!(unbalanced.validate().is_err());
+        assert!(scorer
+            .calculate_authenticity_with_weights(content, "Enochian", ContentType::Quest, &[], &unbalanced)
+            .is_err());
+        let negative = ScoringWeights { tradition_alignment: -0.10, historical_accuracy: 0.90, ..historical };
+        assert!(negative.validate().is_err());
+    }
+    
+    #[test]
+    fn test_fingerprint_covers_validators() {
+        let baseline = AuthenticityScorer::new().lexicon_fingerprint();
+        assert_eq!(AuthenticityScorer::new().lexicon_fingerprint(), baseline);
+        
+        let mut reweighted = AuthenticityScorer::new();
+        reweighted.heuristic.tradition_validators.get_mut("Hermetic_Qabalah").unwrap().authenticity_weight = 0.9;
+        assert_ne!(reweighted.lexicon_fingerprint(), baseline);
+        
+        let mut extended = AuthenticityScorer::new();
+        extended.heuristic.tradition_validators.get_mut("Enochian").unwrap().key_concepts.push("shewstone".to_string());
+        assert_ne!(extended.lexicon_fingerprint(), baseline);
     }
-}
-
-/// Scoring weights for different components
-#[derive(Debug, Clone)]
-struct ScoringWeights {
-    tradition_alignment: f64,
-    historical_accuracy: f64,
-    spiritual_depth: f64,
-    practical_applicability: f64,
-    source_quality: f64,
 }
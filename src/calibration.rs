@@ -0,0 +1,362 @@
+//! Labeled passages for measuring the authenticity scorer
+//!
+//! The corpus pairs short passages with a human judgement of whether they are
+//! authentic to their tradition. `calibrate` scores every passage so that
+//! keyword and weight changes can be measured rather than guessed: both how
+//! the scorer's own thresholds judge the corpus and how well its scores
+//! separate authentic from inauthentic passages at all.
+
+use serde::{Deserialize, Serialize};
+use crate::authenticity::{AuthenticityScorer, ContentType};
+use crate::constants;
+
+/// Accuracy the default scorer must reach on `CORPUS` at its best threshold
+///
+/// Raise this when scorer changes improve the corpus result; never lower it
+/// without relabeling or replacing the passages it fails.
+pub const MIN_CALIBRATION_ACCURACY: f64 = 0.9;
+
+/// A passage labeled as authentic or inauthentic to a tradition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalibrationSample {
+    /// Tradition the passage is judged against
+    pub tradition: &'static str,
+    /// Passage text
+    pub text: &'static str,
+    /// Sources the passage cites
+    pub sources: &'static [&'static str],
+    /// Whether the passage is authentic to the tradition
+    pub authentic: bool,
+}
+
+/// Embedded calibration corpus, grouped by tradition
+pub const CORPUS: &[CalibrationSample] = &[
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "In 1582 at Mortlake, John Dee recorded in his spiritual diary how Edward Kelley, scrying in the shewstone, \
+               received angelic communication concerning the Enochian language and the watchtower tablets. \
+               Study the calls with grounded, balanced practice and seek divine guidance.",
+        sources: &["John Dee Spiritual Diaries"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "Each aethyr is ruled by a governor within the celestial hierarchy. The Elizabethan practice of scrying \
+               the aethyrs is approached through invocation and meditation, as Dee and Kelley did in Prague in 1584, \
+               for spiritual development and inner transformation.",
+        sources: &["Enochian Tablets", "Watchtower Manuscripts"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "The four watchtower tablets received by Edward Kelley in 1583 set the angelic names in order. \
+               John Dee kept a spiritual diary of each scrying session; study them as a responsible spiritual practice \
+               of divine communion and sacred wisdom.",
+        sources: &["Enochian Tablets"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "Renaissance angelic communication through the Enochian language was a spiritual practice of scrying. \
+               The governor of each aethyr offers divine guidance and spiritual growth to the grounded and ethical seeker.",
+        sources: &["Edward Kelley Communications"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "Download the modern Enochian app on your smartphone and use the internet to summon wealth and money \
+               overnight, gaining control and power over others.",
+        sources: &[],
+        authentic: false,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "This computer-generated spell brings money and wealth. Manipulation of others is easy with this \
+               dangerous and harmful chant.",
+        sources: &[],
+        authentic: false,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "Buy our 21st century crystal kit to attract money. No study needed, results guaranteed.",
+        sources: &[],
+        authentic: false,
+    },
+    CalibrationSample {
+        tradition: "Enochian",
+        text: "A recipe for lemon cake: mix flour, sugar and eggs, then bake for forty minutes.",
+        sources: &["Family cookbook"],
+        authentic: false,
+    },
+    CalibrationSample {
+        tradition: "Hermetic_Qabalah",
+        text: "The Hermetic Qabalah maps emanation through the sephiroth of the tree of life. Pathworking with the \
+               divine names, as Mathers taught and Isaac Luria and Moses de Leon described before him, is a spiritual \
+               practice of meditation and study aimed at mystical union and higher consciousness.",
+        sources: &["Zohar", "Golden Dawn Manuscripts"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Hermetic_Qabalah",
+        text: "Qabalah describes the tree of life as ten sephiroth joined by paths. Pathworking is a grounded, \
+               balanced meditation technique; the divine names guide spiritual growth, inner transformation and \
+               mystical union, drawing on the Renaissance Hermetic tradition of sacred wisdom.",
+        sources: &["Sefer Yetzirah"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Hermetic_Qabalah",
+        text: "Isaac Luria taught that emanation descends through the sephiroth and that study and meditation on the \
+               divine names restore what was scattered. Practised in a safe and ethical way, this Qabalah leads to \
+               spiritual growth and mystical union.",
+        sources: &["Tree of Life Studies"],
+        authentic: true,
+    },
+    CalibrationSample {
+        tradition: "Hermetic_Qabalah",
+        text: "Use the tree of life to gain money and wealth. This modern shortcut skips study and gives you control \
+               and power over others.",
+        sources: &[],
+        authentic: false,
+    },
+    CalibrationSample {
+        tradition: "Hermetic_Qabalah",
+        text: "Our internet course on the computer teaches manipulation of others and domination of your rivals. \
+               Dangerous, but effective.",
+        sources: &[],
+        authentic: false,
+    },
+    CalibrationSample {
+        tradition: "Hermetic_Qabalah",
+        text: "Qabalah is just a fun name for our weekend party. Bring snacks and your favourite board games.",
+        sources: &["Event flyer"],
+        authentic: false,
+    },
+];
+
+/// Verdict counts against the corpus labels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfusionCounts {
+    /// Authentic passages accepted
+    pub true_positives: usize,
+    /// Inauthentic passages accepted
+    pub false_positives: usize,
+    /// Inauthentic passages rejected
+    pub true_negatives: usize,
+    /// Authentic passages rejected
+    pub false_negatives: usize,
+}
+
+impl ConfusionCounts {
+    fn record(&mut self, authentic: bool, accepted: bool) {
+        match (authentic, accepted) {
+            (true, true) => self.true_positives += 1,
+            (false, true) => self.false_positives += 1,
+            (false, false) => self.true_negatives += 1,
+            (true, false) => self.false_negatives += 1,
+        }
+    }
+    
+    /// Number of passages counted
+    pub fn total(&self) -> usize {
+        self.true_positives + self.false_positives + self.true_negatives + self.false_negatives
+    }
+    
+    /// Share of accepted passages that are authentic (1.0 when none are accepted)
+    pub fn precision(&self) -> f64 {
+        ratio(self.true_positives, self.true_positives + self.false_positives)
+    }
+    
+    /// Share of authentic passages that are accepted (1.0 when there are none)
+    pub fn recall(&self) -> f64 {
+        ratio(self.true_positives, self.true_positives + self.false_negatives)
+    }
+    
+    /// Share of passages whose verdict matches the label (1.0 when empty)
+    pub fn accuracy(&self) -> f64 {
+        ratio(self.true_positives + self.true_negatives, self.total())
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        1.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// How the scorer judged one corpus passage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampleVerdict {
+    /// Tradition the passage was scored against
+    pub tradition: String,
+    /// Corpus label
+    pub authentic: bool,
+    /// Overall score, or `None` if scoring failed
+    pub score: Option<f64>,
+    /// Minimum threshold of the tradition
+    pub threshold: f64,
+}
+
+impl SampleVerdict {
+    /// Whether the score reaches `threshold`; passages that failed to score never do
+    pub fn accepted_at(&self, threshold: f64) -> bool {
+        self.score.is_some_and(|score| score >= threshold)
+    }
+    
+    /// Whether the score reaches the tradition's minimum threshold
+    pub fn accepted(&self) -> bool {
+        self.accepted_at(self.threshold)
+    }
+}
+
+/// Scorer performance on the calibration corpus
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalibrationReport {
+    /// Verdict for each passage, in corpus order
+    pub verdicts: Vec<SampleVerdict>,
+}
+
+impl CalibrationReport {
+    /// Counts at each tradition's minimum threshold
+    pub fn counts(&self) -> ConfusionCounts {
+        self.count(|_| true, SampleVerdict::accepted)
+    }
+    
+    /// Counts for `tradition` at its minimum threshold
+    pub fn tradition_counts(&self, tradition: &str) -> ConfusionCounts {
+        self.count(|verdict| verdict.tradition == tradition, SampleVerdict::accepted)
+    }
+    
+    /// Counts if every passage were judged against `threshold` instead
+    pub fn counts_at(&self, threshold: f64) -> ConfusionCounts {
+        self.count(|_| true, |verdict| verdict.accepted_at(threshold))
+    }
+    
+    /// Precision at each tradition's minimum threshold
+    pub fn precision(&self) -> f64 {
+        self.counts().precision()
+    }
+    
+    /// Recall at each tradition's minimum threshold
+    pub fn recall(&self) -> f64 {
+        self.counts().recall()
+    }
+    
+    /// Accuracy at each tradition's minimum threshold
+    pub fn accuracy(&self) -> f64 {
+        self.counts().accuracy()
+    }
+    
+    /// Single threshold with the highest accuracy, and that accuracy
+    ///
+    /// Measures how well scores separate the labels regardless of where the
+    /// thresholds sit. Candidates are the passage scores themselves, so the
+    /// lowest is preferred on ties; an empty report gives `(0.0, 1.0)`.
+    pub fn best_threshold(&self) -> (f64, f64) {
+        let mut candidates: Vec<f64> = self.verdicts.iter().filter_map(|verdict| verdict.score).collect();
+        candidates.sort_by(|a, b| a.total_cmp(b));
+        candidates.into_iter()
+            .map(|threshold| (threshold, self.counts_at(threshold).accuracy()))
+            .fold(None, |best: Option<(f64, f64)>, candidate| match best {
+                Some(best) if best.1 >= candidate.1 => Some(best),
+                _ => Some(candidate),
+            })
+            .unwrap_or((0.0, 1.0))
+    }
+    
+    /// Passages whose verdict at their tradition's minimum threshold disagrees with their label
+    pub fn misclassified(&self) -> Vec<&SampleVerdict> {
+        self.verdicts.iter().filter(|verdict| verdict.accepted() != verdict.authentic).collect()
+    }
+    
+    fn count(&self, include: impl Fn(&SampleVerdict) -> bool, accepted: impl Fn(&SampleVerdict) -> bool) -> ConfusionCounts {
+        let mut counts = ConfusionCounts::default();
+        for verdict in self.verdicts.iter().filter(|verdict| include(verdict)) {
+            counts.record(verdict.authentic, accepted(verdict));
+        }
+        counts
+    }
+}
+
+/// Score `CORPUS` with `scorer` and compare its verdicts against the labels
+pub fn calibrate(scorer: &AuthenticityScorer) -> CalibrationReport {
+    calibrate_samples(scorer, CORPUS)
+}
+
+/// Score `samples` with `scorer` and compare its verdicts against their labels
+///
+/// Passages are scored as codex content with their cited sources and judged
+/// against their tradition's minimum threshold, or
+/// `constants::AUTHENTICITY_THRESHOLD` for traditions without a validator.
+pub fn calibrate_samples(scorer: &AuthenticityScorer, samples: &[CalibrationSample]) -> CalibrationReport {
+    let verdicts = samples.iter()
+        .map(|sample| {
+            let sources: Vec<String> = sample.sources.iter().map(|source| source.to_string()).collect();
+            let score = scorer.calculate_authenticity(sample.text, sample.tradition, ContentType::Codex, &sources)
+                .ok()
+                .map(|score| score.overall_score);
+            SampleVerdict {
+                tradition: sample.tradition.to_string(),
+                authentic: sample.authentic,
+                score,
+                threshold: scorer.minimum_threshold(sample.tradition).unwrap_or(constants::AUTHENTICITY_THRESHOLD),
+            }
+        })
+        .collect();
+    
+    CalibrationReport { verdicts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_default_scorer_separates_corpus() {
+        let report = calibrate(&AuthenticityScorer::new());
+        assert_eq!(report.verdicts.len(), CORPUS.len());
+        assert!(report.verdicts.iter().all(|verdict| verdict.score.is_some()));
+        
+        let (threshold, accuracy) = report.best_threshold();
+        assert!(accuracy >= MIN_CALIBRATION_ACCURACY, "accuracy {} at {}: {:#?}", accuracy, threshold, report);
+        
+        // Every authentic passage outscores every inauthentic one of its
+        // tradition, and the tradition minimums let no inauthentic one through
+        for tradition in ["Enochian", "Hermetic_Qabalah"] {
+            let scores = |authentic: bool| report.verdicts.iter()
+                .filter(|verdict| verdict.tradition == tradition && verdict.authentic == authentic)
+                .filter_map(|verdict| verdict.score)
+                .collect::<Vec<f64>>();
+            let lowest_authentic = scores(true).into_iter().fold(f64::INFINITY, f64::min);
+            let highest_inauthentic = scores(false).into_iter().fold(f64::NEG_INFINITY, f64::max);
+            assert!(lowest_authentic > highest_inauthentic, "{}: {} <= {}", tradition, lowest_authentic, highest_inauthentic);
+            assert_eq!(report.tradition_counts(tradition).false_positives, 0, "{}", tradition);
+        }
+    }
+    
+    #[test]
+    fn test_report_counts_verdicts() {
+        let samples = [
+            CalibrationSample { tradition: "Enochian", text: "Renaissance scrying with John Dee", sources: &[], authentic: true },
+            CalibrationSample { tradition: "Enochian", text: "money and wealth", sources: &[], authentic: false },
+            CalibrationSample { tradition: "Unknown", text: "anything", sources: &[], authentic: true },
+        ];
+        let report = calibrate_samples(&AuthenticityScorer::new(), &samples);
+        
+        // Unsupported traditions fail to score and count as rejected
+        assert_eq!(report.verdicts[2].score, None);
+        assert_eq!(report.verdicts[2].threshold, constants::AUTHENTICITY_THRESHOLD);
+        
+        let everything = report.counts_at(0.0);
+        assert_eq!((everything.true_positives, everything.false_positives, everything.false_negatives), (1, 1, 1));
+        assert_eq!(everything.precision(), 0.5);
+        assert_eq!(everything.recall(), 0.5);
+        
+        let enochian = report.tradition_counts("Enochian");
+        assert_eq!(enochian.total(), 2);
+        assert_eq!(report.counts().total(), 3);
+        assert_eq!(report.misclassified().len(), report.counts().false_positives + report.counts().false_negatives);
+    }
+}
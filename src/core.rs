@@ -1,8 +1,27 @@
 //! Core functionality for the Enochian Cyphers system
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use crate::{Result, EnochianError};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::{Arc, OnceLock};
+use crate::{ConstraintCheck, Result, EnochianError};
+use crate::traditions::TraditionManager;
+use crate::governors::shared_manager;
+use crate::authenticity::{AuthenticityScorer, ContentType, ScoringWeights};
+use crate::metrics::Counter;
+use crate::journal::{InscriptionJournal, JournalOp};
+use crate::runtime::{Clock, FixedClock, NullSink, RandomSource, StateSink, SystemClock, SystemRandom};
+
+/// Enter a span carrying the given fields until the end of the enclosing scope
+///
+/// Spans are only emitted with the `tracing` feature; `log` output is
+/// unchanged either way, and is attributed to the span when bridged into
+/// tracing.
+macro_rules! quest_span {
+    ($name:literal $(, $field:ident = $value:expr)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($name $(, $field = %$value)*).entered();
+    };
+}
 
 /// Core Enochian Cyphers system
 #[derive(Debug, Clone)]
@@ -10,11 +29,54 @@ pub struct EnochianCore {
     /// System configuration
     pub config: SystemConfig,
     /// Current game states
-    pub game_states: HashMap<String, GameState>,
+    pub game_states: BTreeMap<String, GameState>,
     /// Quest registry
-    pub quest_registry: HashMap<String, QuestData>,
+    pub quest_registry: BTreeMap<String, QuestData>,
+    /// Player state snapshots taken before each mutation, oldest first
+    pub state_history: StateHistory,
+    /// Tradition data used for mastery gating
+    pub tradition_manager: TraditionManager,
+    /// Registered quest IDs by content fingerprint
+    pub content_fingerprints: BTreeMap<String, String>,
+    /// Quest chains by chain ID
+    pub quest_chains: BTreeMap<String, QuestChain>,
+    /// Quest start, completion, failure and abandonment events, oldest first,
+    /// at most `SystemConfig::max_event_log` long
+    pub quest_events: Vec<QuestEvent>,
+    /// Mastery rank changes caused by rewards, oldest first, at most
+    /// `SystemConfig::max_event_log` long
+    pub rank_events: Vec<RankChange>,
+    /// Players rolled back to local checkpoints, oldest first, at most
+    /// `SystemConfig::max_event_log` long
+    pub restore_events: Vec<RestoreEvent>,
+    /// Local checkpoints per player, oldest first (not synchronized over P2P)
+    pub player_snapshots: BTreeMap<String, VecDeque<(SnapshotId, GameState)>>,
+    /// Next checkpoint identifier to hand out
+    next_snapshot_id: u64,
+    /// Quests withheld from players after failing authenticity revalidation
+    pub quarantined_quests: BTreeSet<String>,
+    /// Lexicon fingerprint of the scorer that last revalidated the registry
+    revalidated_lexicon: Option<String>,
+    /// Scorer probed by `health`, built on first use
+    health_scorer: OnceLock<AuthenticityScorer>,
     /// Initialized status
     pub initialized: bool,
+    /// Construction time, used for uptime reporting
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// Source of every timestamp the core records
+    clock: Arc<dyn Clock>,
+    /// Source of every random value the core draws
+    random: Arc<dyn RandomSource>,
+    /// Operations committed so far, when `SystemConfig::record_journal` is set
+    journal: Option<InscriptionJournal>,
+    /// Destination of the state `flush` persists
+    sink: Arc<dyn StateSink>,
+    /// Hash of each player's state as of the last flush
+    flushed_states: BTreeMap<String, String>,
+    /// Number of journal entries persisted by flushes so far
+    flushed_journal_len: usize,
+    /// Set by `shutdown`; mutations are rejected from then on
+    closed: bool,
 }
 
 /// System configuration
@@ -25,13 +87,563 @@ pub struct SystemConfig {
     /// Maximum concurrent quests per player
     pub max_concurrent_quests: u32,
     /// Tradition weighting
-    pub tradition_weighting: HashMap<String, f64>,
+    pub tradition_weighting: BTreeMap<String, f64>,
     /// Governor interaction cooldown (in blocks)
     pub governor_interaction_cooldown: u32,
     /// Enable P2P synchronization
     pub enable_p2p_sync: bool,
     /// Enable Bitcoin L1 integration
     pub enable_bitcoin_integration: bool,
+    /// Maximum number of retained player state snapshots; 0, the default,
+    /// keeps no history
+    #[serde(default = "default_max_state_history")]
+    pub max_state_history: usize,
+    /// Maximum total serialized size of retained snapshots, in bytes
+    #[serde(default = "default_max_state_history_bytes")]
+    pub max_state_history_bytes: usize,
+    /// Maximum number of quest events, and of rank events, retained; the
+    /// oldest are dropped first
+    #[serde(default = "default_max_event_log")]
+    pub max_event_log: usize,
+    /// Handling of quests whose content duplicates a registered quest
+    #[serde(default)]
+    pub duplicate_content_policy: DuplicateContentPolicy,
+    /// Maximum number of checkpoints kept per player
+    #[serde(default = "default_max_player_snapshots")]
+    pub max_player_snapshots: usize,
+    /// Fraction of a quest's energy cost waived at full relationship with its governor
+    #[serde(default = "default_relationship_energy_discount")]
+    pub relationship_energy_discount: f64,
+    /// Relationship every council member must have with the player before a
+    /// council quest can be completed
+    #[serde(default = "default_council_min_relationship")]
+    pub council_min_relationship: f64,
+    /// Withhold quests from players when revalidation scores them below
+    /// the authenticity threshold
+    #[serde(default)]
+    pub quarantine_on_revalidation: bool,
+    /// Register quests with no tradition_integration as Enochian instead of
+    /// rejecting them
+    #[serde(default)]
+    pub default_to_enochian: bool,
+    /// Largest difference allowed between a quest's required energy and
+    /// `derive_required_energy`; unchecked when unset
+    #[serde(default)]
+    pub energy_tolerance: Option<u32>,
+    /// Most mastery a player can gain in one tradition per
+    /// `mastery_cap_window_blocks`; the excess is deferred. Uncapped when unset
+    #[serde(default)]
+    pub mastery_gain_cap: Option<f64>,
+    /// Length in blocks of the window `mastery_gain_cap` applies to
+    #[serde(default = "default_mastery_cap_window_blocks")]
+    pub mastery_cap_window_blocks: u64,
+    /// Most relationship a player can gain with a governor per
+    /// `relationship_cap_window_blocks`; the excess is deferred. Uncapped when unset
+    #[serde(default)]
+    pub relationship_gain_cap: Option<f64>,
+    /// Length in blocks of the window `relationship_gain_cap` applies to
+    #[serde(default = "default_relationship_cap_window_blocks")]
+    pub relationship_cap_window_blocks: u64,
+    /// Reject quests tagged outside `QUEST_TAGS` instead of only warning
+    #[serde(default)]
+    pub strict_tags: bool,
+    /// Record committed operations in an `InscriptionJournal` for replay
+    #[serde(default)]
+    pub record_journal: bool,
+    /// Mastery at which each named rank begins
+    #[serde(default)]
+    pub mastery_rank_thresholds: MasteryRankThresholds,
+}
+
+/// Outcome of re-scoring one registered quest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RevalidationResult {
+    /// Quest ID
+    pub quest_id: String,
+    /// Score stored before revalidation
+    pub previous_score: f64,
+    /// Recomputed score, now stored on the quest
+    pub score: f64,
+    /// Whether the recomputed score is below the authenticity threshold
+    pub below_threshold: bool,
+    /// Whether the quest was withheld from players
+    pub quarantined: bool,
+}
+
+/// How serious a quest lint issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LintSeverity {
+    /// Worth a look, but the quest can still be registered and inscribed
+    Warning,
+    /// Registration or inscription would fail
+    Error,
+}
+
+/// One problem found by `EnochianCore::lint_quest`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintIssue {
+    /// How serious the problem is
+    pub severity: LintSeverity,
+    /// Field the problem was found in, e.g. "choice_branches[1]"
+    pub location: String,
+    /// What is wrong
+    pub message: String,
+}
+
+/// Every issue found in a quest before registration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestLintReport {
+    /// Quest ID
+    pub quest_id: String,
+    /// Issues in the order the checks ran
+    pub issues: Vec<LintIssue>,
+}
+
+impl QuestLintReport {
+    /// Whether no issues were found
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+    
+    /// Whether any issue would block registration
+    pub fn has_errors(&self) -> bool {
+        self.issues.iter().any(|issue| issue.severity == LintSeverity::Error)
+    }
+    
+}
+
+/// Issue found by `EnochianCore::check_quest`
+enum QuestIssue {
+    /// Reported by the lint; registration goes ahead
+    Warning { location: String, message: String },
+    /// Registration fails with `error`
+    Error { location: String, error: EnochianError },
+}
+
+impl QuestIssue {
+    fn warning(location: impl Into<String>, message: String) -> Self {
+        QuestIssue::Warning { location: location.into(), message }
+    }
+    
+    fn error(location: impl Into<String>, error: EnochianError) -> Self {
+        QuestIssue::Error { location: location.into(), error }
+    }
+    
+    fn generic(location: impl Into<String>, message: String) -> Self {
+        QuestIssue::error(location, EnochianError::Generic { message })
+    }
+}
+
+impl From<QuestIssue> for LintIssue {
+    fn from(issue: QuestIssue) -> Self {
+        match issue {
+            QuestIssue::Warning { location, message } => LintIssue { severity: LintSeverity::Warning, location, message },
+            QuestIssue::Error { location, error } => LintIssue { severity: LintSeverity::Error, location, message: error.to_string() },
+        }
+    }
+}
+
+/// Handling of quests whose content duplicates a registered quest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DuplicateContentPolicy {
+    /// Register duplicates as separate quests
+    #[default]
+    Allow,
+    /// Reject duplicates with an error naming the existing quest
+    Reject,
+    /// Skip registration and return the existing quest's ID
+    ReturnExisting,
+}
+
+/// Player state snapshots, oldest first, each with its serialized size
+///
+/// Bounded both by count (`SystemConfig::max_state_history`) and by total
+/// serialized size (`SystemConfig::max_state_history_bytes`), since a single
+/// snapshot grows with a player's deferred rewards, tags and windows.
+#[derive(Debug, Clone, Default)]
+pub struct StateHistory {
+    snapshots: VecDeque<(GameState, usize)>,
+    total_bytes: usize,
+}
+
+impl StateHistory {
+    /// Number of snapshots held
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+    
+    /// Whether no snapshots are held
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+    
+    /// Serialized size of every snapshot held, in bytes
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+    
+    /// Snapshot at `index`, oldest first
+    pub fn get(&self, index: usize) -> Option<&GameState> {
+        self.snapshots.get(index).map(|(snapshot, _)| snapshot)
+    }
+    
+    /// Most recent snapshot
+    pub fn last(&self) -> Option<&GameState> {
+        self.snapshots.back().map(|(snapshot, _)| snapshot)
+    }
+    
+    /// Iterate over the snapshots, oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &GameState> {
+        self.snapshots.iter().map(|(snapshot, _)| snapshot)
+    }
+    
+    /// Append a snapshot, then drop the oldest until at most `max_len`
+    /// remain and they fit in `max_bytes`; the newest is kept even when it
+    /// alone exceeds `max_bytes`
+    fn record(&mut self, snapshot: Option<GameState>, max_len: usize, max_bytes: usize) {
+        if let Some(snapshot) = snapshot.filter(|_| max_len > 0) {
+            let size = serde_json::to_vec(&snapshot).map_or(0, |bytes| bytes.len());
+            self.total_bytes += size;
+            self.snapshots.push_back((snapshot, size));
+        }
+        
+        while self.snapshots.len() > max_len || (self.total_bytes > max_bytes && self.snapshots.len() > 1) {
+            match self.snapshots.pop_front() {
+                Some((_, size)) => self.total_bytes -= size,
+                None => break,
+            }
+        }
+    }
+    
+    /// Keep only the snapshots `keep` accepts, returning how many were removed
+    fn retain(&mut self, mut keep: impl FnMut(&GameState) -> bool) -> usize {
+        let before = self.snapshots.len();
+        self.snapshots.retain(|(snapshot, _)| keep(snapshot));
+        self.snapshots.shrink_to_fit();
+        self.total_bytes = self.snapshots.iter().map(|(_, size)| size).sum();
+        before - self.snapshots.len()
+    }
+}
+
+/// Number of recent authenticity scores kept for trend tracking
+pub const AUTHENTICITY_TREND_WINDOW: usize = 20;
+
+fn default_max_state_history() -> usize {
+    0
+}
+
+fn default_max_state_history_bytes() -> usize {
+    16 * 1024 * 1024
+}
+
+fn default_max_event_log() -> usize {
+    4096
+}
+
+fn default_max_player_snapshots() -> usize {
+    16
+}
+
+fn default_relationship_energy_discount() -> f64 {
+    0.4
+}
+
+fn default_council_min_relationship() -> f64 {
+    0.5
+}
+
+fn default_mastery_cap_window_blocks() -> u64 {
+    144
+}
+
+fn default_relationship_cap_window_blocks() -> u64 {
+    144
+}
+
+fn default_repeat_reward_scale() -> f64 {
+    1.0
+}
+
+/// Aethyr every player starts with access to; revocation never removes it
+pub const STARTING_AETHYR: u32 = 1;
+
+/// Player energy, always within 0-`Energy::MAX`
+///
+/// Arithmetic saturates at both ends, and deserializing an out-of-range value
+/// fails rather than clamping. Fractional changes are rounded to a whole
+/// amount by `Energy::round_change` before they apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct Energy(u32);
+
+impl Energy {
+    /// Highest energy a player can hold
+    pub const MAX: u32 = 25;
+    /// No energy
+    pub const ZERO: Energy = Energy(0);
+    /// Full energy, granted to new players
+    pub const FULL: Energy = Energy(Energy::MAX);
+    
+    /// Energy of `value`, rejecting values above `Energy::MAX`
+    pub fn new(value: u32) -> Result<Energy> {
+        if value > Energy::MAX {
+            return Err(EnochianError::InvalidEnergy { value, max: Energy::MAX });
+        }
+        Ok(Energy(value))
+    }
+    
+    /// Energy of `value`, clamped to `Energy::MAX`
+    pub fn saturating(value: u32) -> Energy {
+        Energy(value.min(Energy::MAX))
+    }
+    
+    /// Whole amount a fractional energy change applies as, rounding half to even
+    ///
+    /// 2.5 rounds to 2, 3.5 to 4 and -0.5 to 0. The change is rounded on its
+    /// own rather than after adding it to the current energy, so it lands the
+    /// same whatever the player holds. Returns `None` for NaN.
+    pub fn round_change(change: f64) -> Option<i64> {
+        if change.is_nan() {
+            return None;
+        }
+        Some(change.round_ties_even() as i64)
+    }
+    
+    /// Energy as a plain number
+    pub fn value(self) -> u32 {
+        self.0
+    }
+    
+    /// Add energy, stopping at `Energy::MAX`
+    pub fn saturating_add(self, amount: u32) -> Energy {
+        Energy(self.0.saturating_add(amount).min(Energy::MAX))
+    }
+    
+    /// Spend energy, stopping at zero
+    pub fn saturating_sub(self, amount: u32) -> Energy {
+        Energy(self.0.saturating_sub(amount))
+    }
+    
+    /// Apply a signed, possibly fractional change, rounded by
+    /// `Energy::round_change` and saturating at both ends
+    pub fn adjusted(self, change: f64) -> Energy {
+        match Energy::round_change(change) {
+            Some(change) => Energy(i64::from(self.0).saturating_add(change).clamp(0, i64::from(Energy::MAX)) as u32),
+            None => self,
+        }
+    }
+}
+
+impl TryFrom<u32> for Energy {
+    type Error = EnochianError;
+    
+    fn try_from(value: u32) -> Result<Energy> {
+        Energy::new(value)
+    }
+}
+
+impl From<Energy> for u32 {
+    fn from(energy: Energy) -> u32 {
+        energy.0
+    }
+}
+
+impl std::fmt::Display for Energy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Tradition mastery, always within 0.0-1.0
+///
+/// Arithmetic saturates at both ends, and deserializing an out-of-range or
+/// NaN value fails rather than clamping.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Mastery(f64);
+
+impl Mastery {
+    /// No mastery
+    pub const ZERO: Mastery = Mastery(0.0);
+    /// Complete mastery
+    pub const FULL: Mastery = Mastery(1.0);
+    
+    /// Mastery of `value`, rejecting NaN and values outside 0.0-1.0
+    pub fn new(value: f64) -> Result<Mastery> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(EnochianError::InvalidMastery { value });
+        }
+        Ok(Mastery(value))
+    }
+    
+    /// Mastery of `value` clamped into 0.0-1.0, with NaN treated as zero
+    pub fn clamped(value: f64) -> Mastery {
+        if value.is_nan() {
+            return Mastery::ZERO;
+        }
+        Mastery(value.clamp(0.0, 1.0))
+    }
+    
+    /// Mastery as a plain number
+    pub fn value(self) -> f64 {
+        self.0
+    }
+    
+    /// Apply a signed change, saturating at 0.0 and 1.0; a NaN change is ignored
+    pub fn saturating_add(self, change: f64) -> Mastery {
+        if change.is_nan() {
+            return self;
+        }
+        Mastery::clamped(self.0 + change)
+    }
+}
+
+impl TryFrom<f64> for Mastery {
+    type Error = EnochianError;
+    
+    fn try_from(value: f64) -> Result<Mastery> {
+        Mastery::new(value)
+    }
+}
+
+impl From<Mastery> for f64 {
+    fn from(mastery: Mastery) -> f64 {
+        mastery.0
+    }
+}
+
+impl std::fmt::Display for Mastery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Named tier of tradition mastery, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum MasteryRank {
+    /// Below `MasteryRankThresholds::initiate`
+    Novice,
+    /// From `MasteryRankThresholds::initiate`
+    Initiate,
+    /// From `MasteryRankThresholds::practitioner`
+    Practitioner,
+    /// From `MasteryRankThresholds::adept`
+    Adept,
+    /// From `MasteryRankThresholds::master`
+    Master,
+}
+
+impl MasteryRank {
+    /// Every rank, lowest first
+    pub const ALL: [MasteryRank; 5] = [
+        MasteryRank::Novice,
+        MasteryRank::Initiate,
+        MasteryRank::Practitioner,
+        MasteryRank::Adept,
+        MasteryRank::Master,
+    ];
+    
+    /// Display name, e.g. "Adept"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MasteryRank::Novice => "Novice",
+            MasteryRank::Initiate => "Initiate",
+            MasteryRank::Practitioner => "Practitioner",
+            MasteryRank::Adept => "Adept",
+            MasteryRank::Master => "Master",
+        }
+    }
+}
+
+impl std::fmt::Display for MasteryRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Mastery at which each rank above `MasteryRank::Novice` begins
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MasteryRankThresholds {
+    /// Start of `MasteryRank::Initiate`
+    pub initiate: f64,
+    /// Start of `MasteryRank::Practitioner`
+    pub practitioner: f64,
+    /// Start of `MasteryRank::Adept`
+    pub adept: f64,
+    /// Start of `MasteryRank::Master`
+    pub master: f64,
+}
+
+impl Default for MasteryRankThresholds {
+    fn default() -> Self {
+        MasteryRankThresholds {
+            initiate: 0.2,
+            practitioner: 0.4,
+            adept: 0.6,
+            master: 0.85,
+        }
+    }
+}
+
+impl MasteryRankThresholds {
+    /// Rank `mastery` falls in; a threshold belongs to the rank it starts
+    pub fn rank_of(&self, mastery: Mastery) -> MasteryRank {
+        let value = mastery.value();
+        [
+            (self.master, MasteryRank::Master),
+            (self.adept, MasteryRank::Adept),
+            (self.practitioner, MasteryRank::Practitioner),
+            (self.initiate, MasteryRank::Initiate),
+        ]
+        .into_iter()
+        .find(|(threshold, _)| value >= *threshold)
+        .map_or(MasteryRank::Novice, |(_, rank)| rank)
+    }
+    
+    /// Whether the thresholds rise strictly and lie within (0.0, 1.0]
+    pub fn is_valid(&self) -> bool {
+        0.0 < self.initiate
+            && self.initiate < self.practitioner
+            && self.practitioner < self.adept
+            && self.adept < self.master
+            && self.master <= 1.0
+    }
+}
+
+/// A player's mastery of a tradition moving into another rank
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankChange {
+    /// Player identifier
+    pub player_id: String,
+    /// Tradition whose mastery changed
+    pub tradition: String,
+    /// Rank before the change
+    pub from: MasteryRank,
+    /// Rank after
+    pub to: MasteryRank,
+    /// Quest whose rewards, choice or failure caused the change; `None` for
+    /// released deferred rewards, penalties and finalized transitions
+    pub quest_id: Option<String>,
+}
+
+impl RankChange {
+    /// Whether the player moved up
+    pub fn is_promotion(&self) -> bool {
+        self.to > self.from
+    }
+}
+
+/// A player rolled back to a local checkpoint by `EnochianCore::restore_player`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestoreEvent {
+    /// Player identifier
+    pub player_id: String,
+    /// Checkpoint restored
+    pub snapshot_id: SnapshotId,
+    /// Version of the state the checkpoint replaced
+    pub replaced_version: u32,
+    /// Version given to the restored state
+    pub restored_version: u32,
 }
 
 /// Game state for a player
@@ -43,20 +655,23 @@ pub struct GameState {
     pub block_height: u64,
     /// Completed quests
     pub completed_quests: Vec<String>,
+    /// Quests failed and not since completed
+    #[serde(default)]
+    pub failed_quests: Vec<String>,
     /// Active quests
     pub active_quests: Vec<String>,
     /// Tradition mastery levels
-    pub tradition_mastery: HashMap<String, f64>,
+    pub tradition_mastery: BTreeMap<String, Mastery>,
     /// Governor relationships
-    pub governor_relationships: HashMap<String, f64>,
+    pub governor_relationships: BTreeMap<String, f64>,
     /// Reputation scores
-    pub reputation_scores: HashMap<String, f64>,
+    pub reputation_scores: BTreeMap<String, f64>,
     /// Owned hypertokens
     pub owned_hypertokens: Vec<String>,
     /// Sacred items
     pub sacred_items: Vec<String>,
     /// Current energy level
-    pub energy_level: u32,
+    pub energy_level: Energy,
     /// Accessible Aethyr levels
     pub aethyr_access: Vec<u32>,
     /// Bitcoin balance in satoshis
@@ -67,456 +682,5423 @@ pub struct GameState {
     pub pending_rewards: u64,
     /// Overall authenticity score
     pub authenticity_score: f64,
+    /// Quest chains whose completion bonus has been granted
+    #[serde(default)]
+    pub completed_chains: Vec<String>,
+    /// Authenticity scores of recently completed quests, oldest first
+    #[serde(default)]
+    pub authenticity_history: Vec<f64>,
+    /// Choice IDs made in each active quest, in the order they were made
+    #[serde(default)]
+    pub quest_choices: BTreeMap<String, Vec<String>>,
+    /// Times each repeatable quest has been completed again after the first
+    #[serde(default)]
+    pub quest_repeat_counts: BTreeMap<String, u32>,
+    /// Block height at which each quest was last completed
+    #[serde(default)]
+    pub quest_completed_at: BTreeMap<String, u64>,
+    /// Indices of the objectives completed in each active quest
+    #[serde(default)]
+    pub completed_objectives: BTreeMap<String, Vec<usize>>,
+    /// Block height at which each governor last blessed a council quest
+    #[serde(default)]
+    pub council_blessed_at: BTreeMap<String, u64>,
+    /// Mastery gains held back by `SystemConfig::mastery_gain_cap`, by tradition
+    #[serde(default)]
+    pub deferred_mastery: BTreeMap<String, f64>,
+    /// Start block of the current cap window and mastery gained in it, by tradition
+    #[serde(default)]
+    pub mastery_gain_windows: BTreeMap<String, (u64, f64)>,
+    /// Relationship gains held back by `SystemConfig::relationship_gain_cap`, by governor
+    #[serde(default)]
+    pub deferred_relationships: BTreeMap<String, f64>,
+    /// Start block of the current cap window and relationship gained in it, by governor
+    #[serde(default)]
+    pub relationship_gain_windows: BTreeMap<String, (u64, f64)>,
+    /// First completions of quests carrying each tag
+    #[serde(default)]
+    pub tag_completions: BTreeMap<String, u32>,
     /// Last update timestamp
     pub last_update: String,
     /// State version
     pub version: u32,
 }
 
-/// Quest data structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QuestData {
-    /// Quest identifier
-    pub quest_id: String,
-    /// Quest title
-    pub title: String,
-    /// Quest description
-    pub description: String,
-    /// Quest objectives
-    pub objectives: Vec<String>,
-    /// Wisdom taught
-    pub wisdom_taught: String,
-    /// Choice branches
-    pub choice_branches: Vec<QuestChoice>,
-    /// Authenticity score
+/// Progression-only view of a player, safe to share on leaderboards or over P2P
+///
+/// Financial fields (`balance_sats`, `staked_amount`, `pending_rewards`) and
+/// holdings are left out; persist the full `GameState` instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicGameState {
+    /// Player identifier
+    pub player_id: String,
+    /// Current block height
+    pub block_height: u64,
+    /// Completed quests
+    pub completed_quests: Vec<String>,
+    /// Merkle root over the completed quests, for inclusion proofs
+    pub completed_quests_merkle_root: String,
+    /// Quest chains whose completion bonus has been granted
+    pub completed_chains: Vec<String>,
+    /// Tradition mastery levels
+    pub tradition_mastery: BTreeMap<String, Mastery>,
+    /// Governor relationships
+    pub governor_relationships: BTreeMap<String, f64>,
+    /// Reputation scores
+    pub reputation_scores: BTreeMap<String, f64>,
+    /// Accessible Aethyr levels
+    pub aethyr_access: Vec<u32>,
+    /// Overall authenticity score
     pub authenticity_score: f64,
-    /// Estimated duration in minutes
-    pub estimated_duration: u32,
-    /// Tradition integration
-    pub tradition_integration: Vec<String>,
-    /// Associated governor
-    pub governor_name: String,
-    /// Difficulty level
-    pub difficulty_level: u32,
-    /// Required energy
-    pub required_energy: u32,
-    /// Rewards
-    pub rewards: QuestRewards,
-    /// Creation timestamp
-    pub created_at: String,
+    /// First completions of quests carrying each tag
+    pub tag_completions: BTreeMap<String, u32>,
+    /// Headline progression metric, see `GameState::illumination_score`
+    pub illumination_score: f64,
+    /// State version
+    pub version: u32,
 }
 
-/// Quest choice structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QuestChoice {
-    /// Choice identifier
-    pub choice_id: String,
-    /// Choice description
-    pub description: String,
-    /// Consequences
-    pub consequences: Vec<String>,
-    /// Difficulty modifier
-    pub difficulty_modifier: f64,
-    /// Tradition alignment
-    pub tradition_alignment: f64,
-    /// Authenticity impact
-    pub authenticity_impact: f64,
-    /// Required traditions
-    pub required_traditions: Vec<String>,
-    /// Energy cost
-    pub energy_cost: u32,
-}
+/// Share of the illumination score from tradition mastery
+pub const ILLUMINATION_MASTERY_WEIGHT: f64 = 0.4;
+/// Share of the illumination score from Aethyr access
+pub const ILLUMINATION_AETHYR_WEIGHT: f64 = 0.25;
+/// Share of the illumination score from governor relationships
+pub const ILLUMINATION_RELATIONSHIP_WEIGHT: f64 = 0.2;
+/// Share of the illumination score from completed quests
+pub const ILLUMINATION_QUEST_WEIGHT: f64 = 0.15;
+/// Completed quests at which the quest component reaches half its share
+const ILLUMINATION_QUEST_HALF_POINT: f64 = 20.0;
 
-/// Quest rewards
+/// Initial state granted to a newly created player
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QuestRewards {
-    /// Experience points
-    pub experience: u32,
-    /// Reputation changes
-    pub reputation_changes: HashMap<String, f64>,
-    /// Tradition mastery gains
-    pub tradition_mastery_gains: HashMap<String, f64>,
-    /// Governor relationship changes
-    pub governor_relationship_changes: HashMap<String, f64>,
-    /// Bitcoin rewards in satoshis
-    pub bitcoin_rewards: u64,
-    /// Sacred items gained
-    pub sacred_items: Vec<String>,
-    /// Hypertoken rewards
-    pub hypertoken_rewards: Vec<String>,
-    /// Aethyr access gained
-    pub aethyr_access_gained: Vec<u32>,
+pub struct StartingLoadout {
+    /// Starting energy level
+    pub energy_level: Energy,
+    /// Starting tradition mastery levels
+    pub tradition_mastery: BTreeMap<String, Mastery>,
+    /// Initially accessible Aethyrs
+    pub aethyr_access: Vec<u32>,
+    /// Starting authenticity score
+    pub authenticity_score: f64,
 }
 
-impl Default for SystemConfig {
+impl Default for StartingLoadout {
     fn default() -> Self {
-        let mut tradition_weighting = HashMap::new();
-        tradition_weighting.insert("Enochian".to_string(), 0.6);
-        tradition_weighting.insert("Hermetic_Qabalah".to_string(), 0.15);
-        tradition_weighting.insert("Thelema".to_string(), 0.1);
-        tradition_weighting.insert("Golden_Dawn".to_string(), 0.1);
-        tradition_weighting.insert("Chaos_Magic".to_string(), 0.05);
+        let mut tradition_mastery = BTreeMap::new();
+        tradition_mastery.insert("Enochian".to_string(), Mastery(0.1));
         
-        SystemConfig {
-            authenticity_threshold: 0.95,
-            max_concurrent_quests: 3,
-            tradition_weighting,
-            governor_interaction_cooldown: 144, // 24 hours at 10min blocks
-            enable_p2p_sync: false,
-            enable_bitcoin_integration: false,
+        StartingLoadout {
+            energy_level: Energy::FULL,
+            tradition_mastery,
+            aethyr_access: vec![STARTING_AETHYR], // Start with access to first Aethyr
+            authenticity_score: 0.85,
         }
     }
 }
 
-impl EnochianCore {
-    /// Create a new Enochian Core instance
+impl GameState {
+    /// Rank of the player's mastery of `tradition` under `thresholds`
+    pub fn mastery_rank(&self, tradition: &str, thresholds: &MasteryRankThresholds) -> MasteryRank {
+        thresholds.rank_of(self.tradition_mastery.get(tradition).copied().unwrap_or_default())
+    }
+    
+    /// Record an authenticity score, keeping only the most recent window
+    pub fn record_authenticity(&mut self, score: f64) {
+        self.authenticity_history.push(score);
+        if self.authenticity_history.len() > AUTHENTICITY_TREND_WINDOW {
+            let excess = self.authenticity_history.len() - AUTHENTICITY_TREND_WINDOW;
+            self.authenticity_history.drain(..excess);
+        }
+    }
+    
+    /// Moving average and least-squares slope (per quest) of recent authenticity scores
+    ///
+    /// With no recorded history the current score is reported with a flat slope.
+    pub fn authenticity_trend(&self) -> (f64, f64) {
+        let scores = &self.authenticity_history;
+        if scores.is_empty() {
+            return (self.authenticity_score, 0.0);
+        }
+        
+        let n = scores.len() as f64;
+        let mean = scores.iter().sum::<f64>() / n;
+        let mean_index = (n - 1.0) / 2.0;
+        
+        let (covariance, variance) = scores.iter().enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, score)| {
+                let dx = i as f64 - mean_index;
+                (cov + dx * (score - mean), var + dx * dx)
+            });
+        let slope = if variance > 0.0 { covariance / variance } else { 0.0 };
+        
+        (mean, slope)
+    }
+    
+    /// Headline progression metric in [0, 1]
+    ///
+    /// Weighted sum of:
+    /// - tradition mastery (`ILLUMINATION_MASTERY_WEIGHT`): Enochian mastery
+    ///   carries the sacred 0.6 weighting, the other 0.4 is the mean mastery
+    ///   across the remaining 25 traditions
+    /// - Aethyr access (`ILLUMINATION_AETHYR_WEIGHT`): share of the 30 Aethyrs
+    /// - governor relationships (`ILLUMINATION_RELATIONSHIP_WEIGHT`): mean
+    ///   positive relationship across all 91 governors
+    /// - completed quests (`ILLUMINATION_QUEST_WEIGHT`): `n / (n + 20)`, so
+    ///   early quests count most
+    pub fn illumination_score(&self) -> f64 {
+        use crate::constants::{AETHYR_COUNT, ENOCHIAN_WEIGHTING, GOVERNOR_COUNT, TRADITION_COUNT};
+        
+        let enochian_mastery = self.tradition_mastery.get("Enochian").copied().unwrap_or_default().value();
+        let other_mastery = self.tradition_mastery.iter()
+            .filter(|(tradition, _)| tradition.as_str() != "Enochian")
+            .map(|(_, mastery)| mastery.value())
+            .sum::<f64>() / (TRADITION_COUNT - 1) as f64;
+        let mastery = ENOCHIAN_WEIGHTING * enochian_mastery + (1.0 - ENOCHIAN_WEIGHTING) * other_mastery.min(1.0);
+        
+        let mut aethyrs = self.aethyr_access.clone();
+        aethyrs.retain(|aethyr_id| validate_aethyr_id(*aethyr_id).is_ok());
+        aethyrs.sort_unstable();
+        aethyrs.dedup();
+        let aethyr = aethyrs.len() as f64 / AETHYR_COUNT as f64;
+        
+        let relationships = (self.governor_relationships.values()
+            .map(|relationship| relationship.clamp(0.0, 1.0))
+            .sum::<f64>() / GOVERNOR_COUNT as f64)
+            .min(1.0);
+        
+        let completed = self.completed_quests.len() as f64;
+        let quests = completed / (completed + ILLUMINATION_QUEST_HALF_POINT);
+        
+        (ILLUMINATION_MASTERY_WEIGHT * mastery
+            + ILLUMINATION_AETHYR_WEIGHT * aethyr
+            + ILLUMINATION_RELATIONSHIP_WEIGHT * relationships
+            + ILLUMINATION_QUEST_WEIGHT * quests)
+            .clamp(0.0, 1.0)
+    }
+    
+    /// Progression data only, without financial fields, for sharing
+    pub fn public_view(&self) -> PublicGameState {
+        PublicGameState {
+            player_id: self.player_id.clone(),
+            block_height: self.block_height,
+            completed_quests: self.completed_quests.clone(),
+            completed_quests_merkle_root: self.completed_quests_merkle_root(),
+            completed_chains: self.completed_chains.clone(),
+            tradition_mastery: self.tradition_mastery.clone(),
+            governor_relationships: self.governor_relationships.clone(),
+            reputation_scores: self.reputation_scores.clone(),
+            aethyr_access: self.aethyr_access.clone(),
+            authenticity_score: self.authenticity_score,
+            tag_completions: self.tag_completions.clone(),
+            illumination_score: self.illumination_score(),
+            version: self.version,
+        }
+    }
+    
+    /// Merkle root (hex SHA-256) over the sorted, deduplicated completed quest IDs
+    ///
+    /// Independent of completion order, so the root can be inscribed and
+    /// individual quests later proven against it with `verify_proof`.
+    pub fn completed_quests_merkle_root(&self) -> String {
+        merkle_root(&self.sorted_completed_quests())
+    }
+    
+    /// Inclusion proof for `quest_id` against `completed_quests_merkle_root`
+    pub fn merkle_proof(&self, quest_id: &str) -> Option<Vec<ProofNode>> {
+        merkle_path(&self.sorted_completed_quests(), quest_id)
+    }
+    
+    fn sorted_completed_quests(&self) -> Vec<String> {
+        let mut quest_ids = self.completed_quests.clone();
+        quest_ids.sort_unstable();
+        quest_ids.dedup();
+        quest_ids
+    }
+    
+    /// Grant access to an Aethyr, keeping `aethyr_access` sorted and unique
+    pub fn grant_aethyr_access(&mut self, aethyr_id: u32) -> Result<()> {
+        validate_aethyr_id(aethyr_id)?;
+        if !self.aethyr_access.contains(&aethyr_id) {
+            self.aethyr_access.push(aethyr_id);
+        }
+        self.aethyr_access.sort_unstable();
+        Ok(())
+    }
+    
+    /// Revoke access to an Aethyr, returning whether access was removed
+    ///
+    /// `STARTING_AETHYR` is kept, so a penalised player can always play on.
+    /// Nothing re-derives access from other state, so a revocation holds until
+    /// access is granted again by a reward or consequence.
+    pub fn revoke_aethyr_access(&mut self, aethyr_id: u32) -> Result<bool> {
+        validate_aethyr_id(aethyr_id)?;
+        if aethyr_id == STARTING_AETHYR {
+            return Ok(false);
+        }
+        let before = self.aethyr_access.len();
+        self.aethyr_access.retain(|aethyr| *aethyr != aethyr_id);
+        Ok(self.aethyr_access.len() != before)
+    }
+}
+
+/// Which side of the running hash a proof sibling is concatenated on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofSide {
+    /// Sibling is the left child
+    Left,
+    /// Sibling is the right child
+    Right,
+}
+
+/// One sibling hash on the path from a quest leaf to the Merkle root
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofNode {
+    /// Hex SHA-256 of the sibling subtree
+    pub hash: String,
+    /// Side the sibling sits on
+    pub side: ProofSide,
+}
+
+/// Check that `quest_id` is included under `root` via `proof`
+pub fn verify_proof(root: &str, quest_id: &str, proof: &[ProofNode]) -> bool {
+    let mut hash = merkle_leaf(quest_id);
+    for node in proof {
+        let sibling: [u8; 32] = match hex::decode(&node.hash).ok().and_then(|bytes| bytes.try_into().ok()) {
+            Some(sibling) => sibling,
+            None => return false,
+        };
+        hash = match node.side {
+            ProofSide::Left => merkle_branch(&sibling, &hash),
+            ProofSide::Right => merkle_branch(&hash, &sibling),
+        };
+    }
+    hex::encode(hash) == root.to_ascii_lowercase()
+}
+
+// Leaves and branches are domain-separated so a branch can never pass as a leaf
+fn merkle_leaf(quest_id: &str) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(quest_id.as_bytes());
+    hasher.finalize().into()
+}
+
+fn merkle_branch(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn empty_merkle_root() -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    
+    Sha256::digest([]).into()
+}
+
+/// Hex root over sorted, deduplicated leaves
+fn merkle_root(leaves: &[String]) -> String {
+    let levels = merkle_levels(leaves);
+    hex::encode(levels.last().and_then(|level| level.first()).copied().unwrap_or_else(empty_merkle_root))
+}
+
+/// Sibling path from `leaf` to the root of sorted, deduplicated `leaves`
+fn merkle_path(leaves: &[String], leaf: &str) -> Option<Vec<ProofNode>> {
+    let mut index = leaves.binary_search_by(|candidate| candidate.as_str().cmp(leaf)).ok()?;
+    let levels = merkle_levels(leaves);
+    
+    let mut proof = Vec::new();
+    for level in &levels[..levels.len() - 1] {
+        let sibling = index ^ 1;
+        // An unpaired last node is promoted without a sibling
+        if let Some(hash) = level.get(sibling) {
+            proof.push(ProofNode {
+                hash: hex::encode(hash),
+                side: if sibling < index { ProofSide::Left } else { ProofSide::Right },
+            });
+        }
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Every tree level from the leaves up; an unpaired last node is carried up unchanged
+fn merkle_levels(quest_ids: &[String]) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![quest_ids.iter().map(|id| merkle_leaf(id)).collect::<Vec<_>>()];
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let next = levels.last().unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => merkle_branch(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Outcome of a batch player creation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// Created player IDs, in input order
+    pub created: Vec<String>,
+    /// Player IDs that could not be created, with the reason, in input order
+    pub failed: Vec<(String, String)>,
+    /// Whether a strict batch removed its created players after a failure
+    pub rolled_back: bool,
+}
+
+/// Reject empty or overlong player IDs and IDs containing whitespace or control characters
+fn validate_player_id(player_id: &str) -> Result<()> {
+    let invalid = |reason: &str| Err(EnochianError::InvalidPlayerId {
+        player_id: player_id.to_string(),
+        reason: reason.to_string(),
+    });
+    
+    if player_id.is_empty() {
+        return invalid("must not be empty");
+    }
+    if player_id.len() > crate::constants::MAX_PLAYER_ID_LENGTH {
+        return invalid(&format!("longer than {} bytes", crate::constants::MAX_PLAYER_ID_LENGTH));
+    }
+    if player_id.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return invalid("must not contain whitespace or control characters");
+    }
+    Ok(())
+}
+
+/// Reject Aethyr IDs outside 1..=`AETHYR_COUNT`
+fn validate_aethyr_id(aethyr_id: u32) -> Result<()> {
+    if aethyr_id == 0 || aethyr_id > crate::constants::AETHYR_COUNT as u32 {
+        return Err(EnochianError::InvalidAethyr {
+            aethyr: aethyr_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Parse the Aethyr ID targeted by an `AethyrAccess` consequence
+fn parse_aethyr_target(target: &str) -> Result<u32> {
+    let aethyr_id = target.trim().parse::<u32>()
+        .map_err(|_| EnochianError::InvalidAethyr {
+            aethyr: target.to_string(),
+        })?;
+    validate_aethyr_id(aethyr_id)?;
+    Ok(aethyr_id)
+}
+
+/// Portion of a positive `pending` gain for `key` that the cap window at
+/// `block_height` still allows; the rest is added to `deferred`
+fn windowed_gain(
+    windows: &mut BTreeMap<String, (u64, f64)>,
+    deferred: &mut BTreeMap<String, f64>,
+    key: &str,
+    pending: f64,
+    cap: f64,
+    window_blocks: u64,
+    block_height: u64,
+) -> f64 {
+    let window = windows.entry(key.to_string()).or_insert((block_height, 0.0));
+    if block_height >= window.0 + window_blocks {
+        *window = (block_height, 0.0);
+    }
+    let granted = pending.min((cap - window.1).max(0.0));
+    window.1 += granted;
+    if pending > granted {
+        *deferred.entry(key.to_string()).or_default() += pending - granted;
+    }
+    granted
+}
+
+#[cfg(feature = "binary")]
+impl GameState {
+    /// Encode as compact postcard bytes (deterministic, typically under half the JSON size)
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        postcard::to_allocvec(self).map_err(|e| EnochianError::BinaryError {
+            message: e.to_string(),
+        })
+    }
+    
+    /// Decode from postcard bytes produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        postcard::from_bytes(bytes).map_err(|e| EnochianError::BinaryError {
+            message: e.to_string(),
+        })
+    }
+}
+
+/// Kind of action an objective asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectiveKind {
+    /// Study texts or teachings
+    Study,
+    /// Practice a technique or meditation
+    Practice,
+    /// Speak with a governor
+    Interact,
+    /// Perform a ritual
+    Ritual,
+    /// Scry or divine
+    Divination,
+}
+
+impl ObjectiveKind {
+    /// Action that completes an objective of this kind
+    pub fn completing_action(&self) -> ActionType {
+        match self {
+            ObjectiveKind::Study => ActionType::Study,
+            ObjectiveKind::Practice => ActionType::Practice,
+            ObjectiveKind::Interact => ActionType::InteractWithGovernor,
+            ObjectiveKind::Ritual => ActionType::PerformRitual,
+            ObjectiveKind::Divination => ActionType::Divination,
+        }
+    }
+    
+    /// Typical minutes to complete an objective of this kind at difficulty 1
+    pub fn base_minutes(&self) -> u32 {
+        match self {
+            ObjectiveKind::Study => 8,
+            ObjectiveKind::Practice => 10,
+            ObjectiveKind::Interact => 5,
+            ObjectiveKind::Ritual => 15,
+            ObjectiveKind::Divination => 12,
+        }
+    }
+    
+    /// Infer the kind of an untyped objective from its wording, defaulting to `Study`
+    pub fn infer(description: &str) -> ObjectiveKind {
+        let description = description.to_lowercase();
+        let mentions = |terms: &[&str]| terms.iter().any(|term| description.contains(term));
+        
+        if mentions(&["ritual", "invoke", "invocation", "ceremony"]) {
+            ObjectiveKind::Ritual
+        } else if mentions(&["scry", "divin", "vision", "oracle"]) {
+            ObjectiveKind::Divination
+        } else if mentions(&["speak", "commune", "converse", "consult", "governor"]) {
+            ObjectiveKind::Interact
+        } else if mentions(&["practice", "practise", "meditat", "exercise"]) {
+            ObjectiveKind::Practice
+        } else {
+            ObjectiveKind::Study
+        }
+    }
+}
+
+/// Player action kinds shared with the story engine state manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionType {
+    /// Start a quest
+    StartQuest,
+    /// Choose a quest branch
+    MakeChoice,
+    /// Complete a quest
+    CompleteQuest,
+    /// Speak with a governor
+    InteractWithGovernor,
+    /// Use a sacred item
+    UseSacredItem,
+    /// Perform a ritual
+    PerformRitual,
+    /// Study texts or teachings
+    Study,
+    /// Practice a technique or meditation
+    Practice,
+    /// Scry or divine
+    Divination,
+}
+
+/// Typed quest objective
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ObjectiveRepr")]
+pub struct QuestObjective {
+    /// Action the objective asks for
+    pub kind: ObjectiveKind,
+    /// Player-facing description
+    pub description: String,
+    /// What the action is directed at (governor, tradition, text), if anything
+    #[serde(default)]
+    pub target: Option<String>,
+}
+
+impl QuestObjective {
+    /// Create an objective without a target
+    pub fn new(kind: ObjectiveKind, description: &str) -> Self {
+        QuestObjective {
+            kind,
+            description: description.to_string(),
+            target: None,
+        }
+    }
+}
+
+impl std::fmt::Display for QuestObjective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+/// Objectives as stored: typed, or a legacy plain description
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ObjectiveRepr {
+    Typed {
+        kind: ObjectiveKind,
+        description: String,
+        #[serde(default)]
+        target: Option<String>,
+    },
+    Text(String),
+}
+
+impl From<ObjectiveRepr> for QuestObjective {
+    fn from(repr: ObjectiveRepr) -> Self {
+        match repr {
+            ObjectiveRepr::Typed { kind, description, target } => QuestObjective { kind, description, target },
+            ObjectiveRepr::Text(description) => QuestObjective::new(ObjectiveKind::infer(&description), &description),
+        }
+    }
+}
+
+/// Thematic tags a quest may carry, for grouping and progression tracking
+pub const QUEST_TAGS: [&str; 8] = [
+    "meditation",
+    "divination",
+    "ritual",
+    "study",
+    "invocation",
+    "scrying",
+    "alchemy",
+    "service",
+];
+
+/// Quest data structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestData {
+    /// Quest identifier
+    pub quest_id: String,
+    /// Quest title
+    pub title: String,
+    /// Quest description
+    pub description: String,
+    /// Quest objectives; plain strings are accepted and typed by their wording
+    pub objectives: Vec<QuestObjective>,
+    /// Wisdom taught
+    pub wisdom_taught: String,
+    /// Choice branches
+    pub choice_branches: Vec<QuestChoice>,
+    /// Authenticity score
+    pub authenticity_score: f64,
+    /// Estimated duration in minutes
+    pub estimated_duration: u32,
+    /// Tradition integration
+    pub tradition_integration: Vec<String>,
+    /// Associated governor
+    pub governor_name: String,
+    /// Difficulty level
+    pub difficulty_level: u32,
+    /// Required energy
+    pub required_energy: u32,
+    /// Rewards
+    pub rewards: QuestRewards,
+    /// Quest IDs that must be completed before this quest can start
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+    /// Whether the quest may be completed again (e.g. practice rituals)
+    #[serde(default)]
+    pub repeatable: bool,
+    /// Blocks after a completion before a repeatable quest can be started again
+    #[serde(default)]
+    pub repeat_cooldown_blocks: u32,
+    /// Scale applied to rewards for repeat completions
+    #[serde(default = "default_repeat_reward_scale")]
+    pub repeat_reward_scale: f64,
+    /// Governors who must all approve the quest's completion; empty for quests
+    /// bound to `governor_name` alone
+    #[serde(default)]
+    pub council: Vec<String>,
+    /// Penalties applied when the quest is failed, e.g. lost relationship or energy
+    #[serde(default)]
+    pub failure_consequences: Vec<StateConsequence>,
+    /// Thematic tags from `QUEST_TAGS`, e.g. "divination"
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Component weights to score this quest with instead of its tradition's
+    /// defaults, e.g. weighting historical accuracy for a reenactment
+    #[serde(default)]
+    pub authenticity_weight_overrides: Option<ScoringWeights>,
+    /// Creation timestamp
+    pub created_at: String,
+}
+
+/// Identifier of a player state checkpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SnapshotId(pub u64);
+
+impl std::fmt::Display for SnapshotId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "snapshot_{}", self.0)
+    }
+}
+
+/// Canonical quest identifier derived from a governor and generation seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct QuestId {
+    /// Governor ID (1-91)
+    pub governor_id: u32,
+    /// Generation seed
+    pub seed: u32,
+}
+
+impl QuestId {
+    /// Create a quest ID for a governor and seed
+    pub fn new(governor_id: u32, seed: u32) -> Self {
+        QuestId { governor_id, seed }
+    }
+}
+
+impl std::fmt::Display for QuestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "quest_{}_{}", self.governor_id, self.seed)
+    }
+}
+
+impl std::str::FromStr for QuestId {
+    type Err = EnochianError;
+    
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || EnochianError::Generic {
+            message: format!("Invalid quest ID: {}", s),
+        };
+        
+        let mut parts = s.split('_');
+        if parts.next() != Some("quest") {
+            return Err(invalid());
+        }
+        let governor_id = parts.next().and_then(|p| p.parse().ok()).ok_or_else(invalid)?;
+        let seed = parts.next().and_then(|p| p.parse().ok()).ok_or_else(invalid)?;
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        
+        // Reject non-canonical spellings such as leading zeros
+        let quest_id = QuestId::new(governor_id, seed);
+        if quest_id.to_string() != s {
+            return Err(invalid());
+        }
+        Ok(quest_id)
+    }
+}
+
+/// Ordered arc of quests that may pass through several governors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestChain {
+    /// Chain identifier
+    pub chain_id: String,
+    /// Chain title
+    pub title: String,
+    /// Quest IDs in the order the arc is meant to be played
+    pub quest_ids: Vec<String>,
+    /// Rewards granted once every quest in the chain is completed
+    pub completion_bonus: QuestRewards,
+}
+
+/// A player's progress through a quest chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainProgress {
+    /// Chain identifier
+    pub chain_id: String,
+    /// Number of chain quests the player has completed
+    pub completed: usize,
+    /// Number of quests in the chain
+    pub total: usize,
+    /// First chain quest the player has not yet completed
+    pub next_quest: Option<String>,
+    /// Whether the completion bonus has been granted
+    pub bonus_granted: bool,
+}
+
+/// A player's progress through the quests carrying one tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagProgress {
+    /// Quests with the tag the player has completed
+    pub completed: u32,
+    /// Registered quests with the tag
+    pub total: usize,
+}
+
+/// Outcome recorded in the quest event log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestOutcome {
+    /// Player started the quest
+    Started,
+    /// Player completed the quest
+    Completed,
+    /// Player abandoned the quest before completing it
+    Abandoned,
+    /// Player failed the quest, e.g. a lost challenge or a catastrophic choice
+    Failed,
+}
+
+/// Entry in the quest event log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestEvent {
+    /// Player identifier
+    pub player_id: String,
+    /// Quest identifier
+    pub quest_id: String,
+    /// What happened
+    pub outcome: QuestOutcome,
+    /// Why the quest was failed, for `QuestOutcome::Failed`
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Energy each difficulty level adds to a quest's cost
+const ENERGY_PER_DIFFICULTY: u32 = 2;
+
+/// Energy each objective adds to a quest's cost
+const ENERGY_PER_OBJECTIVE: u32 = 1;
+
+/// Energy cost suited to a quest's difficulty and objective count
+///
+/// Difficulty (clamped to 1-10) costs `ENERGY_PER_DIFFICULTY` per level and
+/// each objective `ENERGY_PER_OBJECTIVE`, capped at `Energy::MAX`. The cost
+/// never falls as difficulty or objectives rise.
+pub fn derive_required_energy(difficulty_level: u32, objective_count: usize) -> u32 {
+    let difficulty_cost = difficulty_level.clamp(1, 10) * ENERGY_PER_DIFFICULTY;
+    let objective_cost = u32::try_from(objective_count).unwrap_or(u32::MAX).saturating_mul(ENERGY_PER_OBJECTIVE);
+    difficulty_cost.saturating_add(objective_cost).min(Energy::MAX)
+}
+
+/// Minutes every quest takes regardless of objectives (briefing and wrap-up)
+const BASE_QUEST_MINUTES: u32 = 10;
+
+/// Minutes added per choice branch for weighing the decision
+const MINUTES_PER_BRANCH: u32 = 3;
+
+/// Growth in objective time per difficulty level above 1
+const DIFFICULTY_DURATION_STEP: f64 = 0.15;
+
+/// Estimated minutes to complete a quest with objectives of the given kinds
+///
+/// Each objective contributes its kind's `base_minutes`, scaled up by
+/// `DIFFICULTY_DURATION_STEP` for every difficulty level above 1, on top of
+/// a fixed base and a few minutes per choice branch. Adding objectives,
+/// branches or difficulty never shortens the estimate.
+pub fn estimate_quest_duration(objective_kinds: impl IntoIterator<Item = ObjectiveKind>, difficulty_level: u32, branch_count: usize) -> u32 {
+    let objective_minutes: u32 = objective_kinds.into_iter().map(|kind| kind.base_minutes()).sum();
+    let difficulty_scale = 1.0 + DIFFICULTY_DURATION_STEP * (difficulty_level.clamp(1, 10) - 1) as f64;
+    
+    BASE_QUEST_MINUTES
+        + (objective_minutes as f64 * difficulty_scale).round() as u32
+        + MINUTES_PER_BRANCH * branch_count as u32
+}
+
+/// Minimum number of finished attempts before difficulty is suggested
+pub const MIN_DIFFICULTY_SAMPLES: usize = 10;
+
+/// Completion rate expected of a correctly rated quest
+const TARGET_COMPLETION_RATE: f64 = 0.7;
+
+/// Quest choice structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestChoice {
+    /// Choice identifier
+    pub choice_id: String,
+    /// Choice description
+    pub description: String,
+    /// Consequences
+    pub consequences: Vec<String>,
+    /// Difficulty modifier
+    pub difficulty_modifier: f64,
+    /// Tradition alignment
+    pub tradition_alignment: f64,
+    /// Authenticity impact
+    pub authenticity_impact: f64,
+    /// Required traditions
+    pub required_traditions: Vec<String>,
+    /// Energy cost
+    pub energy_cost: u32,
+    /// Machine-readable effects applied when this choice is made
+    #[serde(default)]
+    pub structured_consequences: Vec<StateConsequence>,
+}
+
+/// Structured, machine-readable effect of a quest choice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateConsequence {
+    /// Kind of state the consequence modifies
+    pub consequence_type: ConsequenceType,
+    /// Target key (tradition, governor, reputation category, item or Aethyr ID)
+    pub target: String,
+    /// Signed change applied to the target
+    pub value_change: f64,
+}
+
+/// Consequence categories shared with the story engine state manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsequenceType {
+    /// Reputation score change
+    ReputationChange,
+    /// Tradition mastery change
+    TraditionMastery,
+    /// Governor relationship change
+    GovernorRelationship,
+    /// Energy level change
+    EnergyModification,
+    /// Sacred item gained
+    ItemGain,
+    /// Sacred item lost
+    ItemLoss,
+    /// Aethyr access gained
+    AethyrAccess,
+    /// Wisdom unlocked (no direct state change)
+    WisdomUnlock,
+    /// Aethyr access revoked (the starting Aethyr is never revoked)
+    AethyrRevoke,
+}
+
+/// Quest rewards
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuestRewards {
+    /// Experience points
+    pub experience: u32,
+    /// Reputation changes
+    pub reputation_changes: BTreeMap<String, f64>,
+    /// Tradition mastery gains
+    pub tradition_mastery_gains: BTreeMap<String, f64>,
+    /// Governor relationship changes
+    pub governor_relationship_changes: BTreeMap<String, f64>,
+    /// Bitcoin rewards in satoshis
+    pub bitcoin_rewards: u64,
+    /// Sacred items gained
+    pub sacred_items: Vec<String>,
+    /// Hypertoken rewards
+    pub hypertoken_rewards: Vec<String>,
+    /// Aethyr access gained
+    pub aethyr_access_gained: Vec<u32>,
+}
+
+impl StateConsequence {
+    /// Human-readable preview of the effect, e.g. "+0.05 Enochian mastery"
+    pub fn preview(&self) -> String {
+        match self.consequence_type {
+            ConsequenceType::ReputationChange => format!("{:+.2} {} reputation", self.value_change, self.target),
+            ConsequenceType::TraditionMastery => format!("{:+.2} {} mastery", self.value_change, self.target),
+            ConsequenceType::GovernorRelationship => format!("{:+.2} {} relationship", self.value_change, self.target),
+            ConsequenceType::EnergyModification => format!("{:+} energy", Energy::round_change(self.value_change).unwrap_or(0)),
+            ConsequenceType::ItemGain => format!("Gain {}", self.target),
+            ConsequenceType::ItemLoss => format!("Lose {}", self.target),
+            ConsequenceType::AethyrAccess => format!("Unlock Aethyr {}", self.target),
+            ConsequenceType::WisdomUnlock => format!("Unlock {}", self.target),
+            ConsequenceType::AethyrRevoke => format!("Lose access to Aethyr {}", self.target),
+        }
+    }
+}
+
+impl QuestData {
+    /// Plaintext of the quest exactly as it is inscribed
+    ///
+    /// Lists the title, governor, traditions, description, objectives, wisdom
+    /// and each choice with its consequences, in stored order. The quest ID and
+    /// numeric tuning (scores, energy, rewards) are not part of the text, so
+    /// the same content registered under two IDs renders identically. This is
+    /// the text that is previewed, fingerprinted and re-scored.
+    pub fn render_canonical(&self) -> String {
+        let mut lines = vec![
+            self.title.clone(),
+            format!("Governor: {}", self.governor_name),
+        ];
+        if !self.council.is_empty() {
+            lines.push(format!("Council: {}", self.council.join(", ")));
+        }
+        lines.push(format!("Traditions: {}", self.tradition_integration.join(", ")));
+        lines.push(String::new());
+        lines.push(self.description.clone());
+        
+        lines.push(String::new());
+        lines.push("Objectives:".to_string());
+        for (index, objective) in self.objectives.iter().enumerate() {
+            lines.push(format!("{}. {}", index + 1, objective));
+        }
+        
+        lines.push(String::new());
+        lines.push(format!("Wisdom: {}", self.wisdom_taught));
+        
+        if !self.choice_branches.is_empty() {
+            lines.push(String::new());
+            lines.push("Choices:".to_string());
+            for choice in &self.choice_branches {
+                lines.push(format!("- {}", choice.description));
+                for consequence in &choice.consequences {
+                    lines.push(format!("  * {}", consequence));
+                }
+            }
+        }
+        
+        let mut text = lines.join("\n");
+        text.push('\n');
+        text
+    }
+    
+    /// Estimated minutes to complete the quest, per `estimate_quest_duration`
+    pub fn estimate_duration(&self) -> u32 {
+        estimate_quest_duration(
+            self.objectives.iter().map(|objective| objective.kind),
+            self.difficulty_level,
+            self.choice_branches.len(),
+        )
+    }
+    
+    /// SHA-256 fingerprint of the canonical rendering, ignoring case and whitespace
+    pub fn content_fingerprint(&self) -> String {
+        use sha2::{Digest, Sha256};
+        
+        let normalized = self.render_canonical()
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+        hex::encode(Sha256::digest(normalized.as_bytes()))
+    }
+    
+    /// Reward scale in `[MIN_REWARD_SCALE, 1.0]` for a playthrough making the given choices
+    ///
+    /// The mean `authenticity_impact` of the chosen branches is placed between
+    /// the quest's least and most authentic choices: always picking the most
+    /// authentic branch earns full rewards, the least authentic earns
+    /// `MIN_REWARD_SCALE`. A playthrough with no recorded choices is scored at
+    /// the mean of all branches, and quests whose branches all share one impact
+    /// always pay in full.
+    pub fn choice_reward_scale(&self, choice_ids: &[String]) -> f64 {
+        let impacts: Vec<f64> = self.choice_branches.iter().map(|c| c.authenticity_impact).collect();
+        let lowest = impacts.iter().copied().fold(f64::INFINITY, f64::min);
+        let highest = impacts.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if impacts.is_empty() || (highest - lowest).abs() < f64::EPSILON {
+            return 1.0;
+        }
+        
+        let chosen: Vec<f64> = choice_ids.iter()
+            .filter_map(|id| self.choice_branches.iter().find(|c| &c.choice_id == id))
+            .map(|c| c.authenticity_impact)
+            .collect();
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        let played = if chosen.is_empty() { mean(&impacts) } else { mean(&chosen) };
+        
+        let position = ((played - lowest) / (highest - lowest)).clamp(0.0, 1.0);
+        MIN_REWARD_SCALE + (1.0 - MIN_REWARD_SCALE) * position
+    }
+}
+
+impl QuestChoice {
+    /// Preview lines for all structured consequences of this choice
+    pub fn consequence_preview(&self) -> Vec<String> {
+        self.structured_consequences.iter().map(|c| c.preview()).collect()
+    }
+}
+
+/// Smallest fraction of scalable rewards paid for the least authentic playthrough
+pub const MIN_REWARD_SCALE: f64 = 0.5;
+
+impl QuestRewards {
+    /// Copy with mastery gains, reputation changes and bitcoin scaled by `factor`
+    ///
+    /// Items, hypertokens, Aethyr access, relationships and experience are
+    /// unlocks rather than amounts and are left unchanged.
+    pub fn scaled(&self, factor: f64) -> QuestRewards {
+        let mut rewards = self.clone();
+        for gain in rewards.tradition_mastery_gains.values_mut() {
+            *gain *= factor;
+        }
+        for change in rewards.reputation_changes.values_mut() {
+            *change *= factor;
+        }
+        rewards.bitcoin_rewards = (rewards.bitcoin_rewards as f64 * factor).round() as u64;
+        rewards
+    }
+    
+    /// Copy with the total relationship change shared equally by `council`
+    ///
+    /// Returns the rewards unchanged when `council` is empty.
+    pub fn shared_by_council(&self, council: &[String]) -> QuestRewards {
+        let mut rewards = self.clone();
+        if council.is_empty() {
+            return rewards;
+        }
+        
+        let total: f64 = self.governor_relationship_changes.values().sum();
+        let share = total / council.len() as f64;
+        rewards.governor_relationship_changes = council.iter()
+            .map(|governor| (governor.clone(), share))
+            .collect();
+        rewards
+    }
+}
+
+/// Liveness and readiness report for server deployments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Ready to serve requests (initialized, not shut down and sacred constraints hold)
+    pub ready: bool,
+    /// Initialized status
+    pub initialized: bool,
+    /// Whether every sacred constraint check passed
+    pub sacred_constraints_passed: bool,
+    /// Names of failing sacred constraint checks
+    pub failed_constraints: Vec<String>,
+    /// Number of players with game state
+    pub player_count: usize,
+    /// Number of registered quests
+    pub quest_count: usize,
+    /// Crate version
+    pub version: String,
+    /// Git commit hash at build time
+    pub git_hash: String,
+    /// Build timestamp
+    pub build_time: String,
+    /// Seconds since the core was constructed
+    pub uptime_seconds: u64,
+    /// Whether `EnochianCore::shutdown` has closed the core
+    pub closed: bool,
+}
+
+/// State persisted by `EnochianCore::flush` or `shutdown`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlushSummary {
+    /// Players whose changed states were persisted, sorted
+    pub players: Vec<String>,
+    /// Number of journal entries persisted
+    pub journal_entries: usize,
+}
+
+impl Default for SystemConfig {
+    fn default() -> Self {
+        let mut tradition_weighting = BTreeMap::new();
+        tradition_weighting.insert("Enochian".to_string(), crate::constants::ENOCHIAN_WEIGHTING);
+        tradition_weighting.insert("Hermetic_Qabalah".to_string(), 0.15);
+        tradition_weighting.insert("Thelema".to_string(), 0.1);
+        tradition_weighting.insert("Golden_Dawn".to_string(), 0.1);
+        tradition_weighting.insert("Chaos_Magic".to_string(), 0.05);
+        
+        SystemConfig {
+            authenticity_threshold: 0.95,
+            max_concurrent_quests: 3,
+            tradition_weighting,
+            governor_interaction_cooldown: 144, // 24 hours at 10min blocks
+            enable_p2p_sync: false,
+            enable_bitcoin_integration: false,
+            max_state_history: default_max_state_history(),
+            max_state_history_bytes: default_max_state_history_bytes(),
+            max_event_log: default_max_event_log(),
+            duplicate_content_policy: DuplicateContentPolicy::Allow,
+            max_player_snapshots: default_max_player_snapshots(),
+            relationship_energy_discount: default_relationship_energy_discount(),
+            council_min_relationship: default_council_min_relationship(),
+            quarantine_on_revalidation: false,
+            default_to_enochian: false,
+            energy_tolerance: None,
+            mastery_gain_cap: None,
+            mastery_cap_window_blocks: default_mastery_cap_window_blocks(),
+            relationship_gain_cap: None,
+            relationship_cap_window_blocks: default_relationship_cap_window_blocks(),
+            strict_tags: false,
+            record_journal: false,
+            mastery_rank_thresholds: MasteryRankThresholds::default(),
+        }
+    }
+}
+
+impl SystemConfig {
+    /// Check the configuration against the sacred constraints without
+    /// stopping at the first failure
+    pub fn checks(&self) -> Vec<ConstraintCheck> {
+        let enochian_weight = self.tradition_weighting.get("Enochian").copied().unwrap_or(0.0);
+        
+        vec![
+            ConstraintCheck::new(
+                "authenticity_threshold",
+                (0.8..=1.0).contains(&self.authenticity_threshold),
+                format!("Authenticity threshold must be between 0.8 and 1.0, found {}", self.authenticity_threshold),
+            ),
+            ConstraintCheck::new(
+                "max_concurrent_quests",
+                (1..=10).contains(&self.max_concurrent_quests),
+                format!("Max concurrent quests must be between 1 and 10, found {}", self.max_concurrent_quests),
+            ),
+            ConstraintCheck::new(
+                "enochian_weighting",
+                enochian_weight >= 0.5,
+                format!("Enochian tradition must have at least 50% weighting, found {}", enochian_weight),
+            ),
+            ConstraintCheck::new(
+                "relationship_energy_discount",
+                (0.0..=1.0).contains(&self.relationship_energy_discount),
+                format!("Relationship energy discount must be between 0.0 and 1.0, found {}", self.relationship_energy_discount),
+            ),
+            ConstraintCheck::new(
+                "council_min_relationship",
+                (-1.0..=1.0).contains(&self.council_min_relationship),
+                format!("Council minimum relationship must be between -1.0 and 1.0, found {}", self.council_min_relationship),
+            ),
+            ConstraintCheck::new(
+                "reward_caps",
+                self.mastery_gain_cap.is_none_or(|cap| cap > 0.0 && cap <= 1.0)
+                    && self.relationship_gain_cap.is_none_or(|cap| cap > 0.0 && cap <= 2.0)
+                    && self.mastery_cap_window_blocks >= 1
+                    && self.relationship_cap_window_blocks >= 1,
+                format!(
+                    "Reward caps must be positive (mastery at most 1.0, relationship at most 2.0) over windows of at least 1 block, found mastery {:?} per {} blocks and relationship {:?} per {} blocks",
+                    self.mastery_gain_cap, self.mastery_cap_window_blocks, self.relationship_gain_cap, self.relationship_cap_window_blocks
+                ),
+            ),
+            ConstraintCheck::new(
+                "mastery_rank_thresholds",
+                self.mastery_rank_thresholds.is_valid(),
+                format!("Mastery rank thresholds must rise strictly within (0.0, 1.0], found {:?}", self.mastery_rank_thresholds),
+            ),
+        ]
+    }
+    
+    /// Copy of `state` for the state history, or `None` when the history
+    /// is disabled
+    fn history_snapshot(&self, state: &GameState) -> Option<GameState> {
+        (self.max_state_history > 0).then(|| state.clone())
+    }
+    
+    /// Energy `player_state` pays to start `quest`
+    ///
+    /// The cost falls linearly with a positive relationship towards the quest's
+    /// governor, `required_energy * (1 - relationship_energy_discount * relationship)`,
+    /// rounded up. Neutral and hostile relationships pay the full cost, and a
+    /// quest with any cost never costs less than 1.
+    pub fn effective_energy_cost(&self, player_state: &GameState, quest: &QuestData) -> u32 {
+        if quest.required_energy == 0 {
+            return 0;
+        }
+        
+        let relationship = player_state.governor_relationships
+            .get(&quest.governor_name).copied().unwrap_or(0.0)
+            .clamp(0.0, 1.0);
+        let discount = self.relationship_energy_discount.clamp(0.0, 1.0) * relationship;
+        let cost = (quest.required_energy as f64 * (1.0 - discount)).ceil() as u32;
+        cost.clamp(1, quest.required_energy)
+    }
+    
+    /// Validate the configuration before handing it to `EnochianCore`
+    pub fn validate(&self) -> Result<()> {
+        match self.checks().into_iter().find(|check| !check.passed) {
+            Some(check) => Err(EnochianError::SacredConstraintViolation {
+                constraint: check.message,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl EnochianCore {
+    /// Create a new Enochian Core instance for standalone play, using the
+    /// system clock and operating system randomness
     pub fn new(config: SystemConfig) -> Self {
+        Self::with_sources(config, Arc::new(SystemClock), Arc::new(SystemRandom))
+    }
+    
+    /// Create a core drawing time and randomness from the given sources
+    ///
+    /// Cores built from identical deterministic sources (see `FixedClock` and
+    /// `SeededRandom`) produce identical states for identical calls, as P2P
+    /// nodes and tests need. Clones share their sources.
+    pub fn with_sources(config: SystemConfig, clock: Arc<dyn Clock>, random: Arc<dyn RandomSource>) -> Self {
         EnochianCore {
+            journal: config.record_journal.then(|| InscriptionJournal::new(config.clone())),
             config,
-            game_states: HashMap::new(),
-            quest_registry: HashMap::new(),
+            game_states: BTreeMap::new(),
+            quest_registry: BTreeMap::new(),
+            state_history: StateHistory::default(),
+            tradition_manager: TraditionManager::new(),
+            content_fingerprints: BTreeMap::new(),
+            quest_chains: BTreeMap::new(),
+            quest_events: Vec::new(),
+            rank_events: Vec::new(),
+            restore_events: Vec::new(),
+            player_snapshots: BTreeMap::new(),
+            next_snapshot_id: 1,
+            quarantined_quests: BTreeSet::new(),
+            revalidated_lexicon: None,
+            health_scorer: OnceLock::new(),
             initialized: false,
+            started_at: clock.now(),
+            clock,
+            random,
+            sink: Arc::new(NullSink),
+            flushed_states: BTreeMap::new(),
+            flushed_journal_len: 0,
+            closed: false,
+        }
+    }
+    
+    /// Persist flushed state to `sink` rather than discarding it
+    pub fn with_sink(mut self, sink: Arc<dyn StateSink>) -> Self {
+        self.sink = sink;
+        self
+    }
+    
+    /// Clock the core takes timestamps from
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+    
+    /// Random source the core draws from
+    pub fn random(&self) -> &dyn RandomSource {
+        self.random.as_ref()
+    }
+    
+    /// Draw a quest seed from the core's random source, for standalone play
+    ///
+    /// Seeds for quests offered over P2P must instead come from
+    /// `derive_quest_seed`, which every node can verify.
+    pub fn next_quest_seed(&self) -> u32 {
+        (self.random.next_u64() >> 32) as u32
+    }
+    
+    /// Operations committed so far, if `SystemConfig::record_journal` is set
+    pub fn journal(&self) -> Option<&InscriptionJournal> {
+        self.journal.as_ref()
+    }
+    
+    /// SHA-256 (hex) over the player states and quest registry
+    ///
+    /// Journal entries carry the hash from before they ran, so replay can
+    /// check it is applying each one to the state it was recorded against.
+    pub fn state_hash(&self) -> String {
+        use sha2::{Digest, Sha256};
+        
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_vec(&self.game_states).unwrap_or_default());
+        hasher.update(serde_json::to_vec(&self.quest_registry).unwrap_or_default());
+        hex::encode(hasher.finalize())
+    }
+    
+    /// Persist every player state changed since the last flush, and the
+    /// journal entries recorded since, to the core's `StateSink`
+    ///
+    /// Players persisted before a sink error count as flushed; the rest stay
+    /// pending for the next flush.
+    pub fn flush(&mut self) -> Result<FlushSummary> {
+        quest_span!("flush");
+        
+        use sha2::{Digest, Sha256};
+        
+        let mut summary = FlushSummary::default();
+        for (player_id, state) in &self.game_states {
+            let hash = hex::encode(Sha256::digest(serde_json::to_vec(state)?));
+            if self.flushed_states.get(player_id) == Some(&hash) {
+                continue;
+            }
+            self.sink.persist_player(state)?;
+            self.flushed_states.insert(player_id.clone(), hash);
+            summary.players.push(player_id.clone());
+        }
+        
+        if let Some(journal) = &self.journal {
+            let pending = &journal.entries()[self.flushed_journal_len..];
+            if !pending.is_empty() {
+                self.sink.persist_journal(pending)?;
+                summary.journal_entries = pending.len();
+                self.flushed_journal_len = journal.len();
+            }
+        }
+        
+        log::info!("Flushed {} players and {} journal entries", summary.players.len(), summary.journal_entries);
+        Ok(summary)
+    }
+    
+    /// Flush pending state and close the core, e.g. on SIGTERM in server
+    /// deployments
+    ///
+    /// Once closed, player and registry mutations fail with
+    /// `EnochianError::CoreClosed`; reads, `flush` and local maintenance
+    /// (`revalidate_registry`, `compact_history`) still run. If the flush
+    /// fails the core stays open, so shutdown can be retried.
+    pub fn shutdown(&mut self) -> Result<FlushSummary> {
+        quest_span!("shutdown");
+        
+        let summary = self.flush()?;
+        self.closed = true;
+        log::info!("Enochian Core shut down");
+        Ok(summary)
+    }
+    
+    /// Whether `shutdown` has closed the core
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+    
+    fn ensure_open(&self, operation: &str) -> Result<()> {
+        if self.closed {
+            return Err(EnochianError::CoreClosed { operation: operation.to_string() });
+        }
+        Ok(())
+    }
+    
+    /// Build `op` only when it will be journaled
+    fn journal_op(&self, op: impl FnOnce() -> JournalOp) -> Option<JournalOp> {
+        self.journal.as_ref().map(|_| op())
+    }
+    
+    /// Run `operation` through `apply` on an open core, journaling `op` if it
+    /// succeeds
+    ///
+    /// A journaled operation sees one instant, taken from the clock at
+    /// millisecond precision and recorded with it, so replay reproduces its
+    /// timestamps exactly.
+    fn journaled<T>(&mut self, operation: &str, op: Option<JournalOp>, apply: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        self.ensure_open(operation)?;
+        let Some(op) = op else {
+            return apply(self);
+        };
+        
+        let prior_hash = self.state_hash();
+        let recorded_at = self.clock.now().timestamp_millis();
+        let clock = std::mem::replace(&mut self.clock, Arc::new(FixedClock::at_unix_millis(recorded_at)));
+        let result = apply(self);
+        self.clock = clock;
+        
+        if result.is_ok() {
+            if let Some(journal) = self.journal.as_mut() {
+                journal.record(op, recorded_at, prior_hash);
+            }
+        }
+        result
+    }
+    
+    /// Apply a journaled operation, as `journal::replay` does
+    pub(crate) fn apply_journal_op(&mut self, op: JournalOp) -> Result<()> {
+        match op {
+            JournalOp::CreatePlayer { player_id, loadout } => self.create_player_state_with_loadout(player_id, loadout).map(|_| ()),
+            JournalOp::RegisterQuest { quest } => self.register_quest(quest).map(|_| ()),
+            JournalOp::RegisterChain { chain } => self.register_chain(chain),
+            JournalOp::StartQuest { player_id, quest_id } => self.start_quest(&player_id, &quest_id),
+            JournalOp::MakeChoice { player_id, quest_id, choice_id } => self.make_quest_choice(&player_id, &quest_id, &choice_id).map(|_| ()),
+            JournalOp::CompleteObjective { player_id, quest_id, objective_index, action } => {
+                self.complete_objective(&player_id, &quest_id, objective_index, action)
+            },
+            JournalOp::CompleteQuest { player_id, quest_id } => self.complete_quest(&player_id, &quest_id).map(|_| ()),
+            JournalOp::AbandonQuest { player_id, quest_id } => self.abandon_quest(&player_id, &quest_id),
+            JournalOp::FailQuest { player_id, quest_id, reason } => self.fail_quest(&player_id, &quest_id, &reason),
+            JournalOp::ApplyPenalty { player_id, consequences } => self.apply_penalty(&player_id, &consequences),
+            JournalOp::ReleaseDeferredRewards { player_id } => self.release_deferred_rewards(&player_id),
+            JournalOp::FinalizeTransition { player_id, state } => self.update_player_state(&player_id, state),
+            JournalOp::RestorePlayer { player_id, snapshot_id, state } => self.restore_player_to(&player_id, snapshot_id, state),
+        }
+    }
+    
+    /// Initialize the core system
+    pub fn initialize(&mut self) -> Result<()> {
+        quest_span!("initialize");
+        
+        // Validate configuration
+        self.config.validate()?;
+        
+        // Initialize subsystems
+        self.initialize_subsystems()?;
+        
+        crate::build_info().log_warnings();
+        
+        self.initialized = true;
+        log::info!("Enochian Core initialized successfully");
+        Ok(())
+    }
+    
+    /// Create a new player game state
+    pub fn create_player_state(&mut self, player_id: String) -> Result<&GameState> {
+        self.create_player_state_with_loadout(player_id, StartingLoadout::default())
+    }
+    
+    /// Create new player game state from a custom starting loadout
+    pub fn create_player_state_with_loadout(&mut self, player_id: String, loadout: StartingLoadout) -> Result<&GameState> {
+        quest_span!("create_player_state", player_id = player_id);
+        
+        let op = self.journal_op(|| JournalOp::CreatePlayer { player_id: player_id.clone(), loadout: loadout.clone() });
+        self.journaled("create_player_state", op, |core| core.insert_player(player_id.clone(), loadout))?;
+        Ok(self.game_states.get(&player_id).unwrap())
+    }
+    
+    fn insert_player(&mut self, player_id: String, loadout: StartingLoadout) -> Result<()> {
+        validate_player_id(&player_id)?;
+        if self.game_states.contains_key(&player_id) {
+            return Err(EnochianError::DuplicatePlayerId { player_id });
+        }
+        
+        self.validate_loadout(&loadout)?;
+        
+        let game_state = GameState {
+            player_id: player_id.clone(),
+            block_height: 0,
+            completed_quests: Vec::new(),
+            failed_quests: Vec::new(),
+            active_quests: Vec::new(),
+            tradition_mastery: loadout.tradition_mastery,
+            governor_relationships: BTreeMap::new(),
+            reputation_scores: BTreeMap::new(),
+            owned_hypertokens: Vec::new(),
+            sacred_items: Vec::new(),
+            energy_level: loadout.energy_level,
+            aethyr_access: {
+                let mut aethyr_access = loadout.aethyr_access;
+                aethyr_access.sort_unstable();
+                aethyr_access
+            },
+            balance_sats: 0,
+            staked_amount: 0,
+            pending_rewards: 0,
+            authenticity_score: loadout.authenticity_score,
+            completed_chains: Vec::new(),
+            authenticity_history: Vec::new(),
+            quest_choices: BTreeMap::new(),
+            completed_objectives: BTreeMap::new(),
+            council_blessed_at: BTreeMap::new(),
+            deferred_mastery: BTreeMap::new(),
+            mastery_gain_windows: BTreeMap::new(),
+            deferred_relationships: BTreeMap::new(),
+            relationship_gain_windows: BTreeMap::new(),
+            tag_completions: BTreeMap::new(),
+            quest_repeat_counts: BTreeMap::new(),
+            quest_completed_at: BTreeMap::new(),
+            last_update: self.clock.now().to_rfc3339(),
+            version: 1,
+        };
+        
+        self.game_states.insert(player_id, game_state);
+        Ok(())
+    }
+    
+    /// Create default game states for many players
+    ///
+    /// Each ID is created independently: duplicates (including repeats within
+    /// the batch) and invalid IDs are reported per ID without stopping the rest.
+    pub fn create_players(&mut self, ids: Vec<String>) -> Result<BatchResult> {
+        quest_span!("create_players", count = ids.len());
+        
+        self.ensure_open("create_players")?;
+        let mut result = BatchResult::default();
+        for player_id in ids {
+            match self.create_player_state(player_id.clone()) {
+                Ok(_) => result.created.push(player_id),
+                Err(e) => result.failed.push((player_id, e.to_string())),
+            }
+        }
+        
+        log::info!("Created {} players, {} failed", result.created.len(), result.failed.len());
+        Ok(result)
+    }
+    
+    /// Create default game states for many players, all or nothing
+    ///
+    /// If any ID fails, every player created by the batch is removed again and
+    /// the result lists no created players, with `rolled_back` set.
+    pub fn create_players_strict(&mut self, ids: Vec<String>) -> Result<BatchResult> {
+        quest_span!("create_players_strict", count = ids.len());
+        
+        let journaled = self.journal.as_ref().map(InscriptionJournal::len);
+        let mut result = self.create_players(ids)?;
+        if !result.failed.is_empty() {
+            for player_id in result.created.drain(..) {
+                self.game_states.remove(&player_id);
+            }
+            if let (Some(journal), Some(len)) = (self.journal.as_mut(), journaled) {
+                journal.truncate(len);
+            }
+            result.rolled_back = true;
+        }
+        Ok(result)
+    }
+    
+    /// Get player game state
+    pub fn get_player_state(&self, player_id: &str) -> Option<&GameState> {
+        self.game_states.get(player_id)
+    }
+    
+    /// Rank of a player's mastery of `tradition` under the configured thresholds
+    pub fn mastery_rank(&self, player_id: &str, tradition: &str) -> Option<MasteryRank> {
+        self.game_states.get(player_id)
+            .map(|state| state.mastery_rank(tradition, &self.config.mastery_rank_thresholds))
+    }
+    
+    /// Update player game state
+    pub fn update_player_state(&mut self, player_id: &str, state: GameState) -> Result<()> {
+        quest_span!("update_player_state", player_id = player_id);
+        
+        let op = self.journal_op(|| JournalOp::FinalizeTransition { player_id: player_id.to_string(), state: state.clone() });
+        self.journaled("update_player_state", op, |core| core.update_player_state_inner(player_id, state))
+    }
+    
+    fn update_player_state_inner(&mut self, player_id: &str, mut state: GameState) -> Result<()> {
+        if !self.game_states.contains_key(player_id) {
+            return Err(EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            });
+        }
+        
+        // Validate state update
+        self.validate_state_update(&state)?;
+        state.aethyr_access.sort_unstable();
+        state.aethyr_access.dedup();
+        
+        self.move_mastery(player_id, &mut state);
+        
+        if let Some(previous) = self.game_states.insert(player_id.to_string(), state) {
+            self.state_history.record(Some(previous), self.config.max_state_history, self.config.max_state_history_bytes);
+        }
+        Ok(())
+    }
+    
+    /// Carry mastery from the player's current state over to `state` one
+    /// tradition at a time, so every rank change is recorded
+    fn move_mastery(&mut self, player_id: &str, state: &mut GameState) {
+        let incoming = std::mem::replace(&mut state.tradition_mastery, self.game_states[player_id].tradition_mastery.clone());
+        let traditions: BTreeSet<String> = incoming.keys().chain(state.tradition_mastery.keys()).cloned().collect();
+        for tradition in traditions {
+            let mastery = incoming.get(&tradition).copied().unwrap_or_default();
+            Self::record_bounded(&mut self.rank_events, self.config.max_event_log, Self::set_mastery(&self.config.mastery_rank_thresholds, state, &tradition, mastery));
+        }
+        state.tradition_mastery.retain(|tradition, _| incoming.contains_key(tradition));
+    }
+    
+    /// Checkpoint a player's state for later rollback
+    ///
+    /// Checkpoints are local (e.g. sandbox retries and testing) and separate
+    /// from the P2P state history. Only the newest `max_player_snapshots` are
+    /// kept per player.
+    pub fn snapshot_player(&mut self, player_id: &str) -> Result<SnapshotId> {
+        quest_span!("snapshot_player", player_id = player_id);
+        
+        self.ensure_open("snapshot_player")?;
+        let state = self.game_states.get(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?
+            .clone();
+        
+        let snapshot_id = SnapshotId(self.next_snapshot_id);
+        self.next_snapshot_id += 1;
+        
+        let snapshots = self.player_snapshots.entry(player_id.to_string()).or_default();
+        snapshots.push_back((snapshot_id, state));
+        while snapshots.len() > self.config.max_player_snapshots {
+            snapshots.pop_front();
+        }
+        
+        Ok(snapshot_id)
+    }
+    
+    /// Roll a player back to a checkpoint taken with `snapshot_player`
+    ///
+    /// The restored state gets a version newer than the state it replaces.
+    /// The restore is recorded in `restore_events` and journaled with the
+    /// checkpoint's state; like checkpoints, it stays out of the P2P state
+    /// history.
+    pub fn restore_player(&mut self, player_id: &str, snapshot_id: SnapshotId) -> Result<&GameState> {
+        quest_span!("restore_player", player_id = player_id, snapshot_id = snapshot_id);
+        
+        self.ensure_open("restore_player")?;
+        let snapshot = self.player_snapshots.get(player_id)
+            .and_then(|snapshots| snapshots.iter().find(|(id, _)| *id == snapshot_id))
+            .map(|(_, state)| state.clone())
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Snapshot {} not found for player {}", snapshot_id, player_id),
+            })?;
+        
+        self.restore_player_to(player_id, snapshot_id, snapshot)?;
+        Ok(&self.game_states[player_id])
+    }
+    
+    /// Replace a player's state with a checkpoint's, as `restore_player` and
+    /// journal replay do
+    fn restore_player_to(&mut self, player_id: &str, snapshot_id: SnapshotId, snapshot: GameState) -> Result<()> {
+        let op = self.journal_op(|| JournalOp::RestorePlayer { player_id: player_id.to_string(), snapshot_id, state: snapshot.clone() });
+        self.journaled("restore_player", op, |core| core.restore_player_inner(player_id, snapshot_id, snapshot))
+    }
+    
+    fn restore_player_inner(&mut self, player_id: &str, snapshot_id: SnapshotId, mut snapshot: GameState) -> Result<()> {
+        let replaced_version = self.game_states.get(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?
+            .version;
+        
+        self.move_mastery(player_id, &mut snapshot);
+        snapshot.version = replaced_version.max(snapshot.version) + 1;
+        snapshot.last_update = self.clock.now().to_rfc3339();
+        Self::record_bounded(&mut self.restore_events, self.config.max_event_log, [RestoreEvent {
+            player_id: player_id.to_string(),
+            snapshot_id,
+            replaced_version,
+            restored_version: snapshot.version,
+        }]);
+        self.game_states.insert(player_id.to_string(), snapshot);
+        
+        log::info!("Player {} restored to {}", player_id, snapshot_id);
+        Ok(())
+    }
+    
+    /// Register a quest, returning the ID it is registered under
+    pub fn register_quest(&mut self, quest: QuestData) -> Result<String> {
+        quest_span!("register_quest", quest_id = quest.quest_id);
+        
+        let op = self.journal_op(|| JournalOp::RegisterQuest { quest: quest.clone() });
+        let result = self.journaled("register_quest", op, |core| core.register_quest_inner(quest));
+        crate::metrics::global().observe(Counter::QuestsRegistered, result)
+    }
+    
+    fn register_quest_inner(&mut self, mut quest: QuestData) -> Result<String> {
+        if quest.tradition_integration.is_empty() && self.config.default_to_enochian {
+            quest.tradition_integration.push("Enochian".to_string());
+        }
+        
+        let first_error = self.check_quest(&quest).into_iter().find_map(|issue| match issue {
+            QuestIssue::Error { error, .. } => Some(error),
+            QuestIssue::Warning { .. } => None,
+        });
+        if let Some(error) = first_error {
+            return Err(error);
+        }
+        
+        // Duplicated content under a different ID passed the checks, so the
+        // policy allows it or returns the existing quest
+        let fingerprint = quest.content_fingerprint();
+        if let Some(existing_id) = self.content_fingerprints.get(&fingerprint) {
+            if self.config.duplicate_content_policy == DuplicateContentPolicy::ReturnExisting {
+                return Ok(existing_id.clone());
+            }
+        }
+        
+        let quest_id = quest.quest_id.clone();
+        self.content_fingerprints.entry(fingerprint).or_insert_with(|| quest_id.clone());
+        self.quest_registry.insert(quest_id.clone(), quest);
+        Ok(quest_id)
+    }
+    
+    /// Derive a quest seed from the player, governor, and a block hash
+    ///
+    /// Clients can't choose the seed, so offered quests are unbiased and anyone
+    /// can verify them. The block hash must come from a finalized block; an
+    /// unconfirmed tip could be reorganized away, letting clients reroll.
+    pub fn derive_quest_seed(player_id: &str, governor_id: u32, block_hash: &str) -> u32 {
+        use sha2::{Digest, Sha256};
+        
+        let mut hasher = Sha256::new();
+        hasher.update(b"enochian-quest-seed");
+        for field in [player_id.as_bytes(), &governor_id.to_be_bytes(), block_hash.as_bytes()] {
+            // Length prefixes keep ("ab", "c") and ("a", "bc") distinct
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field);
+        }
+        let digest = hasher.finalize();
+        u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]])
+    }
+    
+    /// Get quest data
+    pub fn get_quest(&self, quest_id: &str) -> Option<&QuestData> {
+        self.quest_registry.get(quest_id)
+    }
+    
+    /// Iterate over every registered quest, in quest ID order
+    pub fn quests(&self) -> impl Iterator<Item = &QuestData> {
+        self.quest_registry.values()
+    }
+    
+    /// Merkle root (hex SHA-256) over the sorted content fingerprints of every registered quest
+    ///
+    /// Commits the whole catalog in one hash. A thin client holding a quest
+    /// checks its membership with `verify_proof(root, &quest.content_fingerprint(), proof)`.
+    pub fn registry_merkle_root(&self) -> String {
+        merkle_root(&self.sorted_content_fingerprints())
+    }
+    
+    /// Inclusion proof for a registered quest against `registry_merkle_root`
+    pub fn registry_proof(&self, quest_id: &str) -> Option<Vec<ProofNode>> {
+        let fingerprint = self.quest_registry.get(quest_id)?.content_fingerprint();
+        merkle_path(&self.sorted_content_fingerprints(), &fingerprint)
+    }
+    
+    fn sorted_content_fingerprints(&self) -> Vec<String> {
+        let mut fingerprints: Vec<String> = self.quests().map(QuestData::content_fingerprint).collect();
+        fingerprints.sort_unstable();
+        fingerprints.dedup();
+        fingerprints
+    }
+    
+    /// Iterate over the registered quests offered by a governor
+    pub fn quests_by_governor<'a>(&'a self, governor_name: &'a str) -> impl Iterator<Item = &'a QuestData> + 'a {
+        self.quests().filter(move |quest| quest.governor_name == governor_name)
+    }
+    
+    /// Iterate over the registered quests carrying a tag
+    pub fn quests_by_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a QuestData> + 'a {
+        self.quests().filter(move |quest| quest.tags.iter().any(|t| t == tag))
+    }
+    
+    /// A player's progress through every tag in `QUEST_TAGS`, e.g.
+    /// 4 of 10 divination quests completed
+    pub fn tag_progress(&self, player_id: &str) -> Result<BTreeMap<String, TagProgress>> {
+        let player_state = self.game_states.get(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        Ok(QUEST_TAGS.iter()
+            .map(|&tag| (tag.to_string(), TagProgress {
+                completed: player_state.tag_completions.get(tag).copied().unwrap_or(0),
+                total: self.quests_by_tag(tag).count(),
+            }))
+            .collect())
+    }
+    
+    /// Recompute every quest's authenticity with the given scorer
+    ///
+    /// Returns `(quest_id, drift)` pairs sorted by quest ID, where drift is the
+    /// recomputed score minus the stored one. Stored scores are left unchanged.
+    pub fn rescore_all_authenticity(&self, scorer: &AuthenticityScorer) -> Vec<(String, f64)> {
+        let validated = scorer.get_validated_traditions();
+        
+        let mut drift: Vec<(String, f64)> = self.quests()
+            .map(|quest| (quest.quest_id.clone(), Self::recompute_authenticity(scorer, &validated, quest) - quest.authenticity_score))
+            .collect();
+        
+        drift.sort_by(|a, b| a.0.cmp(&b.0));
+        drift
+    }
+    
+    /// Whether the registry was last revalidated against a different lexicon
+    /// than `scorer`'s, or never revalidated at all
+    pub fn needs_revalidation(&self, scorer: &AuthenticityScorer) -> bool {
+        self.revalidated_lexicon.as_deref() != Some(scorer.lexicon_fingerprint().as_str())
+    }
+    
+    /// Re-score every quest with the given scorer and store the new scores
+    ///
+    /// Quests now below the authenticity threshold are flagged, and with
+    /// `quarantine_on_revalidation` set they are withheld from players until a
+    /// later revalidation passes them. Results are sorted by quest ID.
+    pub fn revalidate_registry(&mut self, scorer: &AuthenticityScorer) -> Vec<RevalidationResult> {
+        quest_span!("revalidate_registry", quests = self.quest_registry.len());
+        
+        let validated = scorer.get_validated_traditions();
+        let threshold = self.config.authenticity_threshold;
+        let quarantine = self.config.quarantine_on_revalidation;
+        
+        let mut results: Vec<RevalidationResult> = self.quest_registry.values_mut()
+            .map(|quest| {
+                let score = Self::recompute_authenticity(scorer, &validated, quest);
+                let previous_score = std::mem::replace(&mut quest.authenticity_score, score);
+                RevalidationResult {
+                    quest_id: quest.quest_id.clone(),
+                    previous_score,
+                    score,
+                    below_threshold: score < threshold,
+                    quarantined: false,
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| a.quest_id.cmp(&b.quest_id));
+        
+        for result in &mut results {
+            if result.below_threshold && quarantine {
+                self.quarantined_quests.insert(result.quest_id.clone());
+                result.quarantined = true;
+            } else if !result.below_threshold {
+                self.quarantined_quests.remove(&result.quest_id);
+            }
+        }
+        
+        let flagged = results.iter().filter(|result| result.below_threshold).count();
+        if flagged > 0 {
+            log::warn!("Revalidation flagged {} of {} quests below authenticity threshold {}", flagged, results.len(), threshold);
+        }
+        
+        self.revalidated_lexicon = Some(scorer.lexicon_fingerprint());
+        results
+    }
+    
+    /// Run every registration and inscription check on a quest without registering it
+    ///
+    /// Covers duplicate IDs and content, the authenticity threshold and branch
+    /// floor, traditions and tags, difficulty and energy, prerequisites
+    /// (unknown or cyclic), reward and consequence bounds, size limits and
+    /// whether the canonical rendering can be inscribed and survives
+    /// serialization unchanged. `register_quest` runs the same checks and
+    /// fails with the first error reported here.
+    pub fn lint_quest(&self, quest: &QuestData) -> QuestLintReport {
+        QuestLintReport {
+            quest_id: quest.quest_id.clone(),
+            issues: self.check_quest(quest).into_iter().map(LintIssue::from).collect(),
+        }
+    }
+    
+    /// Every issue in a quest, in the order the checks run
+    fn check_quest(&self, quest: &QuestData) -> Vec<QuestIssue> {
+        let threshold = self.config.authenticity_threshold;
+        let mut issues = Vec::new();
+        
+        if self.quest_registry.contains_key(&quest.quest_id) {
+            issues.push(QuestIssue::error("quest_id", EnochianError::DuplicateQuestId {
+                quest_id: quest.quest_id.clone(),
+            }));
+        }
+        
+        // Authenticity, overall and on every branch
+        if quest.authenticity_score < threshold {
+            issues.push(QuestIssue::error("authenticity_score", EnochianError::AuthenticityError {
+                message: format!("Quest authenticity {} below threshold {}", quest.authenticity_score, threshold),
+            }));
+        }
+        for (index, choice) in quest.choice_branches.iter().enumerate() {
+            let location = format!("choice_branches[{}]", index);
+            let branch_authenticity = quest.authenticity_score + choice.authenticity_impact;
+            if branch_authenticity < threshold {
+                issues.push(QuestIssue::error(&location, EnochianError::AuthenticityError {
+                    message: format!("Choice {} authenticity {} below threshold {}", choice.choice_id, branch_authenticity, threshold),
+                }));
+            }
+            for consequence in &choice.structured_consequences {
+                if let Err(error) = Self::validate_state_consequences(std::slice::from_ref(consequence)) {
+                    issues.push(QuestIssue::error(&location, error));
+                }
+            }
+        }
+        
+        for consequence in &quest.failure_consequences {
+            if let Err(error) = Self::validate_state_consequences(std::slice::from_ref(consequence)) {
+                issues.push(QuestIssue::error("failure_consequences", error));
+            }
+        }
+        if let Some(Err(error)) = quest.authenticity_weight_overrides.as_ref().map(ScoringWeights::validate) {
+            issues.push(QuestIssue::error("authenticity_weight_overrides", error));
+        }
+        
+        // Blended scoring and recommendations need a known tradition;
+        // registration adds Enochian to an empty list when defaulting
+        if quest.tradition_integration.is_empty() && !self.config.default_to_enochian {
+            issues.push(QuestIssue::generic("tradition_integration", format!("Quest {} integrates no traditions", quest.quest_id)));
+        }
+        let unknown: Vec<usize> = (0..quest.tradition_integration.len())
+            .filter(|&index| self.tradition_manager.get_tradition(&quest.tradition_integration[index]).is_none())
+            .collect();
+        if !quest.tradition_integration.is_empty() && unknown.len() == quest.tradition_integration.len() {
+            issues.push(QuestIssue::error("tradition_integration", EnochianError::TraditionNotSupported {
+                tradition: quest.tradition_integration.join(", "),
+            }));
+        } else {
+            for index in unknown {
+                issues.push(QuestIssue::warning(format!("tradition_integration[{}]", index), format!("Unknown tradition {}", quest.tradition_integration[index])));
+            }
+        }
+        
+        // Tags must come from the vocabulary in strict mode
+        for (index, tag) in quest.tags.iter().enumerate() {
+            if QUEST_TAGS.contains(&tag.as_str()) {
+                continue;
+            }
+            let location = format!("tags[{}]", index);
+            issues.push(if self.config.strict_tags {
+                QuestIssue::error(location, EnochianError::UnknownQuestTag {
+                    quest_id: quest.quest_id.clone(),
+                    tag: tag.clone(),
+                })
+            } else {
+                QuestIssue::warning(location, format!("Unknown tag {}", tag))
+            });
+        }
+        
+        // Difficulty and energy
+        if quest.difficulty_level == 0 || quest.difficulty_level > 10 {
+            issues.push(QuestIssue::generic("difficulty_level", format!("Quest difficulty {} outside 1-10", quest.difficulty_level)));
+        }
+        if quest.required_energy > Energy::MAX {
+            issues.push(QuestIssue::generic("required_energy", format!("Quest cannot require more than {} energy", Energy::MAX)));
+        }
+        if let Some(tolerance) = self.config.energy_tolerance {
+            let derived = derive_required_energy(quest.difficulty_level, quest.objectives.len());
+            if quest.required_energy.abs_diff(derived) > tolerance {
+                issues.push(QuestIssue::generic("required_energy", format!(
+                    "Quest requires {} energy but difficulty {} with {} objectives suggests {} (tolerance {})",
+                    quest.required_energy,
+                    quest.difficulty_level,
+                    quest.objectives.len(),
+                    derived,
+                    tolerance
+                )));
+            }
+        }
+        
+        // Prerequisites must exist and must not lead back to this quest
+        for (index, prerequisite) in quest.prerequisites.iter().enumerate() {
+            let location = format!("prerequisites[{}]", index);
+            if prerequisite == &quest.quest_id || self.prerequisite_reaches(prerequisite, &quest.quest_id) {
+                issues.push(QuestIssue::generic(location, format!("Prerequisite {} leads back to quest {}", prerequisite, quest.quest_id)));
+            } else if !self.quest_registry.contains_key(prerequisite) {
+                issues.push(QuestIssue::warning(location, format!("Prerequisite {} is not registered", prerequisite)));
+            }
+        }
+        
+        // Rewards
+        for aethyr_id in &quest.rewards.aethyr_access_gained {
+            if let Err(error) = validate_aethyr_id(*aethyr_id) {
+                issues.push(QuestIssue::error("rewards.aethyr_access_gained", error));
+            }
+        }
+        for (tradition, gain) in &quest.rewards.tradition_mastery_gains {
+            if !(0.0..=1.0).contains(gain) {
+                issues.push(QuestIssue::generic("rewards.tradition_mastery_gains", format!("{} mastery gain {} outside 0.0-1.0", tradition, gain)));
+            }
+        }
+        for (governor, change) in &quest.rewards.governor_relationship_changes {
+            if !(-1.0..=1.0).contains(change) {
+                issues.push(QuestIssue::generic("rewards.governor_relationship_changes", format!("{} relationship change {} outside -1.0-1.0", governor, change)));
+            }
+        }
+        for (faction, change) in &quest.rewards.reputation_changes {
+            if !change.is_finite() {
+                issues.push(QuestIssue::generic("rewards.reputation_changes", format!("{} reputation change {} is not finite", faction, change)));
+            }
+        }
+        if !(0.0..=1.0).contains(&quest.repeat_reward_scale) {
+            issues.push(QuestIssue::generic("repeat_reward_scale", format!("Repeat reward scale {} outside 0.0-1.0", quest.repeat_reward_scale)));
+        }
+        
+        // Size limits and rendering stability
+        let rendering = quest.render_canonical();
+        if rendering.len() > crate::constants::MAX_SCORING_CONTENT_SIZE {
+            issues.push(QuestIssue::warning("content", format!(
+                "Rendering is {} bytes; only {} can be rescored",
+                rendering.len(), crate::constants::MAX_SCORING_CONTENT_SIZE
+            )));
+        }
+        if let Err(error) = crate::ordinals::sanitize_for_inscription(&rendering) {
+            issues.push(QuestIssue::error("content", error));
+        }
+        let round_tripped = serde_json::to_value(quest)
+            .and_then(serde_json::from_value::<QuestData>)
+            .map(|copy| copy.render_canonical());
+        match round_tripped {
+            Ok(copy) if copy == rendering => {},
+            Ok(_) => issues.push(QuestIssue::generic("content", "Rendering changes after a serialization round trip".to_string())),
+            Err(error) => issues.push(QuestIssue::generic("content", format!("Quest does not survive serialization: {}", error))),
+        }
+        
+        // Duplicated content under a different ID
+        let duplicate_of = self.content_fingerprints.get(&quest.content_fingerprint())
+            .filter(|existing_id| **existing_id != quest.quest_id);
+        if let Some(existing_id) = duplicate_of {
+            match self.config.duplicate_content_policy {
+                DuplicateContentPolicy::Allow => {},
+                DuplicateContentPolicy::Reject => {
+                    issues.push(QuestIssue::generic("content", format!("Quest {} duplicates existing quest {}", quest.quest_id, existing_id)));
+                },
+                DuplicateContentPolicy::ReturnExisting => {
+                    issues.push(QuestIssue::warning("content", format!("Content duplicates quest {}, whose ID registration returns instead", existing_id)));
+                },
+            }
+        }
+        
+        issues
+    }
+    
+    /// Whether `target` is among the registered prerequisites of `start`, transitively
+    fn prerequisite_reaches(&self, start: &str, target: &str) -> bool {
+        let mut stack = vec![start];
+        let mut seen = std::collections::HashSet::new();
+        while let Some(quest_id) = stack.pop() {
+            if !seen.insert(quest_id) {
+                continue;
+            }
+            if let Some(quest) = self.quest_registry.get(quest_id) {
+                for prerequisite in &quest.prerequisites {
+                    if prerequisite == target {
+                        return true;
+                    }
+                    stack.push(prerequisite);
+                }
+            }
+        }
+        false
+    }
+    
+    fn recompute_authenticity(scorer: &AuthenticityScorer, validated: &[String], quest: &QuestData) -> f64 {
+        let tradition = quest.tradition_integration.iter()
+            .find(|tradition| validated.contains(tradition))
+            .map(String::as_str)
+            .unwrap_or("Enochian");
+        
+        let content = quest.render_canonical();
+        
+        let score = match &quest.authenticity_weight_overrides {
+            Some(weights) => scorer.calculate_authenticity_with_weights(&content, tradition, ContentType::Quest, &[], weights),
+            None => scorer.calculate_authenticity(&content, tradition, ContentType::Quest, &[]),
+        };
+        score.map(|score| score.overall_score)
+            .unwrap_or_else(|_| scorer.quick_score(&content))
+    }
+    
+    /// Register a quest chain
+    ///
+    /// Every referenced quest must already be registered, and the chain must be
+    /// acyclic: no quest may appear twice or require a quest later in the chain.
+    pub fn register_chain(&mut self, chain: QuestChain) -> Result<()> {
+        quest_span!("register_chain", chain_id = chain.chain_id);
+        
+        let op = self.journal_op(|| JournalOp::RegisterChain { chain: chain.clone() });
+        self.journaled("register_chain", op, |core| core.register_chain_inner(chain))
+    }
+    
+    fn register_chain_inner(&mut self, chain: QuestChain) -> Result<()> {
+        if self.quest_chains.contains_key(&chain.chain_id) {
+            return Err(EnochianError::Generic {
+                message: format!("Quest chain {} already registered", chain.chain_id),
+            });
+        }
+        
+        if chain.quest_ids.is_empty() {
+            return Err(EnochianError::Generic {
+                message: format!("Quest chain {} has no quests", chain.chain_id),
+            });
+        }
+        
+        for (position, quest_id) in chain.quest_ids.iter().enumerate() {
+            let quest = self.quest_registry.get(quest_id)
+                .ok_or_else(|| EnochianError::Generic {
+                    message: format!("Quest chain {} references unknown quest {}", chain.chain_id, quest_id),
+                })?;
+            
+            let later = &chain.quest_ids[position + 1..];
+            if later.contains(quest_id) {
+                return Err(EnochianError::Generic {
+                    message: format!("Quest chain {} repeats quest {}", chain.chain_id, quest_id),
+                });
+            }
+            if let Some(prerequisite) = quest.prerequisites.iter().find(|p| later.contains(p)) {
+                return Err(EnochianError::Generic {
+                    message: format!(
+                        "Quest chain {} is cyclic: {} requires later quest {}",
+                        chain.chain_id, quest_id, prerequisite
+                    ),
+                });
+            }
+        }
+        
+        self.quest_chains.insert(chain.chain_id.clone(), chain);
+        Ok(())
+    }
+    
+    /// Get a player's progress through a quest chain
+    pub fn chain_progress(&self, player_id: &str, chain_id: &str) -> Result<ChainProgress> {
+        let player_state = self.game_states.get(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let chain = self.quest_chains.get(chain_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Quest chain {} not found", chain_id),
+            })?;
+        
+        Ok(ChainProgress {
+            chain_id: chain.chain_id.clone(),
+            completed: chain.quest_ids.iter()
+                .filter(|q| player_state.completed_quests.contains(q))
+                .count(),
+            total: chain.quest_ids.len(),
+            next_quest: chain.quest_ids.iter()
+                .find(|q| !player_state.completed_quests.contains(q))
+                .cloned(),
+            bonus_granted: player_state.completed_chains.contains(&chain.chain_id),
+        })
+    }
+    
+    /// Start a quest for a player
+    pub fn start_quest(&mut self, player_id: &str, quest_id: &str) -> Result<()> {
+        quest_span!("start_quest", player_id = player_id, quest_id = quest_id);
+        
+        let op = self.journal_op(|| JournalOp::StartQuest { player_id: player_id.to_string(), quest_id: quest_id.to_string() });
+        let result = self.journaled("start_quest", op, |core| core.start_quest_inner(player_id, quest_id));
+        crate::metrics::global().observe(Counter::QuestsStarted, result)
+    }
+    
+    fn start_quest_inner(&mut self, player_id: &str, quest_id: &str) -> Result<()> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let quest = self.quest_registry.get(quest_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Quest {} not found", quest_id),
+            })?;
+        
+        // Check if player can start quest
+        self.validate_quest_start(player_state, quest)?;
+        let energy_cost = self.config.effective_energy_cost(player_state, quest);
+        
+        self.state_history.record(self.config.history_snapshot(player_state), self.config.max_state_history, self.config.max_state_history_bytes);
+        
+        // Add quest to active quests
+        player_state.active_quests.push(quest_id.to_string());
+        player_state.energy_level = player_state.energy_level.saturating_sub(energy_cost);
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        Self::record_bounded(&mut self.quest_events, self.config.max_event_log, [QuestEvent {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            outcome: QuestOutcome::Started,
+            reason: None,
+        }]);
+        
+        log::info!("Player {} started quest {}", player_id, quest_id);
+        Ok(())
+    }
+    
+    /// Complete a quest for a player
+    pub fn complete_quest(&mut self, player_id: &str, quest_id: &str) -> Result<QuestRewards> {
+        quest_span!("complete_quest", player_id = player_id, quest_id = quest_id);
+        
+        let op = self.journal_op(|| JournalOp::CompleteQuest { player_id: player_id.to_string(), quest_id: quest_id.to_string() });
+        let result = self.journaled("complete_quest", op, |core| core.complete_quest_inner(player_id, quest_id));
+        crate::metrics::global().observe(Counter::QuestsCompleted, result)
+    }
+    
+    fn complete_quest_inner(&mut self, player_id: &str, quest_id: &str) -> Result<QuestRewards> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let quest = self.quest_registry.get(quest_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Quest {} not found", quest_id),
+            })?;
+        
+        // Check if quest is active
+        if !player_state.active_quests.contains(&quest_id.to_string()) {
+            return Err(EnochianError::Generic {
+                message: format!("Quest {} is not active for player {}", quest_id, player_id),
+            });
+        }
+        
+        Self::check_council_approval(&self.config, player_state, quest)?;
+        
+        self.state_history.record(self.config.history_snapshot(player_state), self.config.max_state_history, self.config.max_state_history_bytes);
+        
+        // Remove from active quests and add to completed, counting repeats
+        player_state.active_quests.retain(|q| q != quest_id);
+        let is_repeat = player_state.completed_quests.contains(&quest_id.to_string());
+        if is_repeat {
+            *player_state.quest_repeat_counts.entry(quest_id.to_string()).or_insert(0) += 1;
+        } else {
+            player_state.completed_quests.push(quest_id.to_string());
+            let mut tags: Vec<&String> = quest.tags.iter().collect();
+            tags.sort_unstable();
+            tags.dedup();
+            for tag in tags {
+                *player_state.tag_completions.entry(tag.clone()).or_insert(0) += 1;
+            }
+        }
+        player_state.failed_quests.retain(|q| q != quest_id);
+        player_state.quest_completed_at.insert(quest_id.to_string(), player_state.block_height);
+        player_state.record_authenticity(quest.authenticity_score);
+        
+        // Apply rewards, scaled by how authentically the quest was played
+        let choices_made = player_state.quest_choices.remove(quest_id).unwrap_or_default();
+        player_state.completed_objectives.remove(quest_id);
+        let mut reward_scale = quest.choice_reward_scale(&choices_made);
+        if is_repeat {
+            reward_scale *= quest.repeat_reward_scale;
+        }
+        let rewards = quest.rewards.scaled(reward_scale).shared_by_council(&quest.council);
+        let mut rank_changes = Self::apply_quest_rewards(&self.config, &self.tradition_manager, player_state, &rewards)?;
+        for governor in &quest.council {
+            player_state.council_blessed_at.insert(governor.clone(), player_state.block_height);
+        }
+        
+        // Grant the bonus of every chain this quest finishes
+        let mut finished_chains: Vec<&QuestChain> = self.quest_chains.values()
+            .filter(|chain| chain.quest_ids.iter().any(|q| q == quest_id))
+            .filter(|chain| !player_state.completed_chains.contains(&chain.chain_id))
+            .filter(|chain| chain.quest_ids.iter().all(|q| player_state.completed_quests.contains(q)))
+            .collect();
+        finished_chains.sort_by(|a, b| a.chain_id.cmp(&b.chain_id));
+        for chain in finished_chains {
+            rank_changes.extend(Self::apply_quest_rewards(&self.config, &self.tradition_manager, player_state, &chain.completion_bonus)?);
+            player_state.completed_chains.push(chain.chain_id.clone());
+            log::info!("Player {} completed quest chain {}", player_id, chain.chain_id);
+        }
+        
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        Self::record_bounded(&mut self.quest_events, self.config.max_event_log, [QuestEvent {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            outcome: QuestOutcome::Completed,
+            reason: None,
+        }]);
+        Self::record_bounded(&mut self.rank_events, self.config.max_event_log, rank_changes.into_iter().map(|change| RankChange {
+            quest_id: Some(quest_id.to_string()),
+            ..change
+        }));
+        
+        log::info!("Player {} completed quest {}", player_id, quest_id);
+        Ok(rewards)
+    }
+    
+    /// Complete one objective of an active quest with the given action
+    ///
+    /// The action must be the one the objective's kind asks for, e.g. a
+    /// `Ritual` objective is only completed by `ActionType::PerformRitual`.
+    pub fn complete_objective(&mut self, player_id: &str, quest_id: &str, objective_index: usize, action: ActionType) -> Result<()> {
+        quest_span!("complete_objective", player_id = player_id, quest_id = quest_id, objective_index = objective_index);
+        
+        let op = self.journal_op(|| JournalOp::CompleteObjective {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            objective_index,
+            action,
+        });
+        self.journaled("complete_objective", op, |core| core.complete_objective_inner(player_id, quest_id, objective_index, action))
+    }
+    
+    fn complete_objective_inner(&mut self, player_id: &str, quest_id: &str, objective_index: usize, action: ActionType) -> Result<()> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let quest = self.quest_registry.get(quest_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Quest {} not found", quest_id),
+            })?;
+        
+        if !player_state.active_quests.contains(&quest_id.to_string()) {
+            return Err(EnochianError::Generic {
+                message: format!("Quest {} is not active for player {}", quest_id, player_id),
+            });
+        }
+        
+        let objective = quest.objectives.get(objective_index)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Objective {} not found in quest {}", objective_index, quest_id),
+            })?;
+        
+        let expected = objective.kind.completing_action();
+        if action != expected {
+            return Err(EnochianError::ObjectiveActionMismatch {
+                quest_id: quest_id.to_string(),
+                objective: objective_index,
+                expected: format!("{:?}", expected),
+                found: format!("{:?}", action),
+            });
+        }
+        
+        let completed = player_state.completed_objectives.get(quest_id);
+        if completed.is_some_and(|indices| indices.contains(&objective_index)) {
+            return Err(EnochianError::Generic {
+                message: format!("Objective {} of quest {} already completed", objective_index, quest_id),
+            });
+        }
+        
+        self.state_history.record(self.config.history_snapshot(player_state), self.config.max_state_history, self.config.max_state_history_bytes);
+        player_state.completed_objectives.entry(quest_id.to_string()).or_default().push(objective_index);
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        log::info!("Player {} completed objective {} of quest {}", player_id, objective_index, quest_id);
+        Ok(())
+    }
+    
+    /// Abandon an active quest without rewards; spent energy is not refunded
+    pub fn abandon_quest(&mut self, player_id: &str, quest_id: &str) -> Result<()> {
+        quest_span!("abandon_quest", player_id = player_id, quest_id = quest_id);
+        
+        let op = self.journal_op(|| JournalOp::AbandonQuest { player_id: player_id.to_string(), quest_id: quest_id.to_string() });
+        self.journaled("abandon_quest", op, |core| core.abandon_quest_inner(player_id, quest_id))
+    }
+    
+    fn abandon_quest_inner(&mut self, player_id: &str, quest_id: &str) -> Result<()> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        if !player_state.active_quests.contains(&quest_id.to_string()) {
+            return Err(EnochianError::Generic {
+                message: format!("Quest {} is not active for player {}", quest_id, player_id),
+            });
+        }
+        
+        self.state_history.record(self.config.history_snapshot(player_state), self.config.max_state_history, self.config.max_state_history_bytes);
+        
+        player_state.active_quests.retain(|q| q != quest_id);
+        player_state.quest_choices.remove(quest_id);
+        player_state.completed_objectives.remove(quest_id);
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        Self::record_bounded(&mut self.quest_events, self.config.max_event_log, [QuestEvent {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            outcome: QuestOutcome::Abandoned,
+            reason: None,
+        }]);
+        
+        log::info!("Player {} abandoned quest {}", player_id, quest_id);
+        Ok(())
+    }
+    
+    /// Fail an active quest: no rewards are granted, its failure consequences
+    /// are applied and spent energy is not refunded
+    ///
+    /// The quest moves to the player's failed quests; only repeatable quests
+    /// can be started again afterwards.
+    pub fn fail_quest(&mut self, player_id: &str, quest_id: &str, reason: &str) -> Result<()> {
+        quest_span!("fail_quest", player_id = player_id, quest_id = quest_id);
+        
+        let op = self.journal_op(|| JournalOp::FailQuest {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            reason: reason.to_string(),
+        });
+        self.journaled("fail_quest", op, |core| core.fail_quest_inner(player_id, quest_id, reason))
+    }
+    
+    fn fail_quest_inner(&mut self, player_id: &str, quest_id: &str, reason: &str) -> Result<()> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let quest = self.quest_registry.get(quest_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Quest {} not found", quest_id),
+            })?;
+        
+        if !player_state.active_quests.contains(&quest_id.to_string()) {
+            return Err(EnochianError::Generic {
+                message: format!("Quest {} is not active for player {}", quest_id, player_id),
+            });
+        }
+        
+        let previous = self.config.history_snapshot(player_state);
+        let rank_changes = Self::apply_state_consequences(&self.config, &self.tradition_manager, player_state, &quest.failure_consequences)?;
+        self.state_history.record(previous, self.config.max_state_history, self.config.max_state_history_bytes);
+        
+        player_state.active_quests.retain(|q| q != quest_id);
+        player_state.quest_choices.remove(quest_id);
+        player_state.completed_objectives.remove(quest_id);
+        if !player_state.failed_quests.contains(&quest_id.to_string()) {
+            player_state.failed_quests.push(quest_id.to_string());
+        }
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        Self::record_bounded(&mut self.quest_events, self.config.max_event_log, [QuestEvent {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            outcome: QuestOutcome::Failed,
+            reason: Some(reason.to_string()),
+        }]);
+        Self::record_bounded(&mut self.rank_events, self.config.max_event_log, rank_changes.into_iter().map(|change| RankChange {
+            quest_id: Some(quest_id.to_string()),
+            ..change
+        }));
+        
+        log::info!("Player {} failed quest {}: {}", player_id, quest_id, reason);
+        Ok(())
+    }
+    
+    /// Suggest a rebalanced difficulty level from recorded quest outcomes
+    ///
+    /// Quests completed more often than the target rate are rated down, and
+    /// those abandoned or failed more often, or needing repeated attempts, are
+    /// rated up. Returns `None` for unknown quests or fewer than
+    /// `MIN_DIFFICULTY_SAMPLES` finished (completed, abandoned or failed) attempts.
+    pub fn suggest_difficulty(&self, quest_id: &str) -> Option<u32> {
+        let quest = self.quest_registry.get(quest_id)?;
+        let events: Vec<&QuestEvent> = self.quest_events.iter()
+            .filter(|event| event.quest_id == quest_id)
+            .collect();
+        
+        let count = |outcome: QuestOutcome| events.iter().filter(|event| event.outcome == outcome).count();
+        let completed = count(QuestOutcome::Completed);
+        let finished = completed + count(QuestOutcome::Abandoned) + count(QuestOutcome::Failed);
+        if finished < MIN_DIFFICULTY_SAMPLES {
+            return None;
+        }
+        let completion_rate = completed as f64 / finished as f64;
+        
+        // Average starts per player who eventually completed the quest
+        let completers: std::collections::HashSet<&str> = events.iter()
+            .filter(|event| event.outcome == QuestOutcome::Completed)
+            .map(|event| event.player_id.as_str())
+            .collect();
+        let average_attempts = if completers.is_empty() {
+            1.0
+        } else {
+            let starts = events.iter()
+                .filter(|event| event.outcome == QuestOutcome::Started)
+                .filter(|event| completers.contains(event.player_id.as_str()))
+                .count();
+            (starts as f64 / completers.len() as f64).max(1.0)
+        };
+        
+        let adjustment = (TARGET_COMPLETION_RATE - completion_rate) * 10.0 + (average_attempts - 1.0);
+        let suggested = (quest.difficulty_level as f64 + adjustment).round().clamp(1.0, 10.0);
+        Some(suggested as u32)
+    }
+    
+    /// Make a choice within an active quest, applying its structured consequences
+    ///
+    /// Each quest takes one choice per attempt; a second is rejected with
+    /// `EnochianError::ChoiceAlreadyMade`.
+    pub fn make_quest_choice(&mut self, player_id: &str, quest_id: &str, choice_id: &str) -> Result<Vec<StateConsequence>> {
+        quest_span!("make_quest_choice", player_id = player_id, quest_id = quest_id);
+        
+        let op = self.journal_op(|| JournalOp::MakeChoice {
+            player_id: player_id.to_string(),
+            quest_id: quest_id.to_string(),
+            choice_id: choice_id.to_string(),
+        });
+        self.journaled("make_quest_choice", op, |core| core.make_quest_choice_inner(player_id, quest_id, choice_id))
+    }
+    
+    fn make_quest_choice_inner(&mut self, player_id: &str, quest_id: &str, choice_id: &str) -> Result<Vec<StateConsequence>> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let quest = self.quest_registry.get(quest_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Quest {} not found", quest_id),
+            })?;
+        
+        // Choices can only be made within an active quest
+        if !player_state.active_quests.contains(&quest_id.to_string()) {
+            return Err(EnochianError::Generic {
+                message: format!("Quest {} is not active for player {}", quest_id, player_id),
+            });
+        }
+        
+        let choice = quest.choice_branches.iter()
+            .find(|c| c.choice_id == choice_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Choice {} not found in quest {}", choice_id, quest_id),
+            })?;
+        
+        // Replaying a choice would reapply its consequences
+        if let Some(made) = player_state.quest_choices.get(quest_id).and_then(|choices| choices.first()) {
+            return Err(EnochianError::ChoiceAlreadyMade {
+                quest_id: quest_id.to_string(),
+                choice_id: made.clone(),
+            });
+        }
+        
+        let previous = self.config.history_snapshot(player_state);
+        let rank_changes = Self::apply_state_consequences(&self.config, &self.tradition_manager, player_state, &choice.structured_consequences)?;
+        self.state_history.record(previous, self.config.max_state_history, self.config.max_state_history_bytes);
+        player_state.quest_choices.entry(quest_id.to_string()).or_default().push(choice_id.to_string());
+        Self::record_bounded(&mut self.rank_events, self.config.max_event_log, rank_changes.into_iter().map(|change| RankChange {
+            quest_id: Some(quest_id.to_string()),
+            ..change
+        }));
+        
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        log::info!("Player {} chose {} in quest {}", player_id, choice_id, quest_id);
+        Ok(choice.structured_consequences.clone())
+    }
+    
+    /// Grant whatever deferred mastery and relationship gains the reward caps
+    /// now allow, e.g. once a new cap window has begun
+    pub fn release_deferred_rewards(&mut self, player_id: &str) -> Result<()> {
+        quest_span!("release_deferred_rewards", player_id = player_id);
+        
+        let op = self.journal_op(|| JournalOp::ReleaseDeferredRewards { player_id: player_id.to_string() });
+        self.journaled("release_deferred_rewards", op, |core| core.release_deferred_rewards_inner(player_id))
+    }
+    
+    fn release_deferred_rewards_inner(&mut self, player_id: &str) -> Result<()> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let rewards = QuestRewards {
+            governor_relationship_changes: player_state.deferred_relationships.keys()
+                .map(|governor| (governor.clone(), 0.0))
+                .collect(),
+            ..QuestRewards::default()
+        };
+        let previous = self.config.history_snapshot(player_state);
+        let rank_changes = Self::apply_quest_rewards(&self.config, &self.tradition_manager, player_state, &rewards)?;
+        self.state_history.record(previous, self.config.max_state_history, self.config.max_state_history_bytes);
+        Self::record_bounded(&mut self.rank_events, self.config.max_event_log, rank_changes);
+        
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        Ok(())
+    }
+    
+    /// Apply penalty consequences to a player outside of any quest choice,
+    /// e.g. after a failed or hostile governor interaction
+    ///
+    /// Consequences are validated up front, so an invalid one leaves the
+    /// player unchanged.
+    pub fn apply_penalty(&mut self, player_id: &str, consequences: &[StateConsequence]) -> Result<()> {
+        quest_span!("apply_penalty", player_id = player_id);
+        
+        let op = self.journal_op(|| JournalOp::ApplyPenalty { player_id: player_id.to_string(), consequences: consequences.to_vec() });
+        self.journaled("apply_penalty", op, |core| core.apply_penalty_inner(player_id, consequences))
+    }
+    
+    fn apply_penalty_inner(&mut self, player_id: &str, consequences: &[StateConsequence]) -> Result<()> {
+        let player_state = self.game_states.get_mut(player_id)
+            .ok_or_else(|| EnochianError::Generic {
+                message: format!("Player {} not found", player_id),
+            })?;
+        
+        let previous = self.config.history_snapshot(player_state);
+        let rank_changes = Self::apply_state_consequences(&self.config, &self.tradition_manager, player_state, consequences)?;
+        self.state_history.record(previous, self.config.max_state_history, self.config.max_state_history_bytes);
+        Self::record_bounded(&mut self.rank_events, self.config.max_event_log, rank_changes);
+        
+        player_state.last_update = self.clock.now().to_rfc3339();
+        player_state.version += 1;
+        
+        log::info!("Player {} penalised with {} consequences", player_id, consequences.len());
+        Ok(())
+    }
+    
+    /// Recommend quests a player can start now, best match first
+    ///
+    /// Candidates must be neither active nor completed, have their prerequisites
+    /// met and be affordable with the player's current energy. Each is scored by
+    /// tradition-mastery fit, governor relationship, authenticity and how close
+    /// its difficulty is to the player's overall mastery.
+    pub fn recommend_quests(&self, player_id: &str, limit: usize) -> Vec<(QuestData, f64)> {
+        let player_state = match self.game_states.get(player_id) {
+            Some(state) => state,
+            None => return Vec::new(),
+        };
+        
+        let average_mastery = if player_state.tradition_mastery.is_empty() {
+            0.0
+        } else {
+            player_state.tradition_mastery.values().map(|mastery| mastery.value()).sum::<f64>()
+                / player_state.tradition_mastery.len() as f64
+        };
+        let target_difficulty = 1.0 + average_mastery * 9.0;
+        
+        let mut recommendations: Vec<(QuestData, f64)> = self.quest_registry.values()
+            .filter(|quest| !self.quarantined_quests.contains(&quest.quest_id))
+            .filter(|quest| !player_state.completed_quests.contains(&quest.quest_id))
+            .filter(|quest| quest.repeatable || !player_state.failed_quests.contains(&quest.quest_id))
+            .filter(|quest| !player_state.active_quests.contains(&quest.quest_id))
+            .filter(|quest| quest.prerequisites.iter().all(|p| player_state.completed_quests.contains(p)))
+            .filter(|quest| self.config.effective_energy_cost(player_state, quest) <= player_state.energy_level.value())
+            .map(|quest| {
+                let tradition_fit = if quest.tradition_integration.is_empty() {
+                    0.0
+                } else {
+                    quest.tradition_integration.iter()
+                        .map(|t| player_state.tradition_mastery.get(t).copied().unwrap_or_default().value())
+                        .sum::<f64>() / quest.tradition_integration.len() as f64
+                };
+                
+                // Relationships range over -1.0..1.0
+                let relationship = player_state.governor_relationships
+                    .get(&quest.governor_name).copied().unwrap_or(0.0);
+                let relationship_fit = (relationship + 1.0) / 2.0;
+                
+                let difficulty_fit = 1.0 - (quest.difficulty_level as f64 - target_difficulty).abs() / 9.0;
+                
+                let score = tradition_fit * 0.4
+                    + relationship_fit * 0.2
+                    + quest.authenticity_score * 0.2
+                    + difficulty_fit.max(0.0) * 0.2;
+                
+                (quest.clone(), score)
+            })
+            .collect();
+        
+        recommendations.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.quest_id.cmp(&b.0.quest_id))
+        });
+        recommendations.truncate(limit);
+        recommendations
+    }
+    
+    /// Prune the state history down to the most recent snapshot per player,
+    /// returning the number of snapshots removed
+    pub fn compact_history(&mut self) -> usize {
+        quest_span!("compact_history");
+        
+        let latest: BTreeMap<&str, usize> = self.state_history.iter()
+            .enumerate()
+            .map(|(index, snapshot)| (snapshot.player_id.as_str(), index))
+            .collect();
+        let keep: BTreeSet<usize> = latest.into_values().collect();
+        
+        let mut index = 0;
+        self.state_history.retain(|_| {
+            index += 1;
+            keep.contains(&(index - 1))
+        })
+    }
+    
+    /// Get memory usage counters
+    pub fn memory_stats(&self) -> serde_json::Value {
+        serde_json::json!({
+            "players": self.game_states.len(),
+            "quests": self.quest_registry.len(),
+            "state_history": self.state_history.len(),
+        })
+    }
+    
+    /// Build a health report suitable for a `/health` route
+    pub fn health(&self) -> HealthReport {
+        let failed_constraints: Vec<String> = crate::run_sacred_checks_with(
+            &self.tradition_manager,
+            &shared_manager(),
+            self.health_scorer.get_or_init(AuthenticityScorer::new),
+        )
+        .into_iter()
+        .filter(|check| !check.passed)
+        .map(|check| check.name)
+        .collect();
+        let sacred_constraints_passed = failed_constraints.is_empty();
+        let build = crate::build_info();
+        
+        HealthReport {
+            ready: self.initialized && !self.closed && sacred_constraints_passed,
+            initialized: self.initialized,
+            sacred_constraints_passed,
+            failed_constraints,
+            player_count: self.game_states.len(),
+            quest_count: self.quest_registry.len(),
+            version: build.version,
+            git_hash: build.git_hash,
+            build_time: build.build_time,
+            uptime_seconds: (self.clock.now() - self.started_at).num_seconds().max(0) as u64,
+            closed: self.closed,
+        }
+    }
+    
+    /// Get system statistics
+    pub fn get_statistics(&self) -> serde_json::Value {
+        serde_json::json!({
+            "total_players": self.game_states.len(),
+            "total_quests": self.quest_registry.len(),
+            "active_quests": self.game_states.values()
+                .map(|state| state.active_quests.len())
+                .sum::<usize>(),
+            "completed_quests": self.game_states.values()
+                .map(|state| state.completed_quests.len())
+                .sum::<usize>(),
+            "average_authenticity": self.game_states.values()
+                .map(|state| state.authenticity_score)
+                .sum::<f64>() / self.game_states.len() as f64,
+            "total_hypertokens": self.game_states.values()
+                .map(|state| state.owned_hypertokens.len())
+                .sum::<usize>(),
+        })
+    }
+    
+    fn initialize_subsystems(&mut self) -> Result<()> {
+        // Initialize tradition system
+        log::info!("Initializing tradition system...");
+        
+        // Initialize governor system
+        log::info!("Initializing governor system...");
+        
+        // Initialize authenticity system
+        log::info!("Initializing authenticity system...");
+        
+        Ok(())
+    }
+    
+    fn validate_state_update(&self, state: &GameState) -> Result<()> {
+        // Energy and mastery ranges are enforced by their types
+        
+        // Validate authenticity score
+        if state.authenticity_score < 0.0 || state.authenticity_score > 1.0 {
+            return Err(EnochianError::Generic {
+                message: "Authenticity score must be between 0.0 and 1.0".to_string(),
+            });
+        }
+        
+        // Validate Aethyr access
+        for aethyr_id in &state.aethyr_access {
+            validate_aethyr_id(*aethyr_id)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn validate_loadout(&self, loadout: &StartingLoadout) -> Result<()> {
+        if loadout.authenticity_score < 0.0 || loadout.authenticity_score > 1.0 {
+            return Err(EnochianError::Generic {
+                message: "Starting authenticity score must be between 0.0 and 1.0".to_string(),
+            });
+        }
+        
+        if loadout.aethyr_access.is_empty() {
+            return Err(EnochianError::Generic {
+                message: "Starting loadout must grant access to at least one Aethyr".to_string(),
+            });
+        }
+        
+        for (index, aethyr) in loadout.aethyr_access.iter().enumerate() {
+            validate_aethyr_id(*aethyr)?;
+            if loadout.aethyr_access[..index].contains(aethyr) {
+                return Err(EnochianError::Generic {
+                    message: format!("Aethyr {} is listed more than once", aethyr),
+                });
+            }
+        }
+        
+        // Starting mastery obeys the same foundation caps as earned mastery
+        for (tradition, mastery) in &loadout.tradition_mastery {
+            if self.tradition_manager.get_tradition(tradition).is_none() {
+                return Err(EnochianError::TraditionNotSupported {
+                    tradition: tradition.clone(),
+                });
+            }
+            
+            let ceiling = self.tradition_manager.get_mastery_ceiling(tradition, &loadout.tradition_mastery);
+            if mastery.value() > ceiling {
+                return Err(EnochianError::Generic {
+                    message: format!(
+                        "Starting {} mastery {} must be between 0.0 and {}",
+                        tradition, mastery, ceiling
+                    ),
+                });
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Every council member must be befriended and not have blessed another
+    /// council quest within `governor_interaction_cooldown` blocks
+    fn check_council_approval(config: &SystemConfig, player_state: &GameState, quest: &QuestData) -> Result<()> {
+        let withheld: Vec<String> = quest.council.iter()
+            .filter_map(|governor| {
+                let relationship = player_state.governor_relationships.get(governor).copied().unwrap_or(0.0);
+                if relationship < config.council_min_relationship {
+                    return Some(format!(
+                        "{} (relationship {:.2} below {:.2})",
+                        governor, relationship, config.council_min_relationship
+                    ));
+                }
+                
+                let available_at = player_state.council_blessed_at.get(governor)
+                    .map(|blessed_at| blessed_at + config.governor_interaction_cooldown as u64)?;
+                (player_state.block_height < available_at)
+                    .then(|| format!("{} (on cooldown until block {})", governor, available_at))
+            })
+            .collect();
+        
+        if withheld.is_empty() {
+            Ok(())
+        } else {
+            Err(EnochianError::CouncilApprovalWithheld {
+                quest_id: quest.quest_id.clone(),
+                withheld,
+            })
+        }
+    }
+    
+    fn validate_quest_start(&self, player_state: &GameState, quest: &QuestData) -> Result<()> {
+        if self.quarantined_quests.contains(&quest.quest_id) {
+            return Err(EnochianError::QuestQuarantined {
+                quest_id: quest.quest_id.clone(),
+            });
+        }
+        
+        // Check if quest already completed, or still cooling down if repeatable
+        if player_state.completed_quests.contains(&quest.quest_id) {
+            if !quest.repeatable {
+                return Err(EnochianError::QuestAlreadyCompleted {
+                    quest_id: quest.quest_id.clone(),
+                });
+            }
+            
+            let completed_at = player_state.quest_completed_at.get(&quest.quest_id).copied().unwrap_or(0);
+            let available_at = completed_at + quest.repeat_cooldown_blocks as u64;
+            if player_state.block_height < available_at {
+                return Err(EnochianError::QuestOnCooldown {
+                    quest_id: quest.quest_id.clone(),
+                    available_at,
+                });
+            }
+        }
+        
+        if player_state.failed_quests.contains(&quest.quest_id) && !quest.repeatable {
+            return Err(EnochianError::QuestFailed {
+                quest_id: quest.quest_id.clone(),
+            });
+        }
+        
+        // Check if quest already active
+        if player_state.active_quests.contains(&quest.quest_id) {
+            return Err(EnochianError::QuestAlreadyActive {
+                quest_id: quest.quest_id.clone(),
+            });
+        }
+        
+        // Check prerequisites
+        let missing: Vec<String> = quest.prerequisites.iter()
+            .filter(|prerequisite| !player_state.completed_quests.contains(prerequisite))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            return Err(EnochianError::PrerequisitesUnmet { missing });
+        }
+        
+        // Check concurrent quest limit
+        if player_state.active_quests.len() >= self.config.max_concurrent_quests as usize {
+            return Err(EnochianError::QuestSlotsFull {
+                limit: self.config.max_concurrent_quests,
+            });
+        }
+        
+        // Check energy requirement
+        let energy_cost = self.config.effective_energy_cost(player_state, quest);
+        if player_state.energy_level.value() < energy_cost {
+            return Err(EnochianError::InsufficientEnergy {
+                required: energy_cost,
+                available: player_state.energy_level.value(),
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Apply `rewards` to a player, returning the mastery rank changes they
+    /// cause (with no quest ID set)
+    fn apply_quest_rewards(config: &SystemConfig, tradition_manager: &TraditionManager, player_state: &mut GameState, rewards: &QuestRewards) -> Result<Vec<RankChange>> {
+        // Reject invalid Aethyrs before changing anything
+        for aethyr_id in &rewards.aethyr_access_gained {
+            validate_aethyr_id(*aethyr_id)?;
+        }
+        
+        // Apply reputation changes
+        for (category, change) in &rewards.reputation_changes {
+            let current = player_state.reputation_scores.get(category).unwrap_or(&0.0);
+            player_state.reputation_scores.insert(category.clone(), current + change);
+        }
+        
+        // Apply tradition mastery gains with any deferred before, limited by the
+        // per-window cap and by unmet foundation prerequisites
+        let mut rank_changes = Vec::new();
+        let traditions: BTreeSet<String> = rewards.tradition_mastery_gains.keys()
+            .chain(player_state.deferred_mastery.keys())
+            .cloned()
+            .collect();
+        for tradition in traditions {
+            let pending = rewards.tradition_mastery_gains.get(&tradition).copied().unwrap_or(0.0)
+                + player_state.deferred_mastery.remove(&tradition).unwrap_or(0.0);
+            rank_changes.extend(Self::grant_mastery(config, tradition_manager, player_state, &tradition, pending));
+        }
+        
+        // Apply governor relationship changes with any deferred before, limited by the per-window cap
+        for (governor, change) in &rewards.governor_relationship_changes {
+            let pending = change + player_state.deferred_relationships.remove(governor).unwrap_or(0.0);
+            Self::grant_relationship(config, player_state, governor, pending);
+        }
+        
+        // Apply Bitcoin rewards
+        player_state.balance_sats += rewards.bitcoin_rewards;
+        
+        // Add sacred items
+        for item in &rewards.sacred_items {
+            if !player_state.sacred_items.contains(item) {
+                player_state.sacred_items.push(item.clone());
+            }
+        }
+        
+        // Add hypertoken rewards
+        for token in &rewards.hypertoken_rewards {
+            if !player_state.owned_hypertokens.contains(token) {
+                player_state.owned_hypertokens.push(token.clone());
+            }
+        }
+        
+        // Add Aethyr access
+        for aethyr in &rewards.aethyr_access_gained {
+            player_state.grant_aethyr_access(*aethyr)?;
+        }
+        
+        Ok(rank_changes)
+    }
+    
+    /// Change a player's mastery in `tradition` by `pending`, returning the
+    /// rank change it causes
+    ///
+    /// Gains are limited by `SystemConfig::mastery_gain_cap`, with the excess
+    /// added to the deferred mastery, and by unmet foundation prerequisites.
+    fn grant_mastery(config: &SystemConfig, tradition_manager: &TraditionManager, player_state: &mut GameState, tradition: &str, pending: f64) -> Option<RankChange> {
+        let gain = match config.mastery_gain_cap {
+            Some(cap) if pending > 0.0 => windowed_gain(
+                &mut player_state.mastery_gain_windows,
+                &mut player_state.deferred_mastery,
+                tradition,
+                pending,
+                cap,
+                config.mastery_cap_window_blocks,
+                player_state.block_height,
+            ),
+            _ => pending,
+        };
+        
+        let ceiling = tradition_manager.get_mastery_ceiling(tradition, &player_state.tradition_mastery);
+        let current = player_state.tradition_mastery.get(tradition).copied().unwrap_or_default();
+        let new_mastery = Mastery::clamped((current.value() + gain).min(ceiling.max(current.value())));
+        Self::set_mastery(&config.mastery_rank_thresholds, player_state, tradition, new_mastery)
+    }
+    
+    /// Set a player's mastery in `tradition`, returning the promotion or
+    /// demotion it causes under `thresholds`
+    ///
+    /// Every mastery change goes through here so that none goes unrecorded.
+    fn set_mastery(thresholds: &MasteryRankThresholds, player_state: &mut GameState, tradition: &str, mastery: Mastery) -> Option<RankChange> {
+        let previous = player_state.tradition_mastery.insert(tradition.to_string(), mastery).unwrap_or_default();
+        let (from, to) = (thresholds.rank_of(previous), thresholds.rank_of(mastery));
+        if from == to {
+            return None;
+        }
+        log::info!("Player {} {} rank changed from {} to {}", player_state.player_id, tradition, from, to);
+        Some(RankChange {
+            player_id: player_state.player_id.clone(),
+            tradition: tradition.to_string(),
+            from,
+            to,
+            quest_id: None,
+        })
+    }
+    
+    /// Change a player's relationship with `governor` by `pending`, deferring
+    /// any gain above what `SystemConfig::relationship_gain_cap` still allows
+    /// in the current window
+    fn grant_relationship(config: &SystemConfig, player_state: &mut GameState, governor: &str, pending: f64) {
+        let gain = match config.relationship_gain_cap {
+            Some(cap) if pending > 0.0 => windowed_gain(
+                &mut player_state.relationship_gain_windows,
+                &mut player_state.deferred_relationships,
+                governor,
+                pending,
+                cap,
+                config.relationship_cap_window_blocks,
+                player_state.block_height,
+            ),
+            _ => pending,
+        };
+        let current = player_state.governor_relationships.get(governor).unwrap_or(&0.0);
+        let new_relationship = (current + gain).clamp(-1.0, 1.0);
+        player_state.governor_relationships.insert(governor.to_string(), new_relationship);
+    }
+    
+    /// Append `entries` to `log`, dropping the oldest beyond `max_len`
+    fn record_bounded<T>(log: &mut Vec<T>, max_len: usize, entries: impl IntoIterator<Item = T>) {
+        log.extend(entries);
+        if log.len() > max_len {
+            let excess = log.len() - max_len;
+            log.drain(..excess);
+        }
+    }
+    
+    fn validate_state_consequences(consequences: &[StateConsequence]) -> Result<()> {
+        for consequence in consequences {
+            if matches!(consequence.consequence_type, ConsequenceType::AethyrAccess | ConsequenceType::AethyrRevoke) {
+                parse_aethyr_target(&consequence.target)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Apply structured consequences to a player, returning the mastery rank
+    /// changes they cause
+    ///
+    /// Mastery and relationship gains pass through the same caps and
+    /// prerequisite ceilings as quest rewards.
+    fn apply_state_consequences(config: &SystemConfig, tradition_manager: &TraditionManager, player_state: &mut GameState, consequences: &[StateConsequence]) -> Result<Vec<RankChange>> {
+        // Reject invalid consequences before changing anything
+        Self::validate_state_consequences(consequences)?;
+        
+        let mut rank_changes = Vec::new();
+        for consequence in consequences {
+            match consequence.consequence_type {
+                ConsequenceType::ReputationChange => {
+                    let current = player_state.reputation_scores.get(&consequence.target).unwrap_or(&0.0);
+                    player_state.reputation_scores.insert(consequence.target.clone(), current + consequence.value_change);
+                },
+                ConsequenceType::TraditionMastery => {
+                    rank_changes.extend(Self::grant_mastery(config, tradition_manager, player_state, &consequence.target, consequence.value_change));
+                },
+                ConsequenceType::GovernorRelationship => {
+                    Self::grant_relationship(config, player_state, &consequence.target, consequence.value_change);
+                },
+                ConsequenceType::EnergyModification => {
+                    player_state.energy_level = player_state.energy_level.adjusted(consequence.value_change);
+                },
+                ConsequenceType::ItemGain => {
+                    if !player_state.sacred_items.contains(&consequence.target) {
+                        player_state.sacred_items.push(consequence.target.clone());
+                    }
+                },
+                ConsequenceType::ItemLoss => {
+                    player_state.sacred_items.retain(|item| item != &consequence.target);
+                },
+                ConsequenceType::AethyrAccess => {
+                    player_state.grant_aethyr_access(parse_aethyr_target(&consequence.target)?)?;
+                },
+                ConsequenceType::AethyrRevoke => {
+                    player_state.revoke_aethyr_access(parse_aethyr_target(&consequence.target)?)?;
+                },
+                ConsequenceType::WisdomUnlock => {} // Narrative only
+            }
+        }
+        Ok(rank_changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authenticity::DangerousTerms;
+    
+    fn test_quest(quest_id: &str, choices: Vec<QuestChoice>) -> QuestData {
+        QuestData {
+            quest_id: quest_id.to_string(),
+            title: "Test Quest".to_string(),
+            description: "A test quest".to_string(),
+            objectives: vec![QuestObjective::new(ObjectiveKind::Study, "Study Enochian principles")],
+            wisdom_taught: "Test wisdom".to_string(),
+            choice_branches: choices,
+            authenticity_score: 0.96,
+            estimated_duration: 30,
+            tradition_integration: vec!["Enochian".to_string()],
+            governor_name: "ABRIOND".to_string(),
+            difficulty_level: 3,
+            required_energy: 5,
+            rewards: QuestRewards {
+                experience: 10,
+                reputation_changes: BTreeMap::new(),
+                tradition_mastery_gains: BTreeMap::new(),
+                governor_relationship_changes: BTreeMap::new(),
+                bitcoin_rewards: 0,
+                sacred_items: vec![],
+                hypertoken_rewards: vec![],
+                aethyr_access_gained: vec![],
+            },
+            prerequisites: vec![],
+            repeatable: false,
+            repeat_cooldown_blocks: 0,
+            repeat_reward_scale: 1.0,
+            council: vec![],
+            failure_consequences: vec![],
+            tags: vec![],
+            authenticity_weight_overrides: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+    
+    fn test_choice(choice_id: &str, structured_consequences: Vec<StateConsequence>) -> QuestChoice {
+        QuestChoice {
+            choice_id: choice_id.to_string(),
+            description: "Perform the invocation".to_string(),
+            consequences: vec!["Deepen Enochian understanding".to_string()],
+            difficulty_modifier: 1.0,
+            tradition_alignment: 0.9,
+            authenticity_impact: 0.1,
+            required_traditions: vec!["Enochian".to_string()],
+            energy_cost: 0,
+            structured_consequences,
+        }
+    }
+    
+    fn test_core() -> EnochianCore {
+        let mut core = EnochianCore::new(SystemConfig::default());
+        core.initialize().unwrap();
+        core
+    }
+    
+    #[test]
+    fn test_choice_applies_structured_consequences() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![test_choice("choice_1", vec![
+            StateConsequence {
+                consequence_type: ConsequenceType::TraditionMastery,
+                target: "Enochian".to_string(),
+                value_change: 0.05,
+            },
+            StateConsequence {
+                consequence_type: ConsequenceType::GovernorRelationship,
+                target: "ABRIOND".to_string(),
+                value_change: 0.2,
+            },
+            StateConsequence {
+                consequence_type: ConsequenceType::ItemGain,
+                target: "Sigillum Dei Aemeth".to_string(),
+                value_change: 1.0,
+            },
+        ])])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        
+        core.make_quest_choice("player", "quest_1", "choice_1").unwrap();
+        
+        let state = core.get_player_state("player").unwrap();
+        assert!((state.tradition_mastery["Enochian"].value() - 0.15).abs() < 1e-9);
+        assert!((state.governor_relationships["ABRIOND"] - 0.2).abs() < 1e-9);
+        assert!(state.sacred_items.contains(&"Sigillum Dei Aemeth".to_string()));
+    }
+    
+    #[test]
+    fn test_second_choice_in_quest_rejected() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mastery = StateConsequence {
+            consequence_type: ConsequenceType::TraditionMastery,
+            target: "Enochian".to_string(),
+            value_change: 0.05,
+        };
+        core.register_quest(test_quest("quest_1", vec![
+            test_choice("choice_1", vec![mastery.clone()]),
+            test_choice("choice_2", vec![mastery]),
+        ])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        core.make_quest_choice("player", "quest_1", "choice_1").unwrap();
+        let before = core.get_player_state("player").unwrap().clone();
+        
+        for choice_id in ["choice_1", "choice_2"] {
+            let result = core.make_quest_choice("player", "quest_1", choice_id);
+            assert!(matches!(result, Err(EnochianError::ChoiceAlreadyMade { ref choice_id, .. }) if choice_id == "choice_1"));
+        }
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(serde_json::to_string(state).unwrap(), serde_json::to_string(&before).unwrap());
+    }
+    
+    #[test]
+    fn test_consequence_gains_respect_caps_and_prerequisites() {
+        let mut core = test_core();
+        core.config.mastery_gain_cap = Some(0.1);
+        core.config.relationship_gain_cap = Some(0.1);
+        core.create_player_state("player".to_string()).unwrap();
+        let consequence = |consequence_type: ConsequenceType, target: &str, value_change: f64| StateConsequence {
+            consequence_type,
+            target: target.to_string(),
+            value_change,
+        };
+        core.register_quest(test_quest("quest_1", vec![test_choice("choice_1", vec![
+            consequence(ConsequenceType::TraditionMastery, "Enochian", 0.25),
+            consequence(ConsequenceType::GovernorRelationship, "ABRIOND", 0.3),
+        ])])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        core.make_quest_choice("player", "quest_1", "choice_1").unwrap();
+        
+        let state = core.get_player_state("player").unwrap();
+        assert!((state.tradition_mastery["Enochian"].value() - 0.2).abs() < 1e-9);
+        assert!((state.deferred_mastery["Enochian"] - 0.15).abs() < 1e-9);
+        assert!((state.governor_relationships["ABRIOND"] - 0.1).abs() < 1e-9);
+        assert!((state.deferred_relationships["ABRIOND"] - 0.2).abs() < 1e-9);
+        
+        // Penalties cannot lift mastery past an unmet foundation prerequisite
+        core.config.mastery_gain_cap = None;
+        core.apply_penalty("player", &[consequence(ConsequenceType::TraditionMastery, "Hermetic_Qabalah", 0.8)]).unwrap();
+        assert!((core.get_player_state("player").unwrap().tradition_mastery["Hermetic_Qabalah"].value() - 0.3).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_choice_requires_active_quest() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![test_choice("choice_1", vec![])])).unwrap();
+        
+        assert!(core.make_quest_choice("player", "quest_1", "choice_1").is_err());
+    }
+    
+    #[test]
+    fn test_game_state_serialization_is_deterministic() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut forward = core.get_player_state("player").unwrap().clone();
+        let mut reverse = forward.clone();
+        
+        let traditions = ["Thelema", "Enochian", "Golden_Dawn", "Hermetic_Qabalah", "Alchemy"];
+        for (i, tradition) in traditions.iter().enumerate() {
+            forward.tradition_mastery.insert(tradition.to_string(), Mastery(i as f64 * 0.1));
+            forward.governor_relationships.insert(format!("GOV{:02}", i), 0.5);
+        }
+        for (i, tradition) in traditions.iter().enumerate().rev() {
+            reverse.tradition_mastery.insert(tradition.to_string(), Mastery(i as f64 * 0.1));
+            reverse.governor_relationships.insert(format!("GOV{:02}", i), 0.5);
+        }
+        
+        let first = serde_json::to_string(&forward).unwrap();
+        assert_eq!(first, serde_json::to_string(&forward).unwrap());
+        assert_eq!(first, serde_json::to_string(&reverse).unwrap());
+    }
+    
+    #[test]
+    fn test_quest_start_already_active() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        
+        let err = core.start_quest("player", "quest_1").unwrap_err();
+        assert!(matches!(err, EnochianError::QuestAlreadyActive { .. }));
+        assert!(!err.is_transient());
+    }
+    
+    #[test]
+    fn test_quest_start_already_completed() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        core.complete_quest("player", "quest_1").unwrap();
+        
+        let err = core.start_quest("player", "quest_1").unwrap_err();
+        assert!(matches!(err, EnochianError::QuestAlreadyCompleted { .. }));
+    }
+    
+    #[test]
+    fn test_quest_start_slots_full() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        for i in 0..4 {
+            core.register_quest(test_quest(&format!("quest_{}", i), vec![])).unwrap();
+        }
+        for i in 0..3 {
+            core.start_quest("player", &format!("quest_{}", i)).unwrap();
+        }
+        
+        let err = core.start_quest("player", "quest_3").unwrap_err();
+        assert!(matches!(err, EnochianError::QuestSlotsFull { limit: 3 }));
+        assert!(err.is_transient());
+    }
+    
+    #[test]
+    fn test_quest_start_insufficient_energy() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.required_energy = 20;
+        core.register_quest(quest).unwrap();
+        core.game_states.get_mut("player").unwrap().energy_level = Energy(10);
+        
+        let err = core.start_quest("player", "quest_1").unwrap_err();
+        assert!(matches!(err, EnochianError::InsufficientEnergy { required: 20, available: 10 }));
+        assert!(err.is_transient());
+    }
+    
+    #[test]
+    fn test_quest_start_prerequisites_unmet() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("quest_2", vec![]);
+        quest.prerequisites = vec!["quest_1".to_string()];
+        core.register_quest(quest).unwrap();
+        
+        match core.start_quest("player", "quest_2").unwrap_err() {
+            EnochianError::PrerequisitesUnmet { missing } => assert_eq!(missing, vec!["quest_1".to_string()]),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+    
+    #[test]
+    fn test_state_history_is_bounded_and_compactable() {
+        let config = SystemConfig { max_state_history: 2, ..SystemConfig::default() };
+        let mut core = EnochianCore::new(config);
+        core.initialize().unwrap();
+        core.create_player_state("player".to_string()).unwrap();
+        for i in 0..3 {
+            core.register_quest(test_quest(&format!("quest_{}", i), vec![])).unwrap();
+            core.start_quest("player", &format!("quest_{}", i)).unwrap();
+        }
+        assert_eq!(core.state_history.len(), 2);
+        
+        assert_eq!(core.compact_history(), 1);
+        assert_eq!(core.state_history.len(), 1);
+        assert_eq!(core.state_history.get(0).unwrap().active_quests.len(), 2);
+    }
+    
+    #[test]
+    fn test_state_history_is_off_by_default() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        assert!(core.state_history.is_empty());
+    }
+    
+    #[test]
+    fn test_state_history_is_bounded_by_size() {
+        let mut core = test_core();
+        core.config.max_state_history = 16;
+        core.create_player_state("player".to_string()).unwrap();
+        for i in 0..3 {
+            let mut quest = test_quest(&format!("quest_{}", i), vec![]);
+            quest.title = format!("Quest {}", i);
+            core.register_quest(quest).unwrap();
+            core.start_quest("player", &format!("quest_{}", i)).unwrap();
+        }
+        assert_eq!(core.state_history.len(), 3);
+        let newest = serde_json::to_vec(core.state_history.last().unwrap()).unwrap().len();
+        
+        // Too small for two snapshots: only the newest survives the next one
+        core.config.max_state_history_bytes = newest + 1;
+        core.abandon_quest("player", "quest_0").unwrap();
+        assert_eq!(core.state_history.len(), 1);
+        assert_eq!(core.state_history.last().unwrap().active_quests.len(), 3);
+        assert_eq!(core.state_history.total_bytes(), serde_json::to_vec(core.state_history.last().unwrap()).unwrap().len());
+    }
+    
+    #[test]
+    fn test_event_logs_are_bounded() {
+        let mut core = EnochianCore::new(SystemConfig { max_event_log: 2, ..SystemConfig::default() });
+        core.initialize().unwrap();
+        core.create_player_state("player".to_string()).unwrap();
+        for i in 0..3 {
+            let mut quest = test_quest(&format!("quest_{}", i), vec![]);
+            quest.title = format!("Quest {}", i);
+            quest.required_energy = 0;
+            quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.3);
+            core.register_quest(quest).unwrap();
+            core.start_quest("player", &format!("quest_{}", i)).unwrap();
+            core.complete_quest("player", &format!("quest_{}", i)).unwrap();
+        }
+        
+        // Oldest dropped first: only quest_2's start and completion remain
+        assert_eq!(core.quest_events.len(), 2);
+        assert!(core.quest_events.iter().all(|event| event.quest_id == "quest_2"));
+        assert_eq!(core.rank_events.len(), 2);
+        assert_eq!(core.rank_events[1].quest_id.as_deref(), Some("quest_2"));
+    }
+    
+    #[test]
+    fn test_hermetic_mastery_capped_until_enochian_foundation() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.rewards.tradition_mastery_gains.insert("Hermetic_Qabalah".to_string(), 0.5);
+        core.register_quest(quest).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        core.complete_quest("player", "quest_1").unwrap();
+        
+        // Enochian starts at 0.1, below the 0.3 foundation
+        let state = core.get_player_state("player").unwrap();
+        assert!((state.tradition_mastery["Hermetic_Qabalah"].value() - 0.3).abs() < 1e-9);
+        
+        let mut quest = test_quest("quest_2", vec![]);
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.3);
+        quest.rewards.tradition_mastery_gains.insert("Hermetic_Qabalah".to_string(), 0.2);
+        core.register_quest(quest).unwrap();
+        core.start_quest("player", "quest_2").unwrap();
+        core.complete_quest("player", "quest_2").unwrap();
+        
+        let state = core.get_player_state("player").unwrap();
+        assert!((state.tradition_mastery["Enochian"].value() - 0.4).abs() < 1e-9);
+        assert!((state.tradition_mastery["Hermetic_Qabalah"].value() - 0.5).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_quest_id_canonical_format() {
+        assert_eq!(QuestId::new(1, 42).to_string(), "quest_1_42");
+        assert_eq!(QuestId::new(91, 0).to_string(), "quest_91_0");
+    }
+    
+    #[test]
+    fn test_quest_id_round_trip() {
+        let quest_id = QuestId::new(17, 123456);
+        assert_eq!(quest_id.to_string().parse::<QuestId>().unwrap(), quest_id);
+        
+        assert!("quest_01_5".parse::<QuestId>().is_err());
+        assert!("ABRIOND_5".parse::<QuestId>().is_err());
+        assert!("quest_1_5_extra".parse::<QuestId>().is_err());
+    }
+    
+    #[test]
+    fn test_register_rejects_duplicate_quest_id() {
+        let mut core = test_core();
+        let quest_id = QuestId::new(1, 42).to_string();
+        core.register_quest(test_quest(&quest_id, vec![])).unwrap();
+        
+        let err = core.register_quest(test_quest(&quest_id, vec![])).unwrap_err();
+        assert!(matches!(err, EnochianError::DuplicateQuestId { .. }));
+    }
+    
+    #[test]
+    fn test_recommend_quests_prefers_tradition_fit() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.game_states.get_mut("player").unwrap()
+            .tradition_mastery.insert("Enochian".to_string(), Mastery(0.9));
+        
+        let enochian_quest = test_quest("enochian_quest", vec![]);
+        let mut chaos_quest = test_quest("chaos_quest", vec![]);
+        chaos_quest.tradition_integration = vec!["Chaos_Magic".to_string()];
+        core.register_quest(chaos_quest).unwrap();
+        core.register_quest(enochian_quest).unwrap();
+        
+        let recommendations = core.recommend_quests("player", 10);
+        assert_eq!(recommendations.len(), 2);
+        assert_eq!(recommendations[0].0.quest_id, "enochian_quest");
+        assert!(recommendations[0].1 > recommendations[1].1);
+    }
+    
+    #[test]
+    fn test_recommend_quests_excludes_unaffordable() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.game_states.get_mut("player").unwrap().energy_level = Energy(10);
+        
+        let mut expensive_quest = test_quest("expensive_quest", vec![]);
+        expensive_quest.required_energy = 20;
+        core.register_quest(expensive_quest).unwrap();
+        core.register_quest(test_quest("cheap_quest", vec![])).unwrap();
+        
+        let recommendations = core.recommend_quests("player", 10);
+        assert_eq!(recommendations.len(), 1);
+        assert_eq!(recommendations[0].0.quest_id, "cheap_quest");
+    }
+    
+    #[test]
+    fn test_governor_relationship_discounts_energy() {
+        let mut core = test_core();
+        core.create_player_state("friend".to_string()).unwrap();
+        core.create_player_state("stranger".to_string()).unwrap();
+        core.game_states.get_mut("friend").unwrap().governor_relationships.insert("ABRIOND".to_string(), 1.0);
+        
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.required_energy = 10;
+        core.register_quest(quest).unwrap();
+        
+        let friend_before = core.game_states["friend"].energy_level;
+        let stranger_before = core.game_states["stranger"].energy_level;
+        core.start_quest("friend", "quest_1").unwrap();
+        core.start_quest("stranger", "quest_1").unwrap();
+        
+        assert_eq!(friend_before.value() - core.game_states["friend"].energy_level.value(), 6);
+        assert_eq!(stranger_before.value() - core.game_states["stranger"].energy_level.value(), 10);
+        
+        // A fully waived cost still charges one point of energy
+        core.config.relationship_energy_discount = 1.0;
+        let cheap = test_quest("quest_2", vec![]);
+        assert_eq!(core.config.effective_energy_cost(&core.game_states["friend"], &cheap), 1);
+    }
+    
+    #[test]
+    fn test_duplicate_content_rejected() {
+        let config = SystemConfig { duplicate_content_policy: DuplicateContentPolicy::Reject, ..SystemConfig::default() };
+        let mut core = EnochianCore::new(config);
+        core.initialize().unwrap();
+        
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        let mut duplicate = test_quest("quest_2", vec![]);
+        duplicate.title = "  TEST   quest ".to_string();
+        
+        let err = core.register_quest(duplicate).unwrap_err();
+        assert!(err.to_string().contains("quest_1"));
+        assert_eq!(core.quest_registry.len(), 1);
+    }
+    
+    #[test]
+    fn test_duplicate_content_returns_existing() {
+        let config = SystemConfig { duplicate_content_policy: DuplicateContentPolicy::ReturnExisting, ..SystemConfig::default() };
+        let mut core = EnochianCore::new(config);
+        core.initialize().unwrap();
+        
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        assert_eq!(core.register_quest(test_quest("quest_2", vec![])).unwrap(), "quest_1");
+        assert!(core.get_quest("quest_2").is_none());
+    }
+    
+    #[test]
+    fn test_distinct_content_coexists() {
+        let config = SystemConfig { duplicate_content_policy: DuplicateContentPolicy::Reject, ..SystemConfig::default() };
+        let mut core = EnochianCore::new(config);
+        core.initialize().unwrap();
+        
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        let mut distinct = test_quest("quest_2", vec![]);
+        distinct.description = "Scry the thirtieth Aethyr".to_string();
+        
+        assert_eq!(core.register_quest(distinct).unwrap(), "quest_2");
+        assert_eq!(core.quest_registry.len(), 2);
+    }
+    
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_game_state_binary_round_trip() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut state = core.get_player_state("player").unwrap().clone();
+        state.completed_quests = vec!["quest_1_42".to_string(), "quest_7_99".to_string()];
+        state.tradition_mastery.insert("Hermetic_Qabalah".to_string(), Mastery(0.25));
+        state.governor_relationships.insert("ABRIOND".to_string(), 0.4);
+        state.reputation_scores.insert("Enochian".to_string(), 12.0);
+        
+        let bytes = state.to_bytes().unwrap();
+        let decoded = GameState::from_bytes(&bytes).unwrap();
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), serde_json::to_string(&state).unwrap());
+        assert_eq!(decoded.to_bytes().unwrap(), bytes);
+        
+        let json_len = serde_json::to_vec(&state).unwrap().len();
+        assert!(bytes.len() * 2 < json_len, "binary {} bytes vs JSON {} bytes", bytes.len(), json_len);
+    }
+    
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_game_state_from_bytes_rejects_garbage() {
+        assert!(matches!(
+            GameState::from_bytes(&[0xff, 0xff, 0xff]),
+            Err(EnochianError::BinaryError { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_health_reports_readiness() {
+        let _guard = crate::governors::SHARED_MANAGER_TEST_LOCK.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let core = EnochianCore::new(SystemConfig::default());
+        let report = core.health();
+        assert!(!report.initialized);
+        assert!(!report.ready);
+        
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1_1", vec![])).unwrap();
+        
+        let report = core.health();
+        assert!(report.ready);
+        assert!(report.sacred_constraints_passed);
+        assert!(report.failed_constraints.is_empty());
+        assert_eq!(report.player_count, 1);
+        assert_eq!(report.quest_count, 1);
+        assert_eq!(report.version, crate::VERSION);
+        
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["ready"], true);
+        
+        // Probes reuse the core's scorer rather than building one each time
+        let scorer: *const AuthenticityScorer = core.health_scorer.get().unwrap();
+        core.health();
+        assert!(std::ptr::eq(scorer, core.health_scorer.get().unwrap()));
+    }
+    
+    fn test_chain(core: &mut EnochianCore) -> QuestChain {
+        for (quest_id, governor_name) in [("quest_1_1", "ABRIOND"), ("quest_2_1", "GEDOONS"), ("quest_3_1", "MIRZIND")] {
+            let mut quest = test_quest(quest_id, vec![]);
+            quest.governor_name = governor_name.to_string();
+            quest.description = format!("Seek the counsel of {}", governor_name);
+            core.register_quest(quest).unwrap();
+        }
+        
+        let mut completion_bonus = test_quest("bonus", vec![]).rewards;
+        completion_bonus.bitcoin_rewards = 1000;
+        completion_bonus.sacred_items = vec!["Sigillum Dei Aemeth".to_string()];
+        QuestChain {
+            chain_id: "chain_creation".to_string(),
+            title: "The Arc of Creation".to_string(),
+            quest_ids: vec!["quest_1_1".to_string(), "quest_2_1".to_string(), "quest_3_1".to_string()],
+            completion_bonus,
+        }
+    }
+    
+    #[test]
+    fn test_chain_partial_progress() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let chain = test_chain(&mut core);
+        core.register_chain(chain).unwrap();
+        
+        core.start_quest("player", "quest_1_1").unwrap();
+        core.complete_quest("player", "quest_1_1").unwrap();
+        
+        let progress = core.chain_progress("player", "chain_creation").unwrap();
+        assert_eq!(progress.completed, 1);
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.next_quest.as_deref(), Some("quest_2_1"));
+        assert!(!progress.bonus_granted);
+        assert_eq!(core.get_player_state("player").unwrap().balance_sats, 0);
+    }
+    
+    #[test]
+    fn test_chain_completion_grants_bonus_once() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let chain = test_chain(&mut core);
+        core.register_chain(chain).unwrap();
+        
+        for quest_id in ["quest_1_1", "quest_2_1", "quest_3_1"] {
+            core.start_quest("player", quest_id).unwrap();
+            core.complete_quest("player", quest_id).unwrap();
+        }
+        
+        let progress = core.chain_progress("player", "chain_creation").unwrap();
+        assert_eq!(progress.completed, 3);
+        assert_eq!(progress.next_quest, None);
+        assert!(progress.bonus_granted);
+        
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(state.balance_sats, 1000);
+        assert_eq!(state.completed_chains, vec!["chain_creation".to_string()]);
+        
+        // Completing an unrelated quest afterwards must not re-grant the bonus
+        core.register_quest(test_quest("quest_4_1", vec![])).unwrap();
+        core.start_quest("player", "quest_4_1").unwrap();
+        core.complete_quest("player", "quest_4_1").unwrap();
+        assert_eq!(core.get_player_state("player").unwrap().balance_sats, 1000);
+    }
+    
+    #[test]
+    fn test_chain_rejects_unknown_quest() {
+        let mut core = test_core();
+        let mut chain = test_chain(&mut core);
+        chain.quest_ids.push("quest_9_9".to_string());
+        
+        let err = core.register_chain(chain).unwrap_err();
+        assert!(err.to_string().contains("quest_9_9"));
+    }
+    
+    #[test]
+    fn test_chain_rejects_cycles() {
+        let mut core = test_core();
+        let mut chain = test_chain(&mut core);
+        chain.quest_ids.push("quest_1_1".to_string());
+        assert!(core.register_chain(chain).is_err());
+        
+        // quest_1_1 requiring quest_3_1 contradicts the chain order
+        let mut core = test_core();
+        let chain = test_chain(&mut core);
+        core.quest_registry.get_mut("quest_1_1").unwrap().prerequisites = vec!["quest_3_1".to_string()];
+        let err = core.register_chain(chain).unwrap_err();
+        assert!(err.to_string().contains("cyclic"));
+    }
+    
+    #[test]
+    fn test_custom_starting_loadout() {
+        let mut core = test_core();
+        let mut loadout = StartingLoadout { energy_level: Energy(10), ..StartingLoadout::default() };
+        loadout.tradition_mastery.insert("Enochian".to_string(), Mastery(0.5));
+        loadout.tradition_mastery.insert("Hermetic_Qabalah".to_string(), Mastery(0.4));
+        loadout.aethyr_access = vec![1, 2, 3];
+        
+        let state = core.create_player_state_with_loadout("veteran".to_string(), loadout).unwrap();
+        assert_eq!(state.energy_level.value(), 10);
+        assert_eq!(state.tradition_mastery.get("Hermetic_Qabalah"), Some(&Mastery(0.4)));
+        assert_eq!(state.aethyr_access, vec![1, 2, 3]);
+        
+        let default_state = core.create_player_state("novice".to_string()).unwrap();
+        assert_eq!(default_state.energy_level, Energy::FULL);
+        assert_eq!(default_state.aethyr_access, vec![1]);
+    }
+    
+    #[test]
+    fn test_invalid_starting_loadout_rejected() {
+        let mut core = test_core();
+        
+        let loadout = StartingLoadout { aethyr_access: vec![1, 31], ..StartingLoadout::default() };
+        assert!(core.create_player_state_with_loadout("p1".to_string(), loadout).is_err());
+        
+        // Hermetic mastery above its cap without the Enochian foundation
+        let mut loadout = StartingLoadout::default();
+        loadout.tradition_mastery.insert("Hermetic_Qabalah".to_string(), Mastery(0.6));
+        assert!(core.create_player_state_with_loadout("p2".to_string(), loadout).is_err());
+        
+        let mut loadout = StartingLoadout::default();
+        loadout.tradition_mastery.insert("Unknown".to_string(), Mastery(0.1));
+        assert!(matches!(
+            core.create_player_state_with_loadout("p3".to_string(), loadout),
+            Err(EnochianError::TraditionNotSupported { .. })
+        ));
+        
+        assert!(core.get_player_state("p1").is_none());
+    }
+    
+    #[test]
+    fn test_illumination_rewards_broad_progress() {
+        let mut core = test_core();
+        let mut narrow = core.create_player_state("narrow".to_string()).unwrap().clone();
+        narrow.tradition_mastery.insert("Enochian".to_string(), Mastery(0.5));
+        let mut broad = narrow.clone();
+        
+        broad.tradition_mastery.insert("Hermetic_Qabalah".to_string(), Mastery(0.4));
+        broad.tradition_mastery.insert("Golden_Dawn".to_string(), Mastery(0.3));
+        broad.aethyr_access = vec![1, 2, 3, 4, 5];
+        broad.governor_relationships.insert("ABRIOND".to_string(), 0.8);
+        broad.governor_relationships.insert("GEDOONS".to_string(), 0.5);
+        broad.completed_quests = vec!["quest_1".to_string(), "quest_2".to_string()];
+        
+        let narrow_score = narrow.illumination_score();
+        let broad_score = broad.illumination_score();
+        assert!(broad_score > narrow_score);
+        assert!((0.0..=1.0).contains(&narrow_score));
+        assert!((0.0..=1.0).contains(&broad_score));
+        assert_eq!(broad_score, broad.clone().illumination_score());
+    }
+    
+    #[test]
+    fn test_illumination_favours_enochian_progress() {
+        let mut core = test_core();
+        let base = core.create_player_state("player".to_string()).unwrap().clone();
+        
+        let mut enochian = base.clone();
+        enochian.tradition_mastery.insert("Enochian".to_string(), Mastery(0.6));
+        let mut hermetic = base.clone();
+        hermetic.tradition_mastery.insert("Hermetic_Qabalah".to_string(), Mastery(0.5));
+        
+        let enochian_gain = enochian.illumination_score() - base.illumination_score();
+        let hermetic_gain = hermetic.illumination_score() - base.illumination_score();
+        assert!(enochian_gain > hermetic_gain);
+        
+        // Full Enochian mastery alone is worth 0.6 of the mastery component
+        let mut master = base.clone();
+        master.tradition_mastery = BTreeMap::from([("Enochian".to_string(), Mastery::FULL)]);
+        master.aethyr_access.clear();
+        assert!((master.illumination_score() - ILLUMINATION_MASTERY_WEIGHT * 0.6).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_fractional_energy_changes_round_half_to_even() {
+        assert_eq!(Energy::round_change(2.5), Some(2));
+        assert_eq!(Energy::round_change(3.5), Some(4));
+        assert_eq!(Energy::round_change(-0.5), Some(0));
+        assert_eq!(Energy::round_change(-1.5), Some(-2));
+        assert_eq!(Energy::round_change(0.49), Some(0));
+        assert_eq!(Energy::round_change(f64::NAN), None);
+        
+        // The change rounds the same whatever energy it applies to
+        assert_eq!(Energy(10).adjusted(2.5).value(), 12);
+        assert_eq!(Energy(11).adjusted(2.5).value(), 13);
+        assert_eq!(Energy(10).adjusted(-0.5).value(), 10);
+        
+        // Boundaries: a fractional drain at zero and a gain at the cap saturate
+        assert_eq!(Energy(0).adjusted(-0.4), Energy::ZERO);
+        assert_eq!(Energy(1).adjusted(-1.5), Energy::ZERO);
+        assert_eq!(Energy(24).adjusted(1.5), Energy::FULL);
+        assert_eq!(Energy::FULL.adjusted(0.5), Energy::FULL);
+        
+        let drain = StateConsequence {
+            consequence_type: ConsequenceType::EnergyModification,
+            target: "energy".to_string(),
+            value_change: -2.5,
+        };
+        assert_eq!(drain.preview(), "-2 energy");
+    }
+    
+    #[test]
+    fn test_energy_saturates_and_rejects_out_of_range() {
+        assert!(Energy::new(Energy::MAX).is_ok());
+        assert!(matches!(Energy::new(26), Err(EnochianError::InvalidEnergy { value: 26, max: 25 })));
+        
+        let energy = Energy::new(20).unwrap();
+        assert_eq!(energy.saturating_add(10), Energy::FULL);
+        assert_eq!(energy.saturating_sub(30), Energy::ZERO);
+        assert_eq!(energy.adjusted(3.7).value(), 24);
+        assert_eq!(energy.adjusted(-100.0), Energy::ZERO);
+        assert_eq!(energy.adjusted(f64::NAN), energy);
+        assert_eq!(energy.adjusted(f64::INFINITY), Energy::FULL);
+        assert_eq!(Energy::saturating(40), Energy::FULL);
+        
+        assert_eq!(serde_json::to_string(&energy).unwrap(), "20");
+        assert!(serde_json::from_str::<Energy>("26").is_err());
+    }
+    
+    #[test]
+    fn test_mastery_saturates_and_rejects_out_of_range() {
+        assert!(Mastery::new(1.0).is_ok());
+        assert!(matches!(Mastery::new(1.2), Err(EnochianError::InvalidMastery { .. })));
+        assert!(Mastery::new(-0.1).is_err());
+        assert!(Mastery::new(f64::NAN).is_err());
+        
+        let mastery = Mastery::new(0.8).unwrap();
+        assert_eq!(mastery.saturating_add(0.5), Mastery::FULL);
+        assert_eq!(mastery.saturating_add(-2.0), Mastery::ZERO);
+        assert_eq!(mastery.saturating_add(f64::NAN), mastery);
+        assert_eq!(Mastery::clamped(f64::NAN), Mastery::ZERO);
+        
+        assert_eq!(serde_json::to_string(&mastery).unwrap(), "0.8");
+        assert!(serde_json::from_str::<Mastery>("1.5").is_err());
+    }
+    
+    #[test]
+    fn test_public_view_omits_financial_fields() {
+        let mut state = merkle_state(&["quest_1", "quest_2"]);
+        state.balance_sats = 50_000;
+        state.staked_amount = 20_000;
+        state.pending_rewards = 1_000;
+        state.governor_relationships.insert("ABRIOND".to_string(), 0.4);
+        
+        let public = serde_json::to_value(state.public_view()).unwrap();
+        for field in ["balance_sats", "staked_amount", "pending_rewards", "owned_hypertokens"] {
+            assert!(public.get(field).is_none(), "{} exposed", field);
+        }
+        assert_eq!(public["completed_quests"], serde_json::json!(["quest_1", "quest_2"]));
+        assert_eq!(public["completed_quests_merkle_root"], state.completed_quests_merkle_root());
+        assert_eq!(public["governor_relationships"]["ABRIOND"], 0.4);
+        assert_eq!(public["illumination_score"], state.illumination_score());
+        
+        // Persistence keeps everything
+        let full = serde_json::to_value(&state).unwrap();
+        assert_eq!(full["balance_sats"], 50_000);
+    }
+    
+    fn merkle_state(quest_ids: &[&str]) -> GameState {
+        let mut state = test_core().create_player_state("player".to_string()).unwrap().clone();
+        state.completed_quests = quest_ids.iter().map(|id| id.to_string()).collect();
+        state
+    }
+    
+    #[test]
+    fn test_merkle_inclusion_proof_verifies() {
+        let state = merkle_state(&["quest_1", "quest_2", "quest_3", "quest_4", "quest_5"]);
+        let root = state.completed_quests_merkle_root();
+        
+        for quest_id in &state.completed_quests {
+            let proof = state.merkle_proof(quest_id).unwrap();
+            assert!(verify_proof(&root, quest_id, &proof));
+        }
+        
+        // A proof only holds for the quest it was built for
+        let proof = state.merkle_proof("quest_2").unwrap();
+        assert!(!verify_proof(&root, "quest_3", &proof));
+    }
+    
+    #[test]
+    fn test_merkle_non_member_fails() {
+        let state = merkle_state(&["quest_1", "quest_2", "quest_3"]);
+        let root = state.completed_quests_merkle_root();
+        
+        assert!(state.merkle_proof("quest_9").is_none());
+        let borrowed = state.merkle_proof("quest_1").unwrap();
+        assert!(!verify_proof(&root, "quest_9", &borrowed));
+        assert!(!verify_proof(&root, "quest_9", &[]));
+    }
+    
+    #[test]
+    fn test_merkle_root_stable_under_reordering() {
+        let forward = merkle_state(&["quest_1", "quest_2", "quest_3"]);
+        let reversed = merkle_state(&["quest_3", "quest_1", "quest_2"]);
+        assert_eq!(forward.completed_quests_merkle_root(), reversed.completed_quests_merkle_root());
+        
+        let extended = merkle_state(&["quest_1", "quest_2", "quest_3", "quest_4"]);
+        assert_ne!(forward.completed_quests_merkle_root(), extended.completed_quests_merkle_root());
+        
+        // A single quest is its own leaf, so the proof is empty
+        let single = merkle_state(&["quest_1"]);
+        assert!(verify_proof(&single.completed_quests_merkle_root(), "quest_1", &single.merkle_proof("quest_1").unwrap()));
+    }
+    
+    fn registry_core(quest_ids: &[&str]) -> EnochianCore {
+        let mut core = test_core();
+        for quest_id in quest_ids {
+            let mut quest = test_quest(quest_id, vec![]);
+            quest.title = format!("The Vision of {}", quest_id);
+            core.register_quest(quest).unwrap();
+        }
+        core
+    }
+    
+    #[test]
+    fn test_registry_root_stable_and_proofs_verify() {
+        let core = registry_core(&["quest_1", "quest_2", "quest_3"]);
+        let root = core.registry_merkle_root();
+        assert_eq!(root, registry_core(&["quest_3", "quest_1", "quest_2"]).registry_merkle_root());
+        assert_ne!(root, registry_core(&["quest_1", "quest_2"]).registry_merkle_root());
+        
+        for quest in core.quests() {
+            let proof = core.registry_proof(&quest.quest_id).unwrap();
+            assert!(verify_proof(&root, &quest.content_fingerprint(), &proof));
+        }
+    }
+    
+    #[test]
+    fn test_registry_proof_rejects_unregistered_quest() {
+        let core = registry_core(&["quest_1", "quest_2", "quest_3"]);
+        let root = core.registry_merkle_root();
+        assert!(core.registry_proof("quest_9").is_none());
+        
+        let outsider = registry_core(&["quest_9"]);
+        let fingerprint = outsider.get_quest("quest_9").unwrap().content_fingerprint();
+        let borrowed = core.registry_proof("quest_1").unwrap();
+        assert!(!verify_proof(&root, &fingerprint, &borrowed));
+        assert!(!verify_proof(&root, &fingerprint, &outsider.registry_proof("quest_9").unwrap()));
+    }
+    
+    #[test]
+    fn test_objective_requires_matching_action() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.objectives.push(QuestObjective::new(ObjectiveKind::Ritual, "Perform the Lesser Banishing"));
+        core.register_quest(quest).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        
+        match core.complete_objective("player", "quest_1", 1, ActionType::InteractWithGovernor) {
+            Err(EnochianError::ObjectiveActionMismatch { objective, expected, found, .. }) => {
+                assert_eq!(objective, 1);
+                assert_eq!(expected, "PerformRitual");
+                assert_eq!(found, "InteractWithGovernor");
+            }
+            other => panic!("expected ObjectiveActionMismatch, got {:?}", other),
+        }
+        assert!(core.game_states["player"].completed_objectives.is_empty());
+        
+        core.complete_objective("player", "quest_1", 1, ActionType::PerformRitual).unwrap();
+        assert_eq!(core.game_states["player"].completed_objectives["quest_1"], vec![1]);
+        assert!(core.complete_objective("player", "quest_1", 1, ActionType::PerformRitual).is_err());
+        
+        core.complete_quest("player", "quest_1").unwrap();
+        assert!(core.game_states["player"].completed_objectives.is_empty());
+    }
+    
+    #[test]
+    fn test_plain_objectives_are_typed() {
+        let objectives: Vec<QuestObjective> = serde_json::from_value(serde_json::json!([
+            "Perform the ritual of the Watchtowers",
+            "Scry the first Aethyr",
+            "Study the Enochian tablets",
+            {"kind": "Interact", "description": "Seek counsel", "target": "ABRIOND"}
+        ])).unwrap();
+        
+        let kinds: Vec<ObjectiveKind> = objectives.iter().map(|objective| objective.kind).collect();
+        assert_eq!(kinds, vec![ObjectiveKind::Ritual, ObjectiveKind::Divination, ObjectiveKind::Study, ObjectiveKind::Interact]);
+        assert_eq!(objectives[3].target.as_deref(), Some("ABRIOND"));
+        assert_eq!(objectives[2].to_string(), "Study the Enochian tablets");
+    }
+    
+    #[test]
+    fn test_batch_creation_reports_each_failure() {
+        let mut core = test_core();
+        core.create_player_state("existing".to_string()).unwrap();
+        
+        let ids = ["alice", "existing", "bob", "alice", "", "with space"];
+        let result = core.create_players(ids.iter().map(|id| id.to_string()).collect()).unwrap();
+        
+        assert_eq!(result.created, vec!["alice".to_string(), "bob".to_string()]);
+        let failed: Vec<&str> = result.failed.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(failed, vec!["existing", "alice", "", "with space"]);
+        assert!(result.failed[0].1.contains("already exists"));
+        assert!(result.failed[2].1.contains("must not be empty"));
+        assert!(!result.rolled_back);
+        assert_eq!(core.game_states.len(), 3);
+    }
+    
+    #[test]
+    fn test_strict_batch_is_all_or_nothing() {
+        let mut core = test_core();
+        core.create_player_state("existing".to_string()).unwrap();
+        
+        let result = core.create_players_strict(vec!["alice".to_string(), "existing".to_string()]).unwrap();
+        assert!(result.rolled_back);
+        assert!(result.created.is_empty());
+        assert_eq!(result.failed.len(), 1);
+        assert!(!core.game_states.contains_key("alice"));
+        assert!(core.game_states.contains_key("existing"));
+        
+        let result = core.create_players_strict(vec!["alice".to_string(), "bob".to_string()]).unwrap();
+        assert!(!result.rolled_back);
+        assert_eq!(result.created.len(), 2);
+        assert_eq!(core.game_states.len(), 3);
+    }
+    
+    fn revoke(aethyr: &str) -> StateConsequence {
+        StateConsequence {
+            consequence_type: ConsequenceType::AethyrRevoke,
+            target: aethyr.to_string(),
+            value_change: 0.0,
+        }
+    }
+    
+    #[test]
+    fn test_revoke_high_aethyr() {
+        let mut core = test_core();
+        core.config.max_state_history = 16;
+        core.create_player_state("player".to_string()).unwrap();
+        for aethyr in [12, 30] {
+            core.game_states.get_mut("player").unwrap().grant_aethyr_access(aethyr).unwrap();
+        }
+        
+        core.apply_penalty("player", &[revoke("30")]).unwrap();
+        let state = &core.game_states["player"];
+        assert_eq!(state.aethyr_access, vec![1, 12]);
+        assert_eq!(state.version, 2);
+        assert_eq!(core.state_history.last().unwrap().aethyr_access, vec![1, 12, 30]);
+        assert_eq!(revoke("30").preview(), "Lose access to Aethyr 30");
+    }
+    
+    #[test]
+    fn test_revoke_keeps_starting_aethyr() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        
+        core.apply_penalty("player", &[revoke("1")]).unwrap();
+        assert_eq!(core.game_states["player"].aethyr_access, vec![STARTING_AETHYR]);
+        assert!(!core.game_states.get_mut("player").unwrap().revoke_aethyr_access(1).unwrap());
+        
+        // Out-of-range targets are rejected without changing anything
+        assert!(matches!(
+            core.apply_penalty("player", &[revoke("2"), revoke("31")]),
+            Err(EnochianError::InvalidAethyr { .. })
+        ));
+        assert_eq!(core.game_states["player"].version, 2);
+    }
+    
+    #[test]
+    fn test_aethyr_above_range_rejected() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.rewards.aethyr_access_gained = vec![2, 31];
+        assert!(matches!(core.register_quest(quest), Err(EnochianError::InvalidAethyr { .. })));
+        
+        let unlock = StateConsequence {
+            consequence_type: ConsequenceType::AethyrAccess,
+            target: "31".to_string(),
+            value_change: 1.0,
+        };
+        let quest = test_quest("quest_2", vec![test_choice("unlock", vec![unlock])]);
+        assert!(matches!(core.register_quest(quest), Err(EnochianError::InvalidAethyr { .. })));
+        
+        let mut state = core.get_player_state("player").unwrap().clone();
+        assert!(state.grant_aethyr_access(31).is_err());
+        assert!(state.grant_aethyr_access(0).is_err());
+        assert_eq!(state.aethyr_access, vec![1]);
+        
+        state.aethyr_access.push(31);
+        assert!(matches!(
+            core.update_player_state("player", state),
+            Err(EnochianError::InvalidAethyr { .. })
+        ));
+        assert_eq!(core.get_player_state("player").unwrap().aethyr_access, vec![1]);
+    }
+    
+    #[test]
+    fn test_aethyr_access_sorted_and_unique() {
+        let mut core = test_core();
+        let loadout = StartingLoadout { aethyr_access: vec![4, 1], ..StartingLoadout::default() };
+        core.create_player_state_with_loadout("player".to_string(), loadout).unwrap();
+        assert_eq!(core.get_player_state("player").unwrap().aethyr_access, vec![1, 4]);
+        
+        let unlock = StateConsequence {
+            consequence_type: ConsequenceType::AethyrAccess,
+            target: "3".to_string(),
+            value_change: 1.0,
+        };
+        let mut quest = test_quest("quest_1", vec![test_choice("unlock", vec![unlock])]);
+        quest.rewards.aethyr_access_gained = vec![2, 4, 2];
+        core.register_quest(quest).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        core.make_quest_choice("player", "quest_1", "unlock").unwrap();
+        core.complete_quest("player", "quest_1").unwrap();
+        assert_eq!(core.get_player_state("player").unwrap().aethyr_access, vec![1, 2, 3, 4]);
+        
+        let mut state = core.get_player_state("player").unwrap().clone();
+        state.aethyr_access = vec![5, 2, 5];
+        core.update_player_state("player", state).unwrap();
+        assert_eq!(core.get_player_state("player").unwrap().aethyr_access, vec![2, 5]);
+    }
+    
+    #[test]
+    fn test_quest_iterators_cover_registry() {
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.register_quest(test_quest("quest_2", vec![])).unwrap();
+        let mut other = test_quest("quest_3", vec![]);
+        other.governor_name = "GEDOONS".to_string();
+        other.title = "Another Quest".to_string();
+        core.register_quest(other).unwrap();
+        
+        let mut all: Vec<&str> = core.quests().map(|quest| quest.quest_id.as_str()).collect();
+        all.sort();
+        assert_eq!(all, vec!["quest_1", "quest_2", "quest_3"]);
+        
+        assert_eq!(core.quests_by_governor("ABRIOND").count(), 2);
+        assert_eq!(core.quests_by_governor("GEDOONS").count(), 1);
+        assert_eq!(core.quests_by_governor("NOBODY").count(), 0);
+    }
+    
+    #[test]
+    fn test_canonical_rendering_is_stable() {
+        let mut quest = test_quest("quest_1", vec![test_choice("invoke", vec![])]);
+        let rendered = quest.render_canonical();
+        assert_eq!(rendered, "Test Quest\n\
+            Governor: ABRIOND\n\
+            Traditions: Enochian\n\
+            \n\
+            A test quest\n\
+            \n\
+            Objectives:\n\
+            1. Study Enochian principles\n\
+            \n\
+            Wisdom: Test wisdom\n\
+            \n\
+            Choices:\n\
+            - Perform the invocation\n  \
+            * Deepen Enochian understanding\n");
+        
+        let round_tripped: QuestData = serde_json::from_str(&serde_json::to_string(&quest).unwrap()).unwrap();
+        assert_eq!(round_tripped.render_canonical(), rendered);
+        
+        // Not rendered, so neither the text nor the fingerprint changes
+        quest.quest_id = "quest_2".to_string();
+        quest.authenticity_score = 0.99;
+        assert_eq!(quest.render_canonical(), rendered);
+        
+        let edits: Vec<fn(&mut QuestData)> = vec![
+            |q| q.title.push('!'),
+            |q| q.governor_name = "GEDOONS".to_string(),
+            |q| q.tradition_integration.push("Thelema".to_string()),
+            |q| q.description.push('!'),
+            |q| q.objectives[0].description.push('!'),
+            |q| q.wisdom_taught.push('!'),
+            |q| q.choice_branches[0].description.push('!'),
+            |q| q.choice_branches[0].consequences.clear(),
+            |q| q.council.push("ZAMFRES".to_string()),
+        ];
+        for edit in edits {
+            let mut edited = quest.clone();
+            edit(&mut edited);
+            assert_ne!(edited.render_canonical(), rendered);
+            assert_ne!(edited.content_fingerprint(), quest.content_fingerprint());
+        }
+    }
+    
+    #[test]
+    fn test_rescore_reports_drift() {
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.register_quest(test_quest("quest_2", vec![])).unwrap();
+        let scorer = AuthenticityScorer::new();
+        
+        // Align stored scores with the scorer so only later edits drift
+        for (quest_id, drift) in core.rescore_all_authenticity(&scorer) {
+            core.quest_registry.get_mut(&quest_id).unwrap().authenticity_score += drift;
+        }
+        core.quest_registry.get_mut("quest_2").unwrap().authenticity_score += 0.1;
+        
+        let drift = core.rescore_all_authenticity(&scorer);
+        assert_eq!(drift.len(), 2);
+        assert_eq!(drift[0].0, "quest_1");
+        assert!(drift[0].1.abs() < 1e-9);
+        assert_eq!(drift[1].0, "quest_2");
+        assert!((drift[1].1 + 0.1).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_tightened_scorer_flags_borderline_quest() {
+        let mut core = test_core();
+        core.config.quarantine_on_revalidation = true;
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        
+        // Put the threshold just under the quest's current score
+        let scorer = AuthenticityScorer::new();
+        assert!(core.needs_revalidation(&scorer));
+        let baseline = core.revalidate_registry(&scorer);
+        core.config.authenticity_threshold = baseline[0].score - 0.001;
+        assert!(!core.revalidate_registry(&scorer)[0].below_threshold);
+        assert!(!core.needs_revalidation(&scorer));
+        
+        // Penalizing a term the quest uses drops it below the threshold
+        let mut terms = DangerousTerms::default();
+        terms.terms.push("study".to_string());
+        let tightened = AuthenticityScorer::new().with_dangerous_terms("Enochian", terms);
+        assert!(core.needs_revalidation(&tightened));
+        
+        let results = core.revalidate_registry(&tightened);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].below_threshold && results[0].quarantined);
+        assert!(results[0].score < results[0].previous_score);
+        assert_eq!(core.get_quest("quest_1").unwrap().authenticity_score, results[0].score);
+        assert!(matches!(core.start_quest("player", "quest_1"), Err(EnochianError::QuestQuarantined { .. })));
+        assert!(core.recommend_quests("player", 5).is_empty());
+        
+        // Reverting the lexicon releases the quest
+        assert!(!core.revalidate_registry(&scorer)[0].below_threshold);
+        assert!(core.quarantined_quests.is_empty());
+        core.start_quest("player", "quest_1").unwrap();
+    }
+    
+    #[test]
+    fn test_duration_estimate_grows_with_objectives_and_difficulty() {
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.objectives.clear();
+        quest.difficulty_level = 1;
+        assert_eq!(quest.estimate_duration(), BASE_QUEST_MINUTES);
+        
+        let mut previous = quest.estimate_duration();
+        for kind in [ObjectiveKind::Interact, ObjectiveKind::Ritual, ObjectiveKind::Study] {
+            quest.objectives.push(QuestObjective::new(kind, "Objective"));
+            assert!(quest.estimate_duration() > previous);
+            previous = quest.estimate_duration();
+        }
+        
+        for difficulty in 2..=10 {
+            quest.difficulty_level = difficulty;
+            assert!(quest.estimate_duration() > previous);
+            previous = quest.estimate_duration();
+        }
+        
+        quest.choice_branches.push(test_choice("choice_1", vec![]));
+        assert_eq!(quest.estimate_duration(), previous + MINUTES_PER_BRANCH);
+    }
+    
+    #[test]
+    fn test_derived_energy_is_monotonic_and_capped() {
+        for objectives in 0..6 {
+            for difficulty in 1..10 {
+                assert!(derive_required_energy(difficulty + 1, objectives) > derive_required_energy(difficulty, objectives));
+                assert!(derive_required_energy(difficulty, objectives + 1) >= derive_required_energy(difficulty, objectives));
+            }
+        }
+        assert_eq!(derive_required_energy(3, 1), 7);
+        assert_eq!(derive_required_energy(10, 20), Energy::MAX);
+        assert_eq!(derive_required_energy(0, 0), derive_required_energy(1, 0));
+    }
+    
+    #[test]
+    fn test_energy_inconsistent_with_difficulty_rejected() {
+        let mut core = test_core();
+        core.config.energy_tolerance = Some(3);
+        
+        let mut mismatched = test_quest("quest_1", vec![]);
+        mismatched.difficulty_level = 10;
+        mismatched.required_energy = 1;
+        assert!(core.register_quest(mismatched.clone()).is_err());
+        
+        mismatched.required_energy = derive_required_energy(10, 1) - 3;
+        core.register_quest(mismatched.clone()).unwrap();
+        
+        // Unchecked without a tolerance
+        let mut unchecked = test_core();
+        mismatched.required_energy = 1;
+        unchecked.register_quest(mismatched).unwrap();
+    }
+    
+    #[test]
+    fn test_lint_reports_every_issue_without_registering() {
+        let mut core = test_core();
+        let mut first = test_quest("quest_1", vec![]);
+        first.prerequisites = vec!["quest_2".to_string()];
+        first.title = "First".to_string();
+        core.register_quest(first).unwrap();
+        
+        let mut flawed = test_quest("quest_2", vec![test_choice("risky", vec![])]);
+        flawed.choice_branches[0].authenticity_impact = -0.2;
+        flawed.tradition_integration = vec!["Enochian".to_string(), "Atlantean".to_string()];
+        flawed.difficulty_level = 11;
+        flawed.prerequisites = vec!["quest_1".to_string(), "quest_9".to_string()];
+        flawed.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 1.5);
+        flawed.rewards.aethyr_access_gained = vec![31];
+        flawed.description = "Null\0byte".to_string();
+        
+        let report = core.lint_quest(&flawed);
+        assert!(report.has_errors());
+        let issues: Vec<(LintSeverity, &str)> = report.issues.iter()
+            .map(|issue| (issue.severity, issue.location.as_str()))
+            .collect();
+        assert_eq!(issues, vec![
+            (LintSeverity::Error, "choice_branches[0]"),
+            (LintSeverity::Warning, "tradition_integration[1]"),
+            (LintSeverity::Error, "difficulty_level"),
+            (LintSeverity::Error, "prerequisites[0]"),
+            (LintSeverity::Warning, "prerequisites[1]"),
+            (LintSeverity::Error, "rewards.aethyr_access_gained"),
+            (LintSeverity::Error, "rewards.tradition_mastery_gains"),
+            (LintSeverity::Error, "content"),
+        ]);
+        assert!(core.get_quest("quest_2").is_none());
+    }
+    
+    #[test]
+    fn test_lint_passes_good_quest() {
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        let mut good = test_quest("quest_2", vec![test_choice("choice_1", vec![])]);
+        good.title = "Second".to_string();
+        good.prerequisites = vec!["quest_1".to_string()];
+        
+        let report = core.lint_quest(&good);
+        assert!(report.is_clean(), "{:?}", report.issues);
+        assert!(core.lint_quest(core.get_quest("quest_1").unwrap()).has_errors());
+    }
+    
+    #[test]
+    fn test_registration_fails_on_every_lint_error() {
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        
+        // Reward bounds the lint flags now block registration too
+        let mut generous = test_quest("quest_2", vec![]);
+        generous.title = "Generous".to_string();
+        generous.rewards.governor_relationship_changes.insert("ABRIOND".to_string(), 2.0);
+        let issue = &core.lint_quest(&generous).issues[0];
+        let error = core.register_quest(generous).unwrap_err();
+        assert_eq!(issue.location, "rewards.governor_relationship_changes");
+        assert_eq!(issue.message, error.to_string());
+        
+        // Duplicate content is an error only when the policy rejects it
+        let copy = test_quest("quest_3", vec![]);
+        assert!(core.lint_quest(&copy).is_clean());
+        core.config.duplicate_content_policy = DuplicateContentPolicy::ReturnExisting;
+        assert_eq!(core.lint_quest(&copy).issues[0].severity, LintSeverity::Warning);
+        core.config.duplicate_content_policy = DuplicateContentPolicy::Reject;
+        assert_eq!(core.lint_quest(&copy).issues[0].severity, LintSeverity::Error);
+        assert!(core.register_quest(copy).unwrap_err().to_string().contains("duplicates existing quest quest_1"));
+    }
+    
+    #[test]
+    fn test_quest_without_traditions_rejected() {
+        let mut core = test_core();
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.tradition_integration.clear();
+        assert!(core.register_quest(quest.clone()).is_err());
+        
+        quest.tradition_integration = vec!["Unknown_Tradition".to_string()];
+        assert!(matches!(core.register_quest(quest), Err(EnochianError::TraditionNotSupported { .. })));
+        assert!(core.get_quest("quest_1").is_none());
+    }
+    
+    #[test]
+    fn test_quest_without_traditions_defaults_to_enochian() {
+        let mut core = test_core();
+        core.config.default_to_enochian = true;
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.tradition_integration.clear();
+        core.register_quest(quest).unwrap();
+        assert_eq!(core.get_quest("quest_1").unwrap().tradition_integration, vec!["Enochian".to_string()]);
+    }
+    
+    #[test]
+    fn test_rising_authenticity_has_positive_slope() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        for (i, score) in [0.95, 0.96, 0.97, 0.98].iter().enumerate() {
+            let quest_id = format!("quest_{}", i);
+            let mut quest = test_quest(&quest_id, vec![]);
+            quest.authenticity_score = *score;
+            quest.title = format!("Quest {}", i);
+            core.register_quest(quest).unwrap();
+            core.start_quest("player", &quest_id).unwrap();
+            core.complete_quest("player", &quest_id).unwrap();
+        }
+        
+        let state = core.get_player_state("player").unwrap();
+        let (average, slope) = state.authenticity_trend();
+        assert!((average - 0.965).abs() < 1e-9);
+        assert!((slope - 0.01).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_authenticity_window_is_bounded() {
+        let mut core = test_core();
+        let mut state = core.create_player_state("player".to_string()).unwrap().clone();
+        assert_eq!(state.authenticity_trend(), (0.85, 0.0));
+        
+        for i in 0..(AUTHENTICITY_TREND_WINDOW + 5) {
+            state.record_authenticity(1.0 - i as f64 * 0.01);
+        }
+        assert_eq!(state.authenticity_history.len(), AUTHENTICITY_TREND_WINDOW);
+        assert!((state.authenticity_history[0] - 0.95).abs() < 1e-9);
+        assert!(state.authenticity_trend().1 < 0.0);
+    }
+    
+    #[test]
+    fn test_quest_seed_derivation_is_deterministic() {
+        let block = "00000000000000000002a7c4c1e48d76c5a37902165a270156b7a8d72728a054";
+        let seed = EnochianCore::derive_quest_seed("player", 1, block);
+        assert_eq!(seed, EnochianCore::derive_quest_seed("player", 1, block));
+        assert_ne!(seed, EnochianCore::derive_quest_seed("player", 2, block));
+        assert_ne!(seed, EnochianCore::derive_quest_seed("player2", 1, block));
+    }
+    
+    #[test]
+    fn test_quest_seed_derivation_is_well_distributed() {
+        let mut buckets = [0usize; 8];
+        for i in 0..4000 {
+            let block = format!("{:064x}", i);
+            let seed = EnochianCore::derive_quest_seed("player", 1, &block);
+            buckets[(seed % 8) as usize] += 1;
+        }
+        
+        // Each bucket should hold close to 500 seeds
+        for count in buckets {
+            assert!((400..=600).contains(&count), "bucket count {}", count);
+        }
+    }
+    
+    fn play_outcomes(core: &mut EnochianCore, quest_id: &str, completions: usize, abandons: usize) {
+        for i in 0..(completions + abandons) {
+            let player_id = format!("player_{}", i);
+            core.create_player_state(player_id.clone()).unwrap();
+            core.start_quest(&player_id, quest_id).unwrap();
+            if i < completions {
+                core.complete_quest(&player_id, quest_id).unwrap();
+            } else {
+                core.abandon_quest(&player_id, quest_id).unwrap();
+            }
+        }
+    }
+    
+    #[test]
+    fn test_suggest_difficulty_requires_samples() {
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        play_outcomes(&mut core, "quest_1", 5, 4);
+        assert_eq!(core.suggest_difficulty("quest_1"), None);
+        assert_eq!(core.suggest_difficulty("missing"), None);
+    }
+    
+    #[test]
+    fn test_suggest_difficulty_follows_completion_rate() {
+        // Every attempt succeeds: easier than its rating of 3
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        play_outcomes(&mut core, "quest_1", 12, 0);
+        assert!(core.suggest_difficulty("quest_1").unwrap() < 3);
+        
+        // Most players give up: harder than its rating
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        play_outcomes(&mut core, "quest_1", 2, 10);
+        assert!(core.suggest_difficulty("quest_1").unwrap() > 3);
+        
+        // On-target completion keeps the current rating
+        let mut core = test_core();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        play_outcomes(&mut core, "quest_1", 7, 3);
+        assert_eq!(core.suggest_difficulty("quest_1"), Some(3));
+        assert_eq!(core.get_player_state("player_9").unwrap().active_quests.len(), 0);
+    }
+    
+    fn authenticity_playthrough(choice_id: &str) -> QuestRewards {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        
+        let mut authentic = test_choice("authentic", vec![]);
+        authentic.authenticity_impact = 0.15;
+        let mut careless = test_choice("careless", vec![]);
+        careless.authenticity_impact = -0.01;
+        let mut quest = test_quest("quest_1", vec![authentic, careless]);
+        quest.rewards.bitcoin_rewards = 1000;
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.1);
+        quest.rewards.reputation_changes.insert("overall".to_string(), 0.2);
+        core.register_quest(quest).unwrap();
+        
+        core.start_quest("player", "quest_1").unwrap();
+        core.make_quest_choice("player", "quest_1", choice_id).unwrap();
+        let rewards = core.complete_quest("player", "quest_1").unwrap();
+        
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(state.balance_sats, rewards.bitcoin_rewards);
+        assert!(state.quest_choices.is_empty());
+        rewards
+    }
+    
+    #[test]
+    fn test_authentic_playthrough_earns_more() {
+        let authentic = authenticity_playthrough("authentic");
+        let careless = authenticity_playthrough("careless");
+        
+        assert_eq!(authentic.bitcoin_rewards, 1000);
+        assert_eq!(careless.bitcoin_rewards, (1000.0 * MIN_REWARD_SCALE) as u64);
+        assert!(authentic.tradition_mastery_gains["Enochian"] > careless.tradition_mastery_gains["Enochian"]);
+        assert!(authentic.reputation_changes["overall"] > careless.reputation_changes["overall"]);
+    }
+    
+    #[test]
+    fn test_reward_scale_is_bounded() {
+        let mut high = test_choice("high", vec![]);
+        high.authenticity_impact = 0.3;
+        let low = test_choice("low", vec![]);
+        let quest = test_quest("quest_1", vec![high, low]);
+        
+        assert_eq!(quest.choice_reward_scale(&["high".to_string()]), 1.0);
+        assert_eq!(quest.choice_reward_scale(&["low".to_string()]), MIN_REWARD_SCALE);
+        assert_eq!(quest.choice_reward_scale(&[]), 0.75);
+        assert_eq!(test_quest("quest_2", vec![]).choice_reward_scale(&[]), 1.0);
+    }
+    
+    #[test]
+    fn test_sub_threshold_branch_rejected() {
+        let mut core = test_core();
+        let mut bad = test_choice("bad", vec![]);
+        bad.authenticity_impact = -0.05;
+        let quest = test_quest("quest_1", vec![test_choice("good", vec![]), bad]);
+        
+        let err = core.register_quest(quest).unwrap_err();
+        assert!(matches!(err, EnochianError::AuthenticityError { .. }));
+        assert!(err.to_string().contains("Choice bad"));
+        assert!(core.get_quest("quest_1").is_none());
+    }
+    
+    #[test]
+    fn test_all_branches_above_threshold_accepted() {
+        let mut core = test_core();
+        let mut cautious = test_choice("cautious", vec![]);
+        cautious.authenticity_impact = -0.01;
+        let quest = test_quest("quest_1", vec![test_choice("good", vec![]), cautious]);
+        assert!(core.register_quest(quest).is_ok());
+    }
+    
+    #[test]
+    fn test_restore_returns_to_snapshot() {
+        let mut core = test_core();
+        core.config.max_state_history = 16;
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        
+        let snapshot = core.snapshot_player("player").unwrap();
+        let before = core.get_player_state("player").unwrap().clone();
+        
+        core.start_quest("player", "quest_1").unwrap();
+        core.complete_quest("player", "quest_1").unwrap();
+        let mutated_version = core.get_player_state("player").unwrap().version;
+        let history_len = core.state_history.len();
+        
+        let restored = core.restore_player("player", snapshot).unwrap();
+        assert!(restored.completed_quests.is_empty());
+        assert_eq!(restored.energy_level, before.energy_level);
+        assert_eq!(restored.tradition_mastery, before.tradition_mastery);
+        assert_eq!(restored.version, mutated_version + 1);
+        
+        // Audited, but kept out of the P2P state history
+        assert_eq!(core.state_history.len(), history_len);
+        assert_eq!(core.restore_events, [RestoreEvent {
+            player_id: "player".to_string(),
+            snapshot_id: snapshot,
+            replaced_version: mutated_version,
+            restored_version: mutated_version + 1,
+        }]);
+        
+        // The quest can be replayed after rolling back
+        core.start_quest("player", "quest_1").unwrap();
+    }
+    
+    #[test]
+    fn test_snapshots_are_bounded_per_player() {
+        let config = SystemConfig { max_player_snapshots: 2, ..SystemConfig::default() };
+        let mut core = EnochianCore::new(config);
+        core.create_player_state("player".to_string()).unwrap();
+        
+        let oldest = core.snapshot_player("player").unwrap();
+        let middle = core.snapshot_player("player").unwrap();
+        let newest = core.snapshot_player("player").unwrap();
+        assert_ne!(middle, newest);
+        
+        assert!(core.restore_player("player", oldest).is_err());
+        assert!(core.restore_player("player", newest).is_ok());
+        assert!(core.snapshot_player("missing").is_err());
+    }
+    
+    #[test]
+    fn test_repeatable_quest_cooldown() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("ritual", vec![]);
+        quest.repeatable = true;
+        quest.repeat_cooldown_blocks = 144;
+        quest.rewards.bitcoin_rewards = 1000;
+        
+        // Repeat rewards may only shrink, and NaN is refused rather than clamped
+        for scale in [f64::NAN, -0.5, 1.5] {
+            quest.repeat_reward_scale = scale;
+            assert!(core.register_quest(quest.clone()).unwrap_err().to_string().contains("Repeat reward scale"));
+        }
+        quest.repeat_reward_scale = 0.5;
+        core.register_quest(quest).unwrap();
+        
+        core.game_states.get_mut("player").unwrap().block_height = 100;
+        core.start_quest("player", "ritual").unwrap();
+        assert_eq!(core.complete_quest("player", "ritual").unwrap().bitcoin_rewards, 1000);
+        
+        // Blocked until the cooldown has elapsed
+        core.game_states.get_mut("player").unwrap().block_height = 243;
+        match core.start_quest("player", "ritual") {
+            Err(EnochianError::QuestOnCooldown { available_at, .. }) => assert_eq!(available_at, 244),
+            other => panic!("expected cooldown error, got {:?}", other),
+        }
+        
+        core.game_states.get_mut("player").unwrap().block_height = 244;
+        core.start_quest("player", "ritual").unwrap();
+        assert_eq!(core.complete_quest("player", "ritual").unwrap().bitcoin_rewards, 500);
+        
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(state.completed_quests, vec!["ritual".to_string()]);
+        assert_eq!(state.quest_repeat_counts.get("ritual"), Some(&1));
+        assert_eq!(state.quest_completed_at.get("ritual"), Some(&244));
+        assert_eq!(state.balance_sats, 1500);
+    }
+    
+    #[test]
+    fn test_council_quest_requires_every_member() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("trial", vec![]);
+        quest.council = vec!["ABRIOND".to_string(), "GEDOONS".to_string(), "ZAMFRES".to_string()];
+        quest.rewards.governor_relationship_changes.insert("ABRIOND".to_string(), 0.3);
+        core.register_quest(quest).unwrap();
+        
+        let state = core.game_states.get_mut("player").unwrap();
+        state.governor_relationships.insert("ABRIOND".to_string(), 0.8);
+        state.governor_relationships.insert("GEDOONS".to_string(), 0.6);
+        state.governor_relationships.insert("ZAMFRES".to_string(), 0.2);
+        core.start_quest("player", "trial").unwrap();
+        
+        match core.complete_quest("player", "trial") {
+            Err(EnochianError::CouncilApprovalWithheld { withheld, .. }) => {
+                assert_eq!(withheld, vec!["ZAMFRES (relationship 0.20 below 0.50)".to_string()]);
+            },
+            other => panic!("expected withheld council approval, got {:?}", other),
         }
+        assert!(core.get_player_state("player").unwrap().active_quests.contains(&"trial".to_string()));
+        
+        core.game_states.get_mut("player").unwrap().governor_relationships.insert("ZAMFRES".to_string(), 0.5);
+        let rewards = core.complete_quest("player", "trial").unwrap();
+        assert_eq!(rewards.governor_relationship_changes.len(), 3);
+        
+        let state = core.get_player_state("player").unwrap();
+        assert!((state.governor_relationships["ABRIOND"] - 0.9).abs() < 1e-9);
+        assert!((state.governor_relationships["ZAMFRES"] - 0.6).abs() < 1e-9);
+        assert_eq!(state.council_blessed_at.get("GEDOONS"), Some(&0));
     }
     
-    /// Initialize the core system
-    pub fn initialize(&mut self) -> Result<()> {
-        // Validate configuration
-        self.validate_config()?;
+    #[test]
+    fn test_council_members_cool_down_between_blessings() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        for quest_id in ["first", "second"] {
+            let mut quest = test_quest(quest_id, vec![]);
+            quest.council = vec!["ABRIOND".to_string(), "GEDOONS".to_string()];
+            core.register_quest(quest).unwrap();
+        }
+        let state = core.game_states.get_mut("player").unwrap();
+        state.governor_relationships.insert("ABRIOND".to_string(), 1.0);
+        state.governor_relationships.insert("GEDOONS".to_string(), 1.0);
         
-        // Initialize subsystems
-        self.initialize_subsystems()?;
+        core.start_quest("player", "first").unwrap();
+        core.start_quest("player", "second").unwrap();
+        core.complete_quest("player", "first").unwrap();
         
-        self.initialized = true;
-        log::info!("Enochian Core initialized successfully");
-        Ok(())
+        match core.complete_quest("player", "second") {
+            Err(EnochianError::CouncilApprovalWithheld { withheld, .. }) => assert_eq!(withheld.len(), 2),
+            other => panic!("expected council cooldown, got {:?}", other),
+        }
+        
+        core.game_states.get_mut("player").unwrap().block_height = 144;
+        core.complete_quest("player", "second").unwrap();
     }
     
-    /// Create a new player game state
-    pub fn create_player_state(&mut self, player_id: String) -> Result<&GameState> {
-        if self.game_states.contains_key(&player_id) {
-            return Err(EnochianError::Generic {
-                message: format!("Player {} already exists", player_id),
-            });
-        }
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_operations_increment_metrics() {
+        use crate::metrics::{global, metrics_snapshot};
         
-        let game_state = GameState {
-            player_id: player_id.clone(),
-            block_height: 0,
-            completed_quests: Vec::new(),
-            active_quests: Vec::new(),
-            tradition_mastery: {
-                let mut mastery = HashMap::new();
-                mastery.insert("Enochian".to_string(), 0.1);
-                mastery
-            },
-            governor_relationships: HashMap::new(),
-            reputation_scores: HashMap::new(),
-            owned_hypertokens: Vec::new(),
-            sacred_items: Vec::new(),
-            energy_level: 25,
-            aethyr_access: vec![1], // Start with access to first Aethyr
-            balance_sats: 0,
-            staked_amount: 0,
-            pending_rewards: 0,
-            authenticity_score: 0.85,
-            last_update: chrono::Utc::now().to_rfc3339(),
-            version: 1,
-        };
+        // Other tests share the process-wide registry, so compare deltas
+        let counts = || Counter::ALL.map(|counter| global().get(counter));
+        let before = counts();
         
-        self.game_states.insert(player_id.clone(), game_state);
-        Ok(self.game_states.get(&player_id).unwrap())
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("metered", vec![])).unwrap();
+        assert!(core.register_quest(test_quest("metered", vec![])).is_err());
+        core.start_quest("player", "metered").unwrap();
+        core.complete_quest("player", "metered").unwrap();
+        AuthenticityScorer::new().calculate_authenticity("Enochian Aethyr", "Enochian", ContentType::Codex, &[]).unwrap();
+        
+        let after = counts();
+        assert!(before.iter().zip(&after).all(|(before, after)| after > before));
+        assert!(metrics_snapshot()["errors"]["DuplicateQuestId"].as_u64().unwrap() >= 1);
     }
     
-    /// Get player game state
-    pub fn get_player_state(&self, player_id: &str) -> Option<&GameState> {
-        self.game_states.get(player_id)
+    fn complete_at(core: &mut EnochianCore, quest: QuestData, block_height: u64) -> QuestRewards {
+        let quest_id = quest.quest_id.clone();
+        core.register_quest(quest).unwrap();
+        core.game_states.get_mut("player").unwrap().block_height = block_height;
+        core.start_quest("player", &quest_id).unwrap();
+        core.complete_quest("player", &quest_id).unwrap()
     }
     
-    /// Update player game state
-    pub fn update_player_state(&mut self, player_id: &str, state: GameState) -> Result<()> {
-        if !self.game_states.contains_key(player_id) {
-            return Err(EnochianError::Generic {
-                message: format!("Player {} not found", player_id),
-            });
-        }
+    #[test]
+    fn test_mastery_gains_capped_per_window_and_deferred() {
+        let mut core = test_core();
+        core.config.mastery_gain_cap = Some(0.1);
+        core.config.mastery_cap_window_blocks = 10;
+        core.create_player_state("player".to_string()).unwrap();
+        let mastery = |core: &EnochianCore| core.get_player_state("player").unwrap().tradition_mastery["Enochian"].value();
+        let start = mastery(&core);
         
-        // Validate state update
-        self.validate_state_update(&state)?;
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.3);
+        complete_at(&mut core, quest, 0);
+        assert!((mastery(&core) - (start + 0.1)).abs() < 1e-9);
         
-        self.game_states.insert(player_id.to_string(), state);
-        Ok(())
+        // Still inside the window: the new gain is deferred too
+        let mut quest = test_quest("quest_2", vec![]);
+        quest.title = "Second".to_string();
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.05);
+        complete_at(&mut core, quest, 5);
+        assert!((mastery(&core) - (start + 0.1)).abs() < 1e-9);
+        assert!((core.get_player_state("player").unwrap().deferred_mastery["Enochian"] - 0.25).abs() < 1e-9);
+        
+        // A new window releases up to the cap again
+        core.game_states.get_mut("player").unwrap().block_height = 10;
+        core.release_deferred_rewards("player").unwrap();
+        assert!((mastery(&core) - (start + 0.2)).abs() < 1e-9);
+        assert!((core.get_player_state("player").unwrap().deferred_mastery["Enochian"] - 0.15).abs() < 1e-9);
     }
     
-    /// Register a quest
-    pub fn register_quest(&mut self, quest: QuestData) -> Result<()> {
-        // Validate quest
-        self.validate_quest(&quest)?;
+    #[test]
+    fn test_relationship_gains_capped_per_window() {
+        let mut core = test_core();
+        core.config.relationship_gain_cap = Some(0.1);
+        core.config.relationship_cap_window_blocks = 10;
+        core.create_player_state("player".to_string()).unwrap();
+        let relationship = |core: &EnochianCore| core.get_player_state("player").unwrap().governor_relationships["ABRIOND"];
         
-        self.quest_registry.insert(quest.quest_id.clone(), quest);
-        Ok(())
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.rewards.governor_relationship_changes.insert("ABRIOND".to_string(), 0.25);
+        complete_at(&mut core, quest, 0);
+        assert!((relationship(&core) - 0.1).abs() < 1e-9);
+        
+        // Releasing again inside the window grants nothing more
+        core.release_deferred_rewards("player").unwrap();
+        core.release_deferred_rewards("player").unwrap();
+        assert!((relationship(&core) - 0.1).abs() < 1e-9);
+        
+        for (block_height, expected) in [(10, 0.2), (20, 0.25)] {
+            core.game_states.get_mut("player").unwrap().block_height = block_height;
+            core.release_deferred_rewards("player").unwrap();
+            assert!((relationship(&core) - expected).abs() < 1e-9);
+        }
+        assert!(core.get_player_state("player").unwrap().deferred_relationships.is_empty());
+        
+        // Losses are never capped
+        let mut quest = test_quest("quest_2", vec![]);
+        quest.title = "Second".to_string();
+        quest.rewards.governor_relationship_changes.insert("ABRIOND".to_string(), -0.5);
+        complete_at(&mut core, quest, 21);
+        assert!((relationship(&core) + 0.25).abs() < 1e-9);
     }
     
-    /// Get quest data
-    pub fn get_quest(&self, quest_id: &str) -> Option<&QuestData> {
-        self.quest_registry.get(quest_id)
+    #[test]
+    fn test_failed_quest_applies_penalties_without_rewards() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("trial", vec![]);
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.1);
+        quest.failure_consequences = vec![
+            StateConsequence {
+                consequence_type: ConsequenceType::GovernorRelationship,
+                target: "ABRIOND".to_string(),
+                value_change: -0.3,
+            },
+            StateConsequence {
+                consequence_type: ConsequenceType::EnergyModification,
+                target: String::new(),
+                value_change: -4.0,
+            },
+        ];
+        core.register_quest(quest).unwrap();
+        core.start_quest("player", "trial").unwrap();
+        let before = core.get_player_state("player").unwrap().clone();
+        
+        core.fail_quest("player", "trial", "Lost the governor's challenge").unwrap();
+        
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(state.failed_quests, vec!["trial".to_string()]);
+        assert!(state.active_quests.is_empty() && state.completed_quests.is_empty());
+        assert_eq!(state.governor_relationships["ABRIOND"], -0.3);
+        assert_eq!(state.energy_level.value(), before.energy_level.value() - 4);
+        assert_eq!(state.tradition_mastery, before.tradition_mastery);
+        
+        // Failure is logged apart from abandonment, and no rewards can follow
+        let event = core.quest_events.last().unwrap();
+        assert_eq!(event.outcome, QuestOutcome::Failed);
+        assert_eq!(event.reason.as_deref(), Some("Lost the governor's challenge"));
+        assert!(core.complete_quest("player", "trial").is_err());
+        assert!(matches!(core.start_quest("player", "trial"), Err(EnochianError::QuestFailed { .. })));
+        assert!(core.recommend_quests("player", 10).is_empty());
     }
     
-    /// Start a quest for a player
-    pub fn start_quest(&mut self, player_id: &str, quest_id: &str) -> Result<()> {
-        let player_state = self.game_states.get_mut(player_id)
-            .ok_or_else(|| EnochianError::Generic {
-                message: format!("Player {} not found", player_id),
-            })?;
-        
-        let quest = self.quest_registry.get(quest_id)
-            .ok_or_else(|| EnochianError::Generic {
-                message: format!("Quest {} not found", quest_id),
-            })?;
+    #[test]
+    fn test_failed_repeatable_quest_can_be_retried() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut quest = test_quest("practice", vec![]);
+        quest.repeatable = true;
+        core.register_quest(quest).unwrap();
         
-        // Check if player can start quest
-        self.validate_quest_start(player_state, quest)?;
+        core.start_quest("player", "practice").unwrap();
+        core.fail_quest("player", "practice", "Broke the circle").unwrap();
+        core.start_quest("player", "practice").unwrap();
+        core.complete_quest("player", "practice").unwrap();
         
-        // Add quest to active quests
-        player_state.active_quests.push(quest_id.to_string());
-        player_state.energy_level = player_state.energy_level.saturating_sub(quest.required_energy);
-        player_state.last_update = chrono::Utc::now().to_rfc3339();
-        player_state.version += 1;
+        let state = core.get_player_state("player").unwrap();
+        assert!(state.failed_quests.is_empty());
+        assert_eq!(state.completed_quests, vec!["practice".to_string()]);
+        assert!(core.fail_quest("player", "practice", "Not active").is_err());
+    }
+    
+    #[test]
+    fn test_non_repeatable_quest_stays_completed() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        core.register_quest(test_quest("once", vec![])).unwrap();
+        core.start_quest("player", "once").unwrap();
+        core.complete_quest("player", "once").unwrap();
         
-        log::info!("Player {} started quest {}", player_id, quest_id);
-        Ok(())
+        core.game_states.get_mut("player").unwrap().block_height = 1_000_000;
+        assert!(matches!(
+            core.start_quest("player", "once"),
+            Err(EnochianError::QuestAlreadyCompleted { .. })
+        ));
     }
     
-    /// Complete a quest for a player
-    pub fn complete_quest(&mut self, player_id: &str, quest_id: &str) -> Result<QuestRewards> {
-        let player_state = self.game_states.get_mut(player_id)
-            .ok_or_else(|| EnochianError::Generic {
-                message: format!("Player {} not found", player_id),
-            })?;
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_quest_lifecycle_spans() {
+        use std::sync::{Arc, Mutex};
+        use tracing::span;
         
-        let quest = self.quest_registry.get(quest_id)
-            .ok_or_else(|| EnochianError::Generic {
-                message: format!("Quest {} not found", quest_id),
-            })?;
+        type RecordedSpan = (String, BTreeMap<String, String>);
         
-        // Check if quest is active
-        if !player_state.active_quests.contains(&quest_id.to_string()) {
-            return Err(EnochianError::Generic {
-                message: format!("Quest {} is not active for player {}", quest_id, player_id),
-            });
+        struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+        
+        impl tracing::field::Visit for FieldVisitor<'_> {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                self.0.insert(field.name().to_string(), format!("{:?}", value));
+            }
         }
         
-        // Remove from active quests and add to completed
-        player_state.active_quests.retain(|q| q != quest_id);
-        player_state.completed_quests.push(quest_id.to_string());
+        struct SpanRecorder(Arc<Mutex<Vec<RecordedSpan>>>);
         
-        // Apply rewards
-        self.apply_quest_rewards(player_state, &quest.rewards)?;
+        impl tracing::Subscriber for SpanRecorder {
+            fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+            
+            fn new_span(&self, attributes: &span::Attributes<'_>) -> span::Id {
+                let mut fields = BTreeMap::new();
+                attributes.record(&mut FieldVisitor(&mut fields));
+                let mut spans = self.0.lock().unwrap();
+                spans.push((attributes.metadata().name().to_string(), fields));
+                span::Id::from_u64(spans.len() as u64)
+            }
+            
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn event(&self, _: &tracing::Event<'_>) {}
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
         
-        player_state.last_update = chrono::Utc::now().to_rfc3339();
-        player_state.version += 1;
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        tracing::subscriber::with_default(SpanRecorder(spans.clone()), || {
+            let mut core = test_core();
+            core.create_player_state("player".to_string()).unwrap();
+            core.register_quest(test_quest("quest_1", vec![])).unwrap();
+            core.start_quest("player", "quest_1").unwrap();
+            core.complete_quest("player", "quest_1").unwrap();
+            
+            let mut quest = test_quest("quest_2", vec![]);
+            quest.title = "Second".to_string();
+            quest.choice_branches = vec![test_choice("choice_1", vec![])];
+            core.register_quest(quest).unwrap();
+            core.start_quest("player", "quest_2").unwrap();
+            core.make_quest_choice("player", "quest_2", "choice_1").unwrap();
+            core.apply_penalty("player", &[]).unwrap();
+            core.revalidate_registry(&AuthenticityScorer::new());
+        });
         
-        log::info!("Player {} completed quest {}", player_id, quest_id);
-        Ok(quest.rewards.clone())
+        let spans = spans.lock().unwrap();
+        // Every mutating operation opens a span
+        for operation in ["create_player_state", "register_quest", "make_quest_choice", "apply_penalty", "revalidate_registry"] {
+            assert!(spans.iter().any(|(name, _)| name == operation), "no span for {}", operation);
+        }
+        let lifecycle: Vec<&RecordedSpan> = spans.iter()
+            .filter(|(name, _)| name == "start_quest" || name == "complete_quest")
+            .collect();
+        assert_eq!(lifecycle.len(), 3);
+        assert_eq!(lifecycle[0].0, "start_quest");
+        assert_eq!(lifecycle[1].0, "complete_quest");
+        for (_, fields) in &lifecycle[..2] {
+            assert_eq!(fields.get("player_id").map(String::as_str), Some("player"));
+            assert_eq!(fields.get("quest_id").map(String::as_str), Some("quest_1"));
+        }
     }
     
-    /// Get system statistics
-    pub fn get_statistics(&self) -> serde_json::Value {
-        serde_json::json!({
-            "total_players": self.game_states.len(),
-            "total_quests": self.quest_registry.len(),
-            "active_quests": self.game_states.values()
-                .map(|state| state.active_quests.len())
-                .sum::<usize>(),
-            "completed_quests": self.game_states.values()
-                .map(|state| state.completed_quests.len())
-                .sum::<usize>(),
-            "average_authenticity": self.game_states.values()
-                .map(|state| state.authenticity_score)
-                .sum::<f64>() / self.game_states.len() as f64,
-            "total_hypertokens": self.game_states.values()
-                .map(|state| state.owned_hypertokens.len())
-                .sum::<usize>(),
-        })
+    #[test]
+    fn test_config_validation() {
+        assert!(SystemConfig::default().validate().is_ok());
+        assert!(SystemConfig::default().checks().iter().all(|check| check.passed));
+        
+        let failed = |config: &SystemConfig| -> Vec<String> {
+            config.checks().into_iter()
+                .filter(|check| !check.passed)
+                .map(|check| check.name)
+                .collect()
+        };
+        
+        let mut config = SystemConfig { authenticity_threshold: 0.5, ..SystemConfig::default() };
+        assert!(matches!(config.validate(), Err(EnochianError::SacredConstraintViolation { .. })));
+        assert_eq!(failed(&config), vec!["authenticity_threshold"]);
+        config.authenticity_threshold = f64::NAN;
+        assert!(config.validate().is_err());
+        
+        let config = SystemConfig { max_concurrent_quests: 0, ..SystemConfig::default() };
+        assert!(config.validate().is_err());
+        assert_eq!(failed(&config), vec!["max_concurrent_quests"]);
+        
+        let mut config = SystemConfig::default();
+        config.tradition_weighting.insert("Enochian".to_string(), 0.4);
+        assert!(config.validate().is_err());
+        assert_eq!(failed(&config), vec!["enochian_weighting"]);
+        
+        // Initialization runs the same validation
+        let mut core = EnochianCore::new(config);
+        assert!(core.initialize().is_err());
     }
     
-    fn validate_config(&self) -> Result<()> {
-        if self.config.authenticity_threshold < 0.8 || self.config.authenticity_threshold > 1.0 {
-            return Err(EnochianError::SacredConstraintViolation {
-                constraint: "Authenticity threshold must be between 0.8 and 1.0".to_string(),
-            });
-        }
+    #[test]
+    fn test_consequence_preview() {
+        let consequence = StateConsequence {
+            consequence_type: ConsequenceType::TraditionMastery,
+            target: "Enochian".to_string(),
+            value_change: 0.05,
+        };
+        assert_eq!(consequence.preview(), "+0.05 Enochian mastery");
+    }
+    
+    fn seeded_core(seed: u64) -> EnochianCore {
+        use crate::runtime::{FixedClock, SeededRandom};
         
-        if self.config.max_concurrent_quests == 0 || self.config.max_concurrent_quests > 10 {
-            return Err(EnochianError::SacredConstraintViolation {
-                constraint: "Max concurrent quests must be between 1 and 10".to_string(),
-            });
+        let mut core = EnochianCore::with_sources(
+            SystemConfig::default(),
+            Arc::new(FixedClock::at_unix(1_700_000_000)),
+            Arc::new(SeededRandom::new(seed)),
+        );
+        core.initialize().unwrap();
+        core
+    }
+    
+    // Create players, then register, start and complete randomly seeded quests
+    fn play(core: &mut EnochianCore) {
+        for player_id in ["alice", "bob", "carol"] {
+            core.create_player_state(player_id.to_string()).unwrap();
         }
-        
-        // Validate Enochian weighting
-        let enochian_weight = self.config.tradition_weighting.get("Enochian").unwrap_or(&0.0);
-        if *enochian_weight < 0.5 {
-            return Err(EnochianError::SacredConstraintViolation {
-                constraint: "Enochian tradition must have at least 50% weighting".to_string(),
-            });
+        for player_id in ["alice", "bob"] {
+            let quest_id = QuestId::new(1, core.next_quest_seed()).to_string();
+            let mut quest = test_quest(&quest_id, vec![]);
+            quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.05);
+            quest.rewards.governor_relationship_changes.insert("ABRIOND".to_string(), 0.1);
+            core.register_quest(quest).unwrap();
+            core.start_quest(player_id, &quest_id).unwrap();
+            core.complete_quest(player_id, &quest_id).unwrap();
         }
+    }
+    
+    #[test]
+    fn test_identical_sources_produce_identical_cores() {
+        let (mut first, mut second) = (seeded_core(7), seeded_core(7));
+        play(&mut first);
+        play(&mut second);
         
-        Ok(())
+        assert_eq!(serde_json::to_string(&first.game_states).unwrap(), serde_json::to_string(&second.game_states).unwrap());
+        let quest_ids = |core: &EnochianCore| core.quests().map(|quest| quest.quest_id.clone()).collect::<Vec<_>>();
+        assert_eq!(quest_ids(&first), quest_ids(&second));
+        assert_eq!(first.registry_merkle_root(), second.registry_merkle_root());
+        assert_eq!(first.game_states["alice"].last_update, "2023-11-14T22:13:20+00:00");
+        assert_eq!(first.health().uptime_seconds, 0);
+        
+        // A different seed offers different quests
+        let mut other = seeded_core(8);
+        play(&mut other);
+        assert_ne!(quest_ids(&first), quest_ids(&other));
     }
     
-    fn initialize_subsystems(&mut self) -> Result<()> {
-        // Initialize tradition system
-        log::info!("Initializing tradition system...");
+    #[test]
+    fn test_same_inputs_serialize_identically() {
+        // Each run builds its maps afresh, so hash-ordered maps would differ
+        let run = || {
+            let mut core = seeded_core(7);
+            play(&mut core);
+            let score = AuthenticityScorer::new()
+                .calculate_authenticity("In 1582 John Dee scried the Aethyr", "Enochian", ContentType::Codex, &[])
+                .unwrap();
+            [
+                serde_json::to_string(&core.game_states).unwrap(),
+                serde_json::to_string(&core.config).unwrap(),
+                serde_json::to_string(&score).unwrap(),
+            ]
+        };
+        assert_eq!(run(), run());
+    }
+    
+    fn tagged_quest(quest_id: &str, tags: &[&str]) -> QuestData {
+        let mut quest = test_quest(quest_id, vec![]);
+        quest.tags = tags.iter().map(|tag| tag.to_string()).collect();
+        quest
+    }
+    
+    #[test]
+    fn test_quests_filtered_and_counted_by_tag() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let mut practice = tagged_quest("quest_1", &["divination", "scrying", "divination"]);
+        practice.repeatable = true;
+        core.register_quest(practice).unwrap();
+        core.register_quest(tagged_quest("quest_2", &["divination"])).unwrap();
+        core.register_quest(tagged_quest("quest_3", &["ritual"])).unwrap();
         
-        // Initialize governor system
-        log::info!("Initializing governor system...");
+        let divination: Vec<&str> = core.quests_by_tag("divination").map(|quest| quest.quest_id.as_str()).collect();
+        assert_eq!(divination, ["quest_1", "quest_2"]);
+        assert_eq!(core.quests_by_tag("alchemy").count(), 0);
         
-        // Initialize authenticity system
-        log::info!("Initializing authenticity system...");
+        // Repeat completions and duplicate tags count once
+        for _ in 0..2 {
+            core.start_quest("player", "quest_1").unwrap();
+            core.complete_quest("player", "quest_1").unwrap();
+        }
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(state.tag_completions["divination"], 1);
+        assert_eq!(state.tag_completions["scrying"], 1);
+        assert_eq!(state.public_view().tag_completions, state.tag_completions);
         
-        Ok(())
+        let progress = core.tag_progress("player").unwrap();
+        assert_eq!(progress.len(), QUEST_TAGS.len());
+        assert_eq!(progress["divination"], TagProgress { completed: 1, total: 2 });
+        assert_eq!(progress["ritual"], TagProgress { completed: 0, total: 1 });
+        assert!(core.tag_progress("nobody").is_err());
     }
     
-    fn validate_state_update(&self, state: &GameState) -> Result<()> {
-        // Validate energy level
-        if state.energy_level > 25 {
-            return Err(EnochianError::Generic {
-                message: "Energy level cannot exceed 25".to_string(),
-            });
-        }
+    #[test]
+    fn test_unknown_tags_rejected_in_strict_mode() {
+        let mut core = test_core();
+        let quest = tagged_quest("quest_1", &["ritual", "necromancy"]);
+        let lint = core.lint_quest(&quest);
+        assert_eq!(lint.issues.len(), 1);
+        assert_eq!(lint.issues[0].location, "tags[1]");
+        assert_eq!(lint.issues[0].severity, LintSeverity::Warning);
+        core.register_quest(quest.clone()).unwrap();
         
-        // Validate authenticity score
-        if state.authenticity_score < 0.0 || state.authenticity_score > 1.0 {
-            return Err(EnochianError::Generic {
-                message: "Authenticity score must be between 0.0 and 1.0".to_string(),
-            });
-        }
+        core.config.strict_tags = true;
+        let mut quest = quest;
+        quest.quest_id = "quest_2".to_string();
+        assert_eq!(core.lint_quest(&quest).issues[0].severity, LintSeverity::Error);
+        let error = core.register_quest(quest).unwrap_err();
+        assert!(matches!(&error, EnochianError::UnknownQuestTag { tag, .. } if tag == "necromancy"));
+        assert_eq!(error.kind(), "UnknownQuestTag");
+        core.register_quest(tagged_quest("quest_3", &["ritual"])).unwrap();
+    }
+    
+    fn journaling_core() -> EnochianCore {
+        let config = SystemConfig { record_journal: true, ..SystemConfig::default() };
+        let mut core = EnochianCore::new(config);
+        core.initialize().unwrap();
+        core
+    }
+    
+    #[test]
+    fn test_replayed_journal_reproduces_state() {
+        let mut core = journaling_core();
+        core.create_player_state("alice".to_string()).unwrap();
+        core.create_players_strict(vec!["bob".to_string(), "bad id".to_string()]).unwrap();
+        core.create_player_state("carol".to_string()).unwrap();
         
-        // Validate tradition mastery
-        for (_, mastery) in &state.tradition_mastery {
-            if *mastery < 0.0 || *mastery > 1.0 {
-                return Err(EnochianError::Generic {
-                    message: "Tradition mastery must be between 0.0 and 1.0".to_string(),
-                });
-            }
-        }
+        let mut quest = tagged_quest("quest_1", &["ritual"]);
+        quest.choice_branches = vec![test_choice("choice_1", vec![])];
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.05);
+        core.register_quest(quest).unwrap();
+        let mut second = test_quest("quest_2", vec![]);
+        second.title = "Second".to_string();
+        core.register_quest(second).unwrap();
+        assert!(core.start_quest("alice", "quest_9").is_err());
         
-        Ok(())
+        core.start_quest("alice", "quest_1").unwrap();
+        core.make_quest_choice("alice", "quest_1", "choice_1").unwrap();
+        core.complete_objective("alice", "quest_1", 0, ActionType::Study).unwrap();
+        core.complete_quest("alice", "quest_1").unwrap();
+        core.start_quest("carol", "quest_2").unwrap();
+        core.fail_quest("carol", "quest_2", "broke the circle").unwrap();
+        let mut finalized = core.get_player_state("alice").unwrap().clone();
+        finalized.block_height = 820_000;
+        core.update_player_state("alice", finalized).unwrap();
+        
+        // Failed and rolled back operations leave no entries
+        let journal = core.journal().unwrap();
+        assert_eq!(journal.len(), 11);
+        assert!(journal.entries().iter().all(|entry| !matches!(&entry.op, JournalOp::CreatePlayer { player_id, .. } if player_id == "bob")));
+        
+        let inscribed = journal.to_inscription().unwrap();
+        let replayed = crate::journal::replay(&InscriptionJournal::from_inscription(&inscribed).unwrap()).unwrap();
+        assert_eq!(replayed.state_hash(), core.state_hash());
+        assert_eq!(serde_json::to_string(&replayed.game_states).unwrap(), serde_json::to_string(&core.game_states).unwrap());
+        assert_eq!(replayed.journal().unwrap().to_inscription().unwrap(), inscribed);
     }
     
-    fn validate_quest(&self, quest: &QuestData) -> Result<()> {
-        // Validate authenticity score
-        if quest.authenticity_score < self.config.authenticity_threshold {
-            return Err(EnochianError::AuthenticityError {
-                message: format!(
-                    "Quest authenticity {} below threshold {}",
-                    quest.authenticity_score,
-                    self.config.authenticity_threshold
-                ),
-            });
-        }
+    #[test]
+    fn test_replayed_journal_reproduces_restore() {
+        let mut core = journaling_core();
+        core.create_player_state("alice".to_string()).unwrap();
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.05);
+        core.register_quest(quest).unwrap();
+        let snapshot = core.snapshot_player("alice").unwrap();
+        core.start_quest("alice", "quest_1").unwrap();
+        core.complete_objective("alice", "quest_1", 0, ActionType::Study).unwrap();
+        core.complete_quest("alice", "quest_1").unwrap();
+        core.restore_player("alice", snapshot).unwrap();
         
-        // Validate difficulty level
-        if quest.difficulty_level == 0 || quest.difficulty_level > 10 {
-            return Err(EnochianError::Generic {
-                message: "Quest difficulty must be between 1 and 10".to_string(),
-            });
-        }
+        // The checkpoint travels with the entry, so replay needs no local snapshots
+        let journal = core.journal().unwrap();
+        assert!(matches!(&journal.entries().last().unwrap().op, JournalOp::RestorePlayer { snapshot_id, .. } if *snapshot_id == snapshot));
+        let replayed = crate::journal::replay(journal).unwrap();
+        assert!(replayed.player_snapshots.is_empty());
+        assert_eq!(replayed.state_hash(), core.state_hash());
+        assert_eq!(replayed.restore_events, core.restore_events);
+        assert_eq!(replayed.rank_events, core.rank_events);
+    }
+    
+    #[test]
+    fn test_replay_rejects_entries_off_the_recorded_state() {
+        let mut core = journaling_core();
+        core.create_player_state("alice".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.start_quest("alice", "quest_1").unwrap();
+        let journal = core.journal().unwrap();
         
-        // Validate required energy
-        if quest.required_energy > 25 {
-            return Err(EnochianError::Generic {
-                message: "Quest cannot require more than 25 energy".to_string(),
-            });
+        // Dropping an entry leaves the next one's prior hash unmatched
+        let mut entries = journal.entries().to_vec();
+        entries.remove(1);
+        assert!(InscriptionJournal::from_entries(journal.config.clone(), entries.clone()).is_err());
+        for (sequence, entry) in entries.iter_mut().enumerate() {
+            entry.sequence = sequence as u64;
         }
+        let gapped = InscriptionJournal::from_entries(journal.config.clone(), entries).unwrap();
+        let error = crate::journal::replay(&gapped).unwrap_err();
+        assert!(matches!(error, EnochianError::JournalReplayError { sequence: 1, .. }), "{}", error);
+    }
+    
+    #[test]
+    fn test_weight_overrides_rescore_quest() {
+        let mut core = test_core();
+        let historical = ScoringWeights {
+            tradition_alignment: 0.10,
+            historical_accuracy: 0.70,
+            spiritual_depth: 0.05,
+            practical_applicability: 0.10,
+            source_quality: 0.05,
+        };
+        let mut reenactment = test_quest("quest_2", vec![]);
+        reenactment.authenticity_weight_overrides = Some(historical.clone());
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
+        core.register_quest(reenactment.clone()).unwrap();
         
-        Ok(())
+        // Same content and stored score, different weighting
+        let drift: BTreeMap<String, f64> = core.rescore_all_authenticity(&AuthenticityScorer::new()).into_iter().collect();
+        assert_ne!(drift["quest_1"], drift["quest_2"]);
+        
+        let mut unbalanced = reenactment;
+        unbalanced.quest_id = "quest_3".to_string();
+        unbalanced.authenticity_weight_overrides = Some(ScoringWeights { historical_accuracy: 0.90, ..historical });
+        assert!(core.lint_quest(&unbalanced).issues.iter().any(|issue| issue.location == "authenticity_weight_overrides"));
+        assert!(matches!(core.register_quest(unbalanced), Err(EnochianError::AuthenticityError { .. })));
     }
     
-    fn validate_quest_start(&self, player_state: &GameState, quest: &QuestData) -> Result<()> {
-        // Check energy requirement
-        if player_state.energy_level < quest.required_energy {
-            return Err(EnochianError::Generic {
-                message: format!(
-                    "Insufficient energy: {} required, {} available",
-                    quest.required_energy,
-                    player_state.energy_level
-                ),
-            });
+    /// Sink recording what it was asked to persist
+    #[derive(Debug, Default)]
+    struct RecordingSink {
+        players: std::sync::Mutex<Vec<String>>,
+        journal_entries: std::sync::Mutex<Vec<u64>>,
+    }
+    
+    impl StateSink for RecordingSink {
+        fn persist_player(&self, state: &GameState) -> Result<()> {
+            self.players.lock().unwrap().push(state.player_id.clone());
+            Ok(())
         }
         
-        // Check concurrent quest limit
-        if player_state.active_quests.len() >= self.config.max_concurrent_quests as usize {
-            return Err(EnochianError::Generic {
-                message: format!(
-                    "Maximum concurrent quests reached: {}",
-                    self.config.max_concurrent_quests
-                ),
-            });
+        fn persist_journal(&self, entries: &[crate::journal::JournalEntry]) -> Result<()> {
+            self.journal_entries.lock().unwrap().extend(entries.iter().map(|entry| entry.sequence));
+            Ok(())
         }
+    }
+    
+    #[test]
+    fn test_flush_persists_pending_state() {
+        let sink = Arc::new(RecordingSink::default());
+        let mut core = journaling_core().with_sink(sink.clone());
+        core.create_player_state("alice".to_string()).unwrap();
+        core.create_player_state("bob".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
         
-        // Check if quest already completed
-        if player_state.completed_quests.contains(&quest.quest_id) {
-            return Err(EnochianError::Generic {
-                message: format!("Quest {} already completed", quest.quest_id),
-            });
-        }
+        let summary = core.flush().unwrap();
+        assert_eq!(summary, FlushSummary { players: vec!["alice".to_string(), "bob".to_string()], journal_entries: 3 });
+        assert_eq!(*sink.players.lock().unwrap(), ["alice", "bob"]);
+        assert_eq!(*sink.journal_entries.lock().unwrap(), [0, 1, 2]);
         
-        // Check if quest already active
-        if player_state.active_quests.contains(&quest.quest_id) {
-            return Err(EnochianError::Generic {
-                message: format!("Quest {} already active", quest.quest_id),
-            });
-        }
+        // Only what changed since is persisted again
+        core.start_quest("alice", "quest_1").unwrap();
+        let summary = core.flush().unwrap();
+        assert_eq!(summary, FlushSummary { players: vec!["alice".to_string()], journal_entries: 1 });
+        assert_eq!(*sink.journal_entries.lock().unwrap(), [0, 1, 2, 3]);
+        assert_eq!(core.flush().unwrap(), FlushSummary::default());
+        assert_eq!(sink.players.lock().unwrap().len(), 3);
+    }
+    
+    #[test]
+    fn test_shutdown_rejects_mutations() {
+        let sink = Arc::new(RecordingSink::default());
+        let mut core = test_core().with_sink(sink.clone());
+        core.create_player_state("alice".to_string()).unwrap();
+        core.register_quest(test_quest("quest_1", vec![])).unwrap();
         
-        Ok(())
+        let summary = core.shutdown().unwrap();
+        assert_eq!(summary.players, ["alice"]);
+        assert!(core.is_closed());
+        assert!(!core.health().ready);
+        
+        let closed = |result: Result<()>, operation: &str| {
+            let error = result.unwrap_err();
+            assert_eq!(error.kind(), "CoreClosed");
+            assert!(matches!(&error, EnochianError::CoreClosed { operation: op } if op == operation), "{}", error);
+        };
+        closed(core.start_quest("alice", "quest_1"), "start_quest");
+        closed(core.create_player_state("bob".to_string()).map(|_| ()), "create_player_state");
+        closed(core.create_players(vec!["bob".to_string()]).map(|_| ()), "create_players");
+        closed(core.register_quest(test_quest("quest_2", vec![])).map(|_| ()), "register_quest");
+        closed(core.snapshot_player("alice").map(|_| ()), "snapshot_player");
+        let state = core.get_player_state("alice").unwrap().clone();
+        closed(core.update_player_state("alice", state), "update_player_state");
+        
+        // Nothing changed, so there is nothing left to flush
+        assert_eq!(core.game_states.len(), 1);
+        assert_eq!(core.flush().unwrap(), FlushSummary::default());
+        assert_eq!(sink.players.lock().unwrap().len(), 1);
     }
     
-    fn apply_quest_rewards(&self, player_state: &mut GameState, rewards: &QuestRewards) -> Result<()> {
-        // Apply reputation changes
-        for (category, change) in &rewards.reputation_changes {
-            let current = player_state.reputation_scores.get(category).unwrap_or(&0.0);
-            player_state.reputation_scores.insert(category.clone(), current + change);
-        }
+    #[test]
+    fn test_mastery_rank_thresholds() {
+        let thresholds = MasteryRankThresholds::default();
+        let rank = |value: f64| thresholds.rank_of(Mastery::new(value).unwrap());
+        assert_eq!(rank(0.0), MasteryRank::Novice);
+        assert_eq!(rank(0.199), MasteryRank::Novice);
+        assert_eq!(rank(0.2), MasteryRank::Initiate);
+        assert_eq!(rank(0.4), MasteryRank::Practitioner);
+        assert_eq!(rank(0.599), MasteryRank::Practitioner);
+        assert_eq!(rank(0.6), MasteryRank::Adept);
+        assert_eq!(rank(0.85), MasteryRank::Master);
+        assert_eq!(rank(1.0), MasteryRank::Master);
         
-        // Apply tradition mastery gains
-        for (tradition, gain) in &rewards.tradition_mastery_gains {
-            let current = player_state.tradition_mastery.get(tradition).unwrap_or(&0.0);
-            let new_mastery = (current + gain).min(1.0);
-            player_state.tradition_mastery.insert(tradition.clone(), new_mastery);
-        }
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
+        let state = core.get_player_state("player").unwrap();
+        assert_eq!(state.mastery_rank("Enochian", &thresholds), MasteryRank::Novice);
+        assert_eq!(state.mastery_rank("Thelema", &thresholds), MasteryRank::Novice);
+        let lenient = MasteryRankThresholds { initiate: 0.1, ..thresholds };
+        assert_eq!(state.mastery_rank("Enochian", &lenient), MasteryRank::Initiate);
+        core.config.mastery_rank_thresholds = lenient;
+        assert_eq!(core.mastery_rank("player", "Enochian"), Some(MasteryRank::Initiate));
+        assert_eq!(core.mastery_rank("nobody", "Enochian"), None);
         
-        // Apply governor relationship changes
-        for (governor, change) in &rewards.governor_relationship_changes {
-            let current = player_state.governor_relationships.get(governor).unwrap_or(&0.0);
-            let new_relationship = (current + change).min(1.0).max(-1.0);
-            player_state.governor_relationships.insert(governor.clone(), new_relationship);
+        // Thresholds must rise strictly within (0.0, 1.0]
+        for invalid in [
+            MasteryRankThresholds { initiate: 0.0, ..thresholds },
+            MasteryRankThresholds { adept: 0.4, ..thresholds },
+            MasteryRankThresholds { master: 1.1, ..thresholds },
+        ] {
+            let config = SystemConfig { mastery_rank_thresholds: invalid, ..SystemConfig::default() };
+            assert!(config.validate().is_err());
         }
+    }
+    
+    #[test]
+    fn test_rank_up_recorded_on_completion() {
+        let mut core = test_core();
+        core.create_player_state("player".to_string()).unwrap();
         
-        // Apply Bitcoin rewards
-        player_state.balance_sats += rewards.bitcoin_rewards;
+        // 0.1 + 0.05 stays a Novice
+        let mut quest = test_quest("quest_1", vec![]);
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.05);
+        complete_at(&mut core, quest, 0);
+        assert!(core.rank_events.is_empty());
         
-        // Add sacred items
-        for item in &rewards.sacred_items {
-            if !player_state.sacred_items.contains(item) {
-                player_state.sacred_items.push(item.clone());
-            }
-        }
+        // 0.15 + 0.3 skips Initiate straight to Practitioner
+        let mut quest = test_quest("quest_2", vec![]);
+        quest.title = "Second".to_string();
+        quest.rewards.tradition_mastery_gains.insert("Enochian".to_string(), 0.3);
+        complete_at(&mut core, quest, 1);
+        assert_eq!(core.rank_events, [RankChange {
+            player_id: "player".to_string(),
+            tradition: "Enochian".to_string(),
+            from: MasteryRank::Novice,
+            to: MasteryRank::Practitioner,
+            quest_id: Some("quest_2".to_string()),
+        }]);
+        assert!(core.rank_events[0].is_promotion());
+        assert_eq!(core.mastery_rank("player", "Enochian"), Some(MasteryRank::Practitioner));
+    }
+    
+    #[test]
+    fn test_rank_changes_recorded_on_every_mastery_path() {
+        let mut core = test_core();
+        core.config.mastery_rank_thresholds = MasteryRankThresholds { initiate: 0.1, ..MasteryRankThresholds::default() };
+        core.create_player_state("player".to_string()).unwrap();
+        let drain = |value_change: f64| StateConsequence {
+            consequence_type: ConsequenceType::TraditionMastery,
+            target: "Enochian".to_string(),
+            value_change,
+        };
         
-        // Add hypertoken rewards
-        for token in &rewards.hypertoken_rewards {
-            if !player_state.owned_hypertokens.contains(token) {
-                player_state.owned_hypertokens.push(token.clone());
-            }
-        }
+        // A penalty demotes the starting Initiate under the configured thresholds
+        core.apply_penalty("player", &[drain(-0.05)]).unwrap();
+        let demotion = core.rank_events.last().unwrap().clone();
+        assert_eq!((demotion.from, demotion.to, demotion.quest_id), (MasteryRank::Initiate, MasteryRank::Novice, None));
+        assert!(!core.rank_events[0].is_promotion());
+        assert_eq!(core.mastery_rank("player", "Enochian"), Some(MasteryRank::Novice));
         
-        // Add Aethyr access
-        for aethyr in &rewards.aethyr_access_gained {
-            if !player_state.aethyr_access.contains(aethyr) {
-                player_state.aethyr_access.push(*aethyr);
-            }
-        }
+        // A choice promotes, tagged with its quest
+        core.register_quest(test_quest("quest_1", vec![test_choice("choice_1", vec![drain(0.4)])])).unwrap();
+        core.start_quest("player", "quest_1").unwrap();
+        core.make_quest_choice("player", "quest_1", "choice_1").unwrap();
+        let promotion = core.rank_events.last().unwrap();
+        assert_eq!((promotion.to, promotion.quest_id.as_deref()), (MasteryRank::Practitioner, Some("quest_1")));
         
-        Ok(())
+        // A finalized transition dropping a tradition demotes it to Novice
+        let mut state = core.get_player_state("player").unwrap().clone();
+        state.tradition_mastery.clear();
+        core.update_player_state("player", state).unwrap();
+        let dropped = core.rank_events.last().unwrap();
+        assert_eq!((dropped.from, dropped.to), (MasteryRank::Practitioner, MasteryRank::Novice));
+        assert!(core.get_player_state("player").unwrap().tradition_mastery.is_empty());
+        assert_eq!(core.rank_events.len(), 3);
     }
 }
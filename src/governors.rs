@@ -1,8 +1,66 @@
 //! Governor Angel management system for the 91 sacred governors
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, PoisonError, RwLock};
 use crate::{Result, EnochianError};
+use crate::traditions::TraditionManager;
+use crate::aethyr_tiers::{AethyrTier, AethyrTiers};
+use crate::core::STARTING_AETHYR;
+
+/// ID of the supreme governor, who holds affinity with every tradition
+pub const SUPREME_GOVERNOR_ID: u32 = 91;
+
+/// Affinity the supreme governor holds with each non-Enochian tradition
+const SUPREME_TRADITION_AFFINITY: f64 = 0.9;
+
+/// Governors a new player is introduced to
+pub const ONBOARDING_GOVERNOR_COUNT: usize = 3;
+
+/// Minimum compassion for a governor to be tutorial-appropriate
+const ONBOARDING_MIN_COMPASSION: f64 = 0.7;
+
+/// Maximum challenge preference for a governor to be tutorial-appropriate
+const ONBOARDING_MAX_CHALLENGE: f64 = 0.7;
+
+/// Aethyr seating the supreme governor and the named governors
+const SUPREME_AETHYR_ID: u32 = 30;
+
+/// Aethyr whose generated governors carry the Mysterious trait and a
+/// Hermetic_Qabalah affinity
+const HERMETIC_AETHYR_NAME: &str = "RII";
+
+/// Aethyr names by ID in Dee's order, from LIL (the 1st, innermost) out to
+/// TEX (the 30th)
+const AETHYR_NAMES: [&str; 30] = [
+    "LIL", "ARN", "ZOM", "PAZ", "LIT", "MAZ", "DEO", "ZID", "ZIP", "ZAX",
+    "ICH", "LOE", "ZIM", "VTA", "OXO", "LEA", "TAN", "ZEN", "POP", "KHR",
+    "ASP", "LIN", "TOR", "NIA", "VTI", "DES", "ZAA", "BAG", "RII", "TEX",
+];
+
+/// An Aethyr and the number of governors ruling it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AethyrLayout {
+    /// Aethyr ID (1-30)
+    pub aethyr_id: u32,
+    /// Aethyr name
+    pub name: String,
+    /// Governors ruling this Aethyr
+    pub governor_count: u32,
+}
+
+/// The canonical distribution of the 91 governors: TEX, the 30th Aethyr, is
+/// ruled by four, every other Aethyr by three
+pub fn canonical_aethyr_layout() -> Vec<AethyrLayout> {
+    AETHYR_NAMES.iter()
+        .zip(1u32..)
+        .map(|(name, aethyr_id)| AethyrLayout {
+            aethyr_id,
+            name: name.to_string(),
+            governor_count: if aethyr_id == SUPREME_AETHYR_ID { 4 } else { 3 },
+        })
+        .collect()
+}
 
 /// Governor Angel data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +114,40 @@ pub struct InteractionStyle {
     pub tradition_orthodoxy: f64,
 }
 
+impl InteractionStyle {
+    /// Check every trait lies within 0.0-1.0
+    pub fn validate(&self) -> Result<()> {
+        let traits = [
+            ("authority_level", self.authority_level),
+            ("wisdom_approach", self.wisdom_approach),
+            ("mystical_intensity", self.mystical_intensity),
+            ("compassion_level", self.compassion_level),
+            ("challenge_preference", self.challenge_preference),
+            ("tradition_orthodoxy", self.tradition_orthodoxy),
+        ];
+        match traits.iter().find(|(_, value)| !(0.0..=1.0).contains(value)) {
+            Some((name, value)) => Err(EnochianError::SacredConstraintViolation {
+                constraint: format!("Interaction style {} must be between 0.0 and 1.0, found {}", name, value),
+            }),
+            None => Ok(()),
+        }
+    }
+    
+    /// Clamp every trait into 0.0-1.0, treating NaN as a neutral 0.5
+    pub fn normalize(&mut self) {
+        for value in [
+            &mut self.authority_level,
+            &mut self.wisdom_approach,
+            &mut self.mystical_intensity,
+            &mut self.compassion_level,
+            &mut self.challenge_preference,
+            &mut self.tradition_orthodoxy,
+        ] {
+            *value = if value.is_nan() { 0.5 } else { value.clamp(0.0, 1.0) };
+        }
+    }
+}
+
 /// Governor manager
 #[derive(Debug, Clone)]
 pub struct GovernorManager {
@@ -67,6 +159,12 @@ pub struct GovernorManager {
     governors_by_aethyr: HashMap<u32, Vec<u32>>,
     /// Governors by domain
     governors_by_domain: HashMap<String, Vec<u32>>,
+    /// Player level required to access each Aethyr
+    aethyr_requirements: HashMap<u32, u32>,
+    /// Governors seated in each Aethyr, in Aethyr ID order
+    aethyr_layout: Vec<AethyrLayout>,
+    /// Tier each Aethyr belongs to
+    aethyr_tiers: AethyrTiers,
 }
 
 impl Default for GovernorManager {
@@ -76,18 +174,57 @@ impl Default for GovernorManager {
 }
 
 impl GovernorManager {
-    /// Create a new governor manager
+    /// Create a new governor manager with the canonical Aethyr layout
     pub fn new() -> Self {
-        let mut manager = GovernorManager {
+        Self::with_layout(canonical_aethyr_layout()).expect("canonical Aethyr layout is valid")
+    }
+    
+    /// Create a governor manager seating governors by a custom Aethyr layout
+    ///
+    /// The layout must list Aethyrs 1-30 in order, seat at least one governor
+    /// in each and 91 in total, and leave room in TEX for the four governors
+    /// always seated there.
+    pub fn with_layout(aethyr_layout: Vec<AethyrLayout>) -> Result<Self> {
+        validate_aethyr_layout(&aethyr_layout)?;
+        
+        let mut manager = GovernorManager::empty(aethyr_layout);
+        manager.initialize_governors();
+        manager.build_indices();
+        Ok(manager)
+    }
+    
+    fn empty(aethyr_layout: Vec<AethyrLayout>) -> Self {
+        GovernorManager {
             governors: HashMap::new(),
             governors_by_name: HashMap::new(),
             governors_by_aethyr: HashMap::new(),
             governors_by_domain: HashMap::new(),
-        };
-        
-        manager.initialize_governors();
-        manager.build_indices();
-        manager
+            aethyr_requirements: aethyr_layout.iter()
+                .map(|aethyr| (aethyr.aethyr_id, default_aethyr_requirement(aethyr.aethyr_id)))
+                .collect(),
+            aethyr_layout,
+            aethyr_tiers: AethyrTiers::default(),
+        }
+    }
+    
+    /// Governors seated in each Aethyr, in Aethyr ID order
+    pub fn aethyr_layout(&self) -> &[AethyrLayout] {
+        &self.aethyr_layout
+    }
+    
+    /// Tier an Aethyr belongs to
+    pub fn aethyr_tier(&self, aethyr_id: u32) -> Option<&AethyrTier> {
+        self.aethyr_tiers.tier_of(aethyr_id)
+    }
+    
+    /// Tier of the Aethyr a governor is seated in
+    pub fn governor_tier(&self, governor_id: u32) -> Option<&AethyrTier> {
+        self.governors.get(&governor_id).and_then(|governor| self.aethyr_tier(governor.aethyr_id))
+    }
+    
+    /// Replace the Aethyr tier definitions
+    pub fn set_aethyr_tiers(&mut self, aethyr_tiers: AethyrTiers) {
+        self.aethyr_tiers = aethyr_tiers;
     }
     
     /// Get governor by ID
@@ -115,9 +252,16 @@ impl GovernorManager {
             .unwrap_or_default()
     }
     
-    /// Get all governor names
+    /// Get all governor names, in governor ID order
     pub fn get_governor_names(&self) -> Vec<String> {
-        self.governors.values().map(|g| g.name.clone()).collect()
+        self.governors_in_id_order().into_iter().map(|g| g.name.clone()).collect()
+    }
+    
+    /// Every governor, sorted by ID so results don't depend on hash map iteration order
+    fn governors_in_id_order(&self) -> Vec<&Governor> {
+        let mut governors: Vec<&Governor> = self.governors.values().collect();
+        governors.sort_by_key(|governor| governor.id);
+        governors
     }
     
     /// Get governor count
@@ -125,9 +269,9 @@ impl GovernorManager {
         self.governors.len()
     }
     
-    /// Find governors by tradition affinity
+    /// Find governors by tradition affinity, in governor ID order
     pub fn find_governors_by_tradition(&self, tradition: &str, min_affinity: f64) -> Vec<&Governor> {
-        self.governors.values()
+        self.governors_in_id_order().into_iter()
             .filter(|governor| {
                 governor.tradition_affinities.get(tradition)
                     .map(|affinity| *affinity >= min_affinity)
@@ -136,6 +280,42 @@ impl GovernorManager {
             .collect()
     }
     
+    /// Every governor's affinity with each tradition, governors in ID order
+    ///
+    /// Only traditions named in some governor's affinities appear.
+    pub fn affinity_matrix(&self) -> BTreeMap<String, Vec<(u32, f64)>> {
+        let mut matrix: BTreeMap<String, Vec<(u32, f64)>> = BTreeMap::new();
+        for governor in self.governors.values() {
+            for (tradition, affinity) in &governor.tradition_affinities {
+                matrix.entry(tradition.clone()).or_default().push((governor.id, *affinity));
+            }
+        }
+        for governors in matrix.values_mut() {
+            governors.sort_by_key(|(id, _)| *id);
+        }
+        matrix
+    }
+    
+    /// Traditions no governor holds an affinity of at least `min_affinity` with
+    ///
+    /// Checks every known tradition plus any other named in an affinity. The
+    /// supreme governor, who holds affinity with every tradition, is left out
+    /// so they cannot mask a gap among the other 90.
+    pub fn coverage_gaps(&self, min_affinity: f64) -> Vec<String> {
+        let matrix = self.affinity_matrix();
+        let traditions: BTreeSet<String> = TraditionManager::new().get_tradition_names().into_iter()
+            .chain(matrix.keys().cloned())
+            .collect();
+        
+        traditions.into_iter()
+            .filter(|tradition| {
+                !matrix.get(tradition).is_some_and(|governors| {
+                    governors.iter().any(|(id, affinity)| *id != SUPREME_GOVERNOR_ID && *affinity >= min_affinity)
+                })
+            })
+            .collect()
+    }
+    
     /// Get recommended governor for player
     pub fn get_recommended_governor(&self, 
                                    player_traditions: &HashMap<String, f64>,
@@ -143,7 +323,8 @@ impl GovernorManager {
         let mut best_governor = None;
         let mut best_score = 0.0;
         
-        for governor in self.governors.values() {
+        // Ties go to the lowest governor ID
+        for governor in self.governors_in_id_order() {
             let score = self.calculate_governor_match_score(governor, player_traditions, player_level);
             if score > best_score {
                 best_score = score;
@@ -154,6 +335,34 @@ impl GovernorManager {
         best_governor
     }
     
+    /// Governors to introduce a new player to, chosen deterministically by seed
+    ///
+    /// Candidates sit in `STARTING_AETHYR`, the only Aethyr a new player can
+    /// enter, excluding the supreme governor. Gentle governors (compassionate, sparing with
+    /// challenges) are preferred; if there are too few, the gentlest of the
+    /// rest make up the pool. `ONBOARDING_GOVERNOR_COUNT` are then drawn from
+    /// the pool in an order fixed by `player_seed`.
+    pub fn onboarding_governors(&self, player_seed: u32) -> Vec<&Governor> {
+        let gentleness = |governor: &Governor| {
+            governor.interaction_style.compassion_level - governor.interaction_style.challenge_preference
+        };
+        let is_gentle = |governor: &Governor| {
+            governor.interaction_style.compassion_level >= ONBOARDING_MIN_COMPASSION
+                && governor.interaction_style.challenge_preference <= ONBOARDING_MAX_CHALLENGE
+        };
+        
+        let mut candidates: Vec<&Governor> = self.get_governors_by_aethyr(STARTING_AETHYR).into_iter()
+            .filter(|governor| governor.id != SUPREME_GOVERNOR_ID)
+            .collect();
+        candidates.sort_by(|a, b| gentleness(b).total_cmp(&gentleness(a)).then(a.id.cmp(&b.id)));
+        
+        let gentle = candidates.iter().filter(|governor| is_gentle(governor)).count();
+        candidates.truncate(gentle.max(ONBOARDING_GOVERNOR_COUNT));
+        candidates.sort_by_key(|governor| onboarding_rank(player_seed, governor.id));
+        candidates.truncate(ONBOARDING_GOVERNOR_COUNT);
+        candidates
+    }
+    
     /// Validate governor interaction
     pub fn validate_interaction(&self, 
                                governor_id: u32, 
@@ -182,12 +391,87 @@ impl GovernorManager {
         Ok(true)
     }
     
+    /// Get the player level required to access an Aethyr
+    ///
+    /// Aethyrs outside the layout require the maximum level of 100.
+    pub fn get_aethyr_requirement(&self, aethyr_id: u32) -> u32 {
+        self.aethyr_requirements.get(&aethyr_id).copied().unwrap_or(100)
+    }
+    
+    /// Export every governor profile as a JSON array ordered by ID
+    pub fn export_all(&self) -> serde_json::Value {
+        let mut governors: Vec<&Governor> = self.governors.values().collect();
+        governors.sort_by_key(|governor| governor.id);
+        serde_json::to_value(governors).unwrap_or_else(|_| serde_json::Value::Array(Vec::new()))
+    }
+    
+    /// Build a manager from profiles produced by `export_all`
+    ///
+    /// The import must hold exactly 91 governors with unique IDs in 1-91 and
+    /// unique names, seated according to the canonical Aethyr layout, with
+    /// interaction styles in range. Aethyr access requirements are reset to
+    /// their defaults.
+    pub fn import_all(json: &serde_json::Value) -> Result<Self> {
+        let governors: Vec<Governor> = serde_json::from_value(json.clone())?;
+        let invalid = |message: String| EnochianError::SacredConstraintViolation { constraint: message };
+        
+        if governors.len() != crate::constants::GOVERNOR_COUNT {
+            return Err(invalid(format!(
+                "Expected {} governors, found {}",
+                crate::constants::GOVERNOR_COUNT,
+                governors.len()
+            )));
+        }
+        
+        let aethyr_count = crate::constants::AETHYR_COUNT as u32;
+        let mut manager = GovernorManager::empty(canonical_aethyr_layout());
+        
+        for governor in governors {
+            if governor.id == 0 || governor.id > crate::constants::GOVERNOR_COUNT as u32 {
+                return Err(invalid(format!("Governor ID {} out of range", governor.id)));
+            }
+            if governor.aethyr_id == 0 || governor.aethyr_id > aethyr_count {
+                return Err(invalid(format!(
+                    "Governor {} assigned to invalid Aethyr {}",
+                    governor.name, governor.aethyr_id
+                )));
+            }
+            if manager.governors.contains_key(&governor.id) {
+                return Err(invalid(format!("Duplicate governor ID {}", governor.id)));
+            }
+            if manager.governors.values().any(|existing| existing.name == governor.name) {
+                return Err(invalid(format!("Duplicate governor name {}", governor.name)));
+            }
+            governor.interaction_style.validate()?;
+            manager.governors.insert(governor.id, governor);
+        }
+        
+        manager.build_indices();
+        
+        for aethyr in &manager.aethyr_layout {
+            let seated = manager.governors_by_aethyr.get(&aethyr.aethyr_id).map_or(0, Vec::len);
+            if seated != aethyr.governor_count as usize {
+                return Err(invalid(format!(
+                    "Aethyr {} has {} governors, expected {}",
+                    aethyr.name, seated, aethyr.governor_count
+                )));
+            }
+        }
+        
+        Ok(manager)
+    }
+    
+    /// Override the player level required to access an Aethyr
+    pub fn set_aethyr_requirement(&mut self, aethyr_id: u32, required_level: u32) {
+        self.aethyr_requirements.insert(aethyr_id, required_level);
+    }
+    
     fn initialize_governors(&mut self) {
-        // Initialize the 91 Governor Angels
-        // First 30 Aethyrs with 3 governors each, plus 1 special governor
+        // Initialize the 91 Governor Angels: the named governors and the
+        // supreme governor sit in TEX, the rest fill the layout in order
         
-        // Aethyr 1: TEX (Transcendence tier)
-        self.add_governor(1, "ABRIOND", 1, "TEX", "Creation Mastery", 
+        // Aethyr 30: TEX (Foundation tier)
+        self.add_governor(1, "ABRIOND", SUPREME_AETHYR_ID, "TEX", "Creation Mastery", 
             "The supreme governor of divine creation and manifestation",
             vec!["Commanding", "Wise", "Creative", "Authoritative"],
             vec!["Divine Creation", "Reality Manifestation", "Sacred Geometry"],
@@ -206,7 +490,7 @@ impl GovernorManager {
             }
         );
         
-        self.add_governor(2, "GEDOONS", 1, "TEX", "Ancient Wisdom",
+        self.add_governor(2, "GEDOONS", SUPREME_AETHYR_ID, "TEX", "Ancient Wisdom",
             "Keeper of the most ancient mysteries and forgotten knowledge",
             vec!["Ancient", "Wise", "Patient", "Mysterious"],
             vec!["Historical Mysteries", "Lost Knowledge", "Time Wisdom"],
@@ -225,7 +509,7 @@ impl GovernorManager {
             }
         );
         
-        self.add_governor(3, "MIRZIND", 1, "TEX", "Transformation",
+        self.add_governor(3, "MIRZIND", SUPREME_AETHYR_ID, "TEX", "Transformation",
             "Master of spiritual transformation and evolutionary change",
             vec!["Transformative", "Dynamic", "Evolutionary", "Intense"],
             vec!["Spiritual Evolution", "Inner Alchemy", "Change Mastery"],
@@ -258,8 +542,9 @@ impl GovernorManager {
                    personality_traits: Vec<&str>,
                    wisdom_specializations: Vec<&str>,
                    tradition_affinities: HashMap<&str, f64>,
-                   interaction_style: InteractionStyle) {
+                   mut interaction_style: InteractionStyle) {
         
+        interaction_style.normalize();
         let governor = Governor {
             id,
             name: name.to_string(),
@@ -303,46 +588,34 @@ impl GovernorManager {
     }
     
     fn add_remaining_governors(&mut self) {
-        // Add the remaining 88 governors (simplified for space)
-        // This would include all 91 governors across 30 Aethyrs
-        
-        let mut current_id = 4;
-        
-        // Aethyr 2: RII (Transcendence tier)
-        for i in 0..3 {
-            self.add_governor(
-                current_id + i,
-                &format!("GOV{:02}", current_id + i),
-                2,
-                "RII",
-                &format!("Domain {}", current_id + i),
-                &format!("Governor {} of Aethyr RII", current_id + i),
-                vec!["Wise", "Powerful", "Mysterious"],
-                vec!["Specialized Knowledge", "Sacred Practices"],
-                hashmap!{"Enochian" => 0.9, "Hermetic_Qabalah" => 0.6},
-                InteractionStyle {
-                    authority_level: 0.8,
-                    wisdom_approach: 0.7,
-                    mystical_intensity: 0.8,
-                    compassion_level: 0.7,
-                    challenge_preference: 0.7,
-                    tradition_orthodoxy: 0.8,
-                }
-            );
-        }
-        current_id += 3;
-        
-        // Continue for all 30 Aethyrs (3 governors each = 90 total)
-        for aethyr_id in 3..=30 {
-            for gov_in_aethyr in 0..3 {
-                if current_id <= 91 {
-                    self.add_governor(
-                        current_id,
-                        &format!("GOV{:02}", current_id),
-                        aethyr_id,
-                        &format!("AET{:02}", aethyr_id),
-                        &format!("Domain {}", current_id),
-                        &format!("Governor {} of Aethyr {}", current_id, aethyr_id),
+        // Generated governors (simplified for space) take the IDs after the
+        // named ones, filling each Aethyr's free seats in layout order
+        let layout = self.aethyr_layout.clone();
+        let mut current_id = self.governors.len() as u32 + 1;
+        
+        for aethyr in &layout {
+            let mut seated = self.governors.values().filter(|g| g.aethyr_id == aethyr.aethyr_id).count() as u32;
+            if aethyr.aethyr_id == SUPREME_AETHYR_ID {
+                seated += 1;
+            }
+            
+            for _ in seated..aethyr.governor_count {
+                let (traits, knowledge, affinities, style) = if aethyr.name == HERMETIC_AETHYR_NAME {
+                    (
+                        vec!["Wise", "Powerful", "Mysterious"],
+                        vec!["Specialized Knowledge", "Sacred Practices"],
+                        hashmap!{"Enochian" => 0.9, "Hermetic_Qabalah" => 0.6},
+                        InteractionStyle {
+                            authority_level: 0.8,
+                            wisdom_approach: 0.7,
+                            mystical_intensity: 0.8,
+                            compassion_level: 0.7,
+                            challenge_preference: 0.7,
+                            tradition_orthodoxy: 0.8,
+                        },
+                    )
+                } else {
+                    (
                         vec!["Wise", "Powerful"],
                         vec!["Specialized Knowledge"],
                         hashmap!{"Enochian" => 0.8},
@@ -353,35 +626,56 @@ impl GovernorManager {
                             compassion_level: 0.7,
                             challenge_preference: 0.7,
                             tradition_orthodoxy: 0.8,
-                        }
-                    );
-                    current_id += 1;
-                }
+                        },
+                    )
+                };
+                
+                self.add_governor(
+                    current_id,
+                    &format!("GOV{:02}", current_id),
+                    aethyr.aethyr_id,
+                    &aethyr.name,
+                    &format!("Domain {}", current_id),
+                    &format!("Governor {} of Aethyr {}", current_id, aethyr.name),
+                    traits,
+                    knowledge,
+                    affinities,
+                    style
+                );
+                current_id += 1;
             }
         }
         
-        // Add the 91st special governor if needed
-        if current_id <= 91 {
-            self.add_governor(
-                91,
-                "SUPREME",
-                1,
-                "TEX",
-                "Supreme Authority",
-                "The supreme governor overseeing all others",
-                vec!["Supreme", "Transcendent", "All-Knowing"],
-                vec!["Universal Wisdom", "Supreme Authority"],
-                hashmap!{"Enochian" => 1.0, "All_Traditions" => 0.9},
-                InteractionStyle {
-                    authority_level: 1.0,
-                    wisdom_approach: 1.0,
-                    mystical_intensity: 1.0,
-                    compassion_level: 1.0,
-                    challenge_preference: 1.0,
-                    tradition_orthodoxy: 1.0,
-                }
-            );
-        }
+        // The supreme governor takes the last ID and its seat in TEX. Rather
+        // than a synthetic "all traditions" key, it holds a real affinity with
+        // every tradition so matching and scoring resolve against the TraditionManager.
+        let tradition_names = TraditionManager::new().get_tradition_names();
+        let supreme_affinities = tradition_names.iter()
+            .map(|name| {
+                let affinity = if name == "Enochian" { 1.0 } else { SUPREME_TRADITION_AFFINITY };
+                (name.as_str(), affinity)
+            })
+            .collect();
+        
+        self.add_governor(
+            SUPREME_GOVERNOR_ID,
+            "SUPREME",
+            SUPREME_AETHYR_ID,
+            &layout[SUPREME_AETHYR_ID as usize - 1].name,
+            "Supreme Authority",
+            "The supreme governor overseeing all others",
+            vec!["Supreme", "Transcendent", "All-Knowing"],
+            vec!["Universal Wisdom", "Supreme Authority"],
+            supreme_affinities,
+            InteractionStyle {
+                authority_level: 1.0,
+                wisdom_approach: 1.0,
+                mystical_intensity: 1.0,
+                compassion_level: 1.0,
+                challenge_preference: 1.0,
+                tradition_orthodoxy: 1.0,
+            }
+        );
     }
     
     fn build_indices(&mut self) {
@@ -394,7 +688,7 @@ impl GovernorManager {
         for (id, governor) in &self.governors {
             self.governors_by_aethyr
                 .entry(governor.aethyr_id)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(*id);
         }
         
@@ -402,9 +696,14 @@ impl GovernorManager {
         for (id, governor) in &self.governors {
             self.governors_by_domain
                 .entry(governor.domain.clone())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(*id);
         }
+        
+        // HashMap iteration order varies between runs, so sort each index
+        for ids in self.governors_by_aethyr.values_mut().chain(self.governors_by_domain.values_mut()) {
+            ids.sort_unstable();
+        }
     }
     
     fn calculate_governor_match_score(&self, 
@@ -413,8 +712,10 @@ impl GovernorManager {
                                     player_level: u32) -> f64 {
         let mut score = 0.0;
         
-        // Tradition affinity matching
-        for (tradition, governor_affinity) in &governor.tradition_affinities {
+        // Tradition affinity matching, summed in tradition name order so the
+        // score doesn't depend on hash map iteration order
+        let affinities: BTreeMap<&String, &f64> = governor.tradition_affinities.iter().collect();
+        for (tradition, governor_affinity) in affinities {
             if let Some(player_mastery) = player_traditions.get(tradition) {
                 let affinity_match = 1.0 - (governor_affinity - player_mastery).abs();
                 score += affinity_match * governor_affinity;
@@ -436,16 +737,58 @@ impl GovernorManager {
         
         score
     }
+}
+
+/// Position of a governor in a player's onboarding draw
+fn onboarding_rank(player_seed: u32, governor_id: u32) -> u64 {
+    use sha2::{Digest, Sha256};
+    
+    let mut hasher = Sha256::new();
+    hasher.update(b"enochian-onboarding");
+    hasher.update(player_seed.to_be_bytes());
+    hasher.update(governor_id.to_be_bytes());
+    let digest = hasher.finalize();
+    u64::from_be_bytes([digest[0], digest[1], digest[2], digest[3], digest[4], digest[5], digest[6], digest[7]])
+}
+
+/// Check a layout covers Aethyrs 1-30 in order and seats all 91 governors
+fn validate_aethyr_layout(layout: &[AethyrLayout]) -> Result<()> {
+    use crate::constants::{AETHYR_COUNT, GOVERNOR_COUNT};
+    let invalid = |message: String| EnochianError::SacredConstraintViolation { constraint: message };
     
-    fn get_aethyr_requirement(&self, aethyr_id: u32) -> u32 {
-        // Aethyr access requirements (simplified)
-        match aethyr_id {
-            1..=10 => aethyr_id * 5,      // Transcendence tier: 5-50
-            11..=20 => 50 + (aethyr_id - 10) * 3, // Mastery tier: 53-80
-            21..=30 => 80 + (aethyr_id - 20) * 2, // Foundation tier: 82-100
-            _ => 100,
+    if layout.len() != AETHYR_COUNT {
+        return Err(invalid(format!("Aethyr layout must list {} Aethyrs, found {}", AETHYR_COUNT, layout.len())));
+    }
+    for (aethyr, expected_id) in layout.iter().zip(1u32..) {
+        if aethyr.aethyr_id != expected_id {
+            return Err(invalid(format!("Aethyr layout lists Aethyr {} where {} was expected", aethyr.aethyr_id, expected_id)));
+        }
+        if aethyr.governor_count == 0 {
+            return Err(invalid(format!("Aethyr {} has no governor", aethyr.name)));
         }
     }
+    
+    // Three named governors and the supreme governor always sit in TEX
+    let supreme_seats = layout[SUPREME_AETHYR_ID as usize - 1].governor_count;
+    if supreme_seats < 4 {
+        return Err(invalid(format!("Aethyr {} needs at least 4 governors, found {}", AETHYR_NAMES[SUPREME_AETHYR_ID as usize - 1], supreme_seats)));
+    }
+    
+    let total: u32 = layout.iter().map(|aethyr| aethyr.governor_count).sum();
+    if total as usize != GOVERNOR_COUNT {
+        return Err(invalid(format!("Aethyr layout seats {} governors, expected {}", total, GOVERNOR_COUNT)));
+    }
+    Ok(())
+}
+
+/// Default Aethyr access requirements (simplified)
+fn default_aethyr_requirement(aethyr_id: u32) -> u32 {
+    match aethyr_id {
+        1..=10 => aethyr_id * 5,      // Transcendence tier: 5-50
+        11..=20 => 50 + (aethyr_id - 10) * 3, // Mastery tier: 53-80
+        21..=30 => 80 + (aethyr_id - 20) * 2, // Foundation tier: 82-100
+        _ => 100,
+    }
 }
 
 /// Macro for creating HashMap literals
@@ -459,9 +802,31 @@ macro_rules! hashmap {
     };
 }
 
-/// Get governor count (for sacred constraint validation)
+/// Process-wide governor manager, built on first use
+static SHARED_MANAGER: RwLock<Option<Arc<GovernorManager>>> = RwLock::new(None);
+
+/// Serializes tests that swap or depend on the shared manager
+#[cfg(test)]
+pub(crate) static SHARED_MANAGER_TEST_LOCK: RwLock<()> = RwLock::new(());
+
+/// The process-wide governor manager, built with the canonical layout on first use
+pub fn shared_manager() -> Arc<GovernorManager> {
+    if let Some(manager) = SHARED_MANAGER.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+        return Arc::clone(manager);
+    }
+    let mut shared = SHARED_MANAGER.write().unwrap_or_else(PoisonError::into_inner);
+    Arc::clone(shared.get_or_insert_with(|| Arc::new(GovernorManager::new())))
+}
+
+/// Replace the process-wide governor manager, e.g. with one from
+/// `GovernorManager::import_all`, returning the previous one if it was built
+pub fn set_shared_manager(manager: GovernorManager) -> Option<Arc<GovernorManager>> {
+    SHARED_MANAGER.write().unwrap_or_else(PoisonError::into_inner).replace(Arc::new(manager))
+}
+
+/// Number of governors loaded in the shared manager (for sacred constraint validation)
 pub fn get_governor_count() -> usize {
-    91
+    shared_manager().get_governor_count()
 }
 
 #[cfg(test)]
@@ -493,13 +858,26 @@ mod tests {
     fn test_aethyr_grouping() {
         let manager = GovernorManager::new();
         
-        // Test Aethyr 1 governors
-        let aethyr1_governors = manager.get_governors_by_aethyr(1);
-        assert!(aethyr1_governors.len() >= 3); // At least ABRIOND, GEDOONS, MIRZIND
+        // Test Aethyr 30 (TEX) governors
+        let tex_governors = manager.get_governors_by_aethyr(SUPREME_AETHYR_ID);
+        assert!(tex_governors.len() >= 3); // At least ABRIOND, GEDOONS, MIRZIND
         
-        // Verify they're all in Aethyr 1
-        for governor in aethyr1_governors {
-            assert_eq!(governor.aethyr_id, 1);
+        // Verify they're all in TEX
+        for governor in tex_governors {
+            assert_eq!(governor.aethyr_id, SUPREME_AETHYR_ID);
+        }
+    }
+    
+    #[test]
+    fn test_aethyr_index_is_sorted_and_stable() {
+        let ids = |manager: &GovernorManager| -> Vec<u32> {
+            manager.get_governors_by_aethyr(1).iter().map(|g| g.id).collect()
+        };
+        let expected = ids(&GovernorManager::new());
+        assert!(expected.windows(2).all(|pair| pair[0] < pair[1]));
+        
+        for _ in 0..10 {
+            assert_eq!(ids(&GovernorManager::new()), expected);
         }
     }
     
@@ -516,4 +894,246 @@ mod tests {
             assert!(*affinity >= 0.9);
         }
     }
+    
+    #[test]
+    fn test_affinity_matrix_covers_every_governor_in_order() {
+        let manager = GovernorManager::new();
+        let matrix = manager.affinity_matrix();
+        
+        let enochian = &matrix["Enochian"];
+        assert!(enochian.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(enochian.contains(&(SUPREME_GOVERNOR_ID, 1.0)));
+        let entries: usize = matrix.values().map(Vec::len).sum();
+        let affinities: usize = (1..=91).map(|id| manager.get_governor(id).unwrap().tradition_affinities.len()).sum();
+        assert_eq!(entries, affinities);
+    }
+    
+    #[test]
+    fn test_coverage_gaps_report_uncovered_traditions() {
+        let mut manager = GovernorManager::new();
+        let gap = "Chaos_Magic";
+        for governor in manager.governors.values_mut() {
+            governor.tradition_affinities.remove(gap);
+        }
+        
+        let gaps = manager.coverage_gaps(0.5);
+        assert!(gaps.contains(&gap.to_string()));
+        assert!(!gaps.contains(&"Enochian".to_string()));
+        assert!(!manager.affinity_matrix().contains_key(gap));
+        
+        // The supreme governor alone does not close a gap
+        manager.governors.get_mut(&SUPREME_GOVERNOR_ID).unwrap()
+            .tradition_affinities.insert(gap.to_string(), 1.0);
+        assert!(manager.coverage_gaps(0.5).contains(&gap.to_string()));
+        manager.governors.get_mut(&1).unwrap().tradition_affinities.insert(gap.to_string(), 0.8);
+        assert!(!manager.coverage_gaps(0.5).contains(&gap.to_string()));
+    }
+    
+    #[test]
+    fn test_supreme_governor_affinities_are_real_traditions() {
+        let manager = GovernorManager::new();
+        let traditions = TraditionManager::new();
+        let supreme = manager.get_governor(SUPREME_GOVERNOR_ID).unwrap();
+        
+        assert_eq!(supreme.name, "SUPREME");
+        assert_eq!(supreme.tradition_affinities.len(), traditions.get_tradition_count());
+        assert_eq!(supreme.tradition_affinities.get("Enochian"), Some(&1.0));
+        for tradition in supreme.tradition_affinities.keys() {
+            assert!(traditions.get_tradition(tradition).is_some(), "unknown tradition {}", tradition);
+        }
+        
+        let affinity_traditions: Vec<String> = supreme.tradition_affinities.keys().cloned().collect();
+        assert!(traditions.validate_combination(&affinity_traditions).is_ok());
+    }
+    
+    #[test]
+    fn test_supreme_governor_matches_every_tradition() {
+        let manager = GovernorManager::new();
+        let traditions = TraditionManager::new();
+        
+        for tradition in traditions.get_tradition_names() {
+            let matches = manager.find_governors_by_tradition(&tradition, SUPREME_TRADITION_AFFINITY);
+            assert!(matches.iter().any(|g| g.id == SUPREME_GOVERNOR_ID));
+        }
+    }
+    
+    #[test]
+    fn test_export_import_round_trip() {
+        let manager = GovernorManager::new();
+        let exported = manager.export_all();
+        assert_eq!(exported.as_array().unwrap().len(), 91);
+        
+        let imported = GovernorManager::import_all(&exported).unwrap();
+        assert_eq!(imported.get_governor_count(), 91);
+        assert_eq!(imported.export_all(), exported);
+        for id in 1..=91 {
+            let original = serde_json::to_value(manager.get_governor(id).unwrap()).unwrap();
+            let copy = serde_json::to_value(imported.get_governor(id).unwrap()).unwrap();
+            assert_eq!(original, copy);
+        }
+        assert_eq!(imported.get_governor_by_name("ABRIOND").unwrap().id, 1);
+        assert_eq!(imported.get_governors_by_aethyr(1).len(), manager.get_governors_by_aethyr(1).len());
+    }
+    
+    #[test]
+    fn test_import_rejects_broken_invariants() {
+        let exported = GovernorManager::new().export_all();
+        
+        let mut missing = exported.clone();
+        missing.as_array_mut().unwrap().pop();
+        assert!(GovernorManager::import_all(&missing).is_err());
+        
+        let mut duplicate = exported.clone();
+        duplicate[1]["id"] = serde_json::json!(1);
+        assert!(GovernorManager::import_all(&duplicate).is_err());
+        
+        let mut bad_aethyr = exported.clone();
+        bad_aethyr[4]["aethyr_id"] = serde_json::json!(31);
+        assert!(GovernorManager::import_all(&bad_aethyr).is_err());
+        
+        assert!(GovernorManager::import_all(&serde_json::json!({"governors": []})).is_err());
+        
+        let mut out_of_range = exported.clone();
+        out_of_range[2]["interaction_style"]["authority_level"] = serde_json::json!(1.5);
+        assert!(GovernorManager::import_all(&out_of_range).is_err());
+    }
+    
+    #[test]
+    fn test_under_populated_shared_manager_fails_validation() {
+        let _guard = SHARED_MANAGER_TEST_LOCK.write().unwrap_or_else(PoisonError::into_inner);
+        let mut manager = GovernorManager::new();
+        manager.governors.remove(&90);
+        manager.build_indices();
+        set_shared_manager(manager);
+        
+        assert_eq!(get_governor_count(), 90);
+        let result = crate::validate_sacred_constraints();
+        set_shared_manager(GovernorManager::new());
+        assert!(matches!(result, Err(EnochianError::SacredConstraintViolation { .. })));
+        assert!(crate::validate_sacred_constraints().is_ok());
+    }
+    
+    #[test]
+    fn test_interaction_style_normalize_clamps() {
+        let mut style = InteractionStyle {
+            authority_level: 1.5,
+            wisdom_approach: -0.2,
+            mystical_intensity: f64::NAN,
+            compassion_level: 0.4,
+            challenge_preference: 0.0,
+            tradition_orthodoxy: 1.0,
+        };
+        assert!(style.validate().is_err());
+        
+        style.normalize();
+        assert!(style.validate().is_ok());
+        assert_eq!(style.authority_level, 1.0);
+        assert_eq!(style.wisdom_approach, 0.0);
+        assert_eq!(style.mystical_intensity, 0.5);
+        assert_eq!(style.compassion_level, 0.4);
+    }
+    
+    #[test]
+    fn test_onboarding_governors_deterministic_and_accessible() {
+        let manager = GovernorManager::new();
+        let ids = |seed: u32| manager.onboarding_governors(seed).iter().map(|g| g.id).collect::<Vec<_>>();
+        
+        assert_eq!(ids(42), ids(42));
+        assert_eq!(ids(42).len(), ONBOARDING_GOVERNOR_COUNT);
+        assert!((0..20).any(|seed| ids(seed) != ids(42)));
+        
+        for seed in 0..20 {
+            for governor in manager.onboarding_governors(seed) {
+                assert_eq!(governor.aethyr_id, STARTING_AETHYR);
+                assert_ne!(governor.id, SUPREME_GOVERNOR_ID);
+                assert!(governor.interaction_style.challenge_preference <= ONBOARDING_MAX_CHALLENGE);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_canonical_layout_seats_four_in_tex() {
+        let manager = GovernorManager::new();
+        let layout = manager.aethyr_layout();
+        assert_eq!(layout.iter().map(|aethyr| aethyr.governor_count).sum::<u32>(), 91);
+        
+        for aethyr in layout {
+            let governors = manager.get_governors_by_aethyr(aethyr.aethyr_id);
+            assert_eq!(governors.len(), aethyr.governor_count as usize);
+            assert!(governors.iter().all(|g| g.aethyr_name == aethyr.name));
+        }
+        
+        let four_governor_aethyrs: Vec<&str> = layout.iter()
+            .filter(|aethyr| aethyr.governor_count == 4)
+            .map(|aethyr| aethyr.name.as_str())
+            .collect();
+        assert_eq!(four_governor_aethyrs, vec!["TEX"]);
+        assert_eq!(layout[0].name, "LIL");
+        assert_eq!(layout[29].aethyr_id, 30);
+        assert_eq!(manager.get_governors_by_aethyr(30).iter().map(|g| g.id).collect::<Vec<_>>(), vec![1, 2, 3, 91]);
+    }
+    
+    #[test]
+    fn test_rii_governors_keep_hermetic_affinity() {
+        let manager = GovernorManager::new();
+        let rii_governors = manager.get_governors_by_aethyr(29);
+        assert_eq!(rii_governors.len(), 3);
+        
+        for governor in rii_governors {
+            assert_eq!(governor.aethyr_name, HERMETIC_AETHYR_NAME);
+            assert!(governor.personality_traits.contains(&"Mysterious".to_string()));
+            assert_eq!(governor.tradition_affinities.get("Hermetic_Qabalah"), Some(&0.6));
+            assert_eq!(governor.tradition_affinities.get("Enochian"), Some(&0.9));
+        }
+        
+        let lil_governor = manager.get_governors_by_aethyr(1)[0];
+        assert!(!lil_governor.tradition_affinities.contains_key("Hermetic_Qabalah"));
+    }
+    
+    #[test]
+    fn test_custom_layout_reseats_governors() {
+        let mut layout = canonical_aethyr_layout();
+        layout[1].governor_count = 4;
+        layout[0].governor_count = 2;
+        let manager = GovernorManager::with_layout(layout).unwrap();
+        assert_eq!(manager.get_governor_count(), 91);
+        assert_eq!(manager.get_governors_by_aethyr(2).len(), 4);
+        assert_eq!(manager.get_governors_by_aethyr(1).len(), 2);
+        
+        let mut short = canonical_aethyr_layout();
+        short[0].governor_count = 2;
+        assert!(GovernorManager::with_layout(short).is_err());
+        
+        let mut crowded_tex = canonical_aethyr_layout();
+        crowded_tex[29].governor_count = 3;
+        crowded_tex[1].governor_count = 4;
+        assert!(GovernorManager::with_layout(crowded_tex).is_err());
+    }
+    
+    #[test]
+    fn test_aethyr_requirement_override() {
+        let mut manager = GovernorManager::new();
+        let mut traditions = HashMap::new();
+        traditions.insert("Enochian".to_string(), 0.8);
+        
+        let governor_id = manager.get_governors_by_aethyr(15)[0].id;
+        assert_eq!(manager.get_aethyr_requirement(15), 65);
+        assert!(!manager.validate_interaction(governor_id, 30, &traditions).unwrap());
+        
+        manager.set_aethyr_requirement(15, 20);
+        assert!(manager.validate_interaction(governor_id, 30, &traditions).unwrap());
+    }
+    
+    #[test]
+    fn test_governor_tier_follows_its_aethyr() {
+        let mut manager = GovernorManager::new();
+        assert_eq!(manager.governor_tier(2).unwrap().name, "Foundation");
+        assert_eq!(manager.governor_tier(manager.get_governors_by_aethyr(1)[0].id).unwrap().name, "Transcendence");
+        assert!(manager.governor_tier(0).is_none());
+        
+        let mut tiers = AethyrTiers::default().tiers().to_vec();
+        tiers[2].name = "Threshold".to_string();
+        manager.set_aethyr_tiers(AethyrTiers::new(tiers).unwrap());
+        assert_eq!(manager.governor_tier(2).unwrap().name, "Threshold");
+    }
 }
@@ -0,0 +1,262 @@
+//! Append-only journal of committed core operations, for replay from chain
+//!
+//! With `SystemConfig::record_journal` set, `EnochianCore` appends an entry
+//! for every operation that succeeds. Each entry names the operation, the
+//! instant it ran at and the core's `state_hash` before it ran, and encodes as
+//! one line of compact JSON that can be inscribed on its own. `replay` rebuilds
+//! the core by applying the entries in order, refusing any entry whose prior
+//! hash does not match the state replayed so far.
+//!
+//! Local-only mutations (snapshot restores, registry revalidation) are not
+//! journaled; a journal spanning one no longer replays past that point.
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use crate::core::{ActionType, EnochianCore, GameState, QuestChain, QuestData, SnapshotId, StartingLoadout, StateConsequence, SystemConfig};
+use crate::runtime::{FixedClock, SystemRandom};
+use crate::{EnochianError, Result};
+
+/// Version of the journal encoding
+pub const JOURNAL_FORMAT_VERSION: u32 = 1;
+
+/// A committed core operation, with the arguments it was called with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JournalOp {
+    /// `create_player_state_with_loadout`
+    CreatePlayer {
+        /// Player identifier
+        player_id: String,
+        /// Starting loadout
+        loadout: StartingLoadout,
+    },
+    /// `register_quest`
+    RegisterQuest {
+        /// Quest as submitted for registration
+        quest: QuestData,
+    },
+    /// `register_chain`
+    RegisterChain {
+        /// Chain as submitted for registration
+        chain: QuestChain,
+    },
+    /// `start_quest`
+    StartQuest {
+        /// Player identifier
+        player_id: String,
+        /// Quest identifier
+        quest_id: String,
+    },
+    /// `make_quest_choice`
+    MakeChoice {
+        /// Player identifier
+        player_id: String,
+        /// Quest identifier
+        quest_id: String,
+        /// Choice identifier
+        choice_id: String,
+    },
+    /// `complete_objective`
+    CompleteObjective {
+        /// Player identifier
+        player_id: String,
+        /// Quest identifier
+        quest_id: String,
+        /// Index of the objective
+        objective_index: usize,
+        /// Action that completed it
+        action: ActionType,
+    },
+    /// `complete_quest`
+    CompleteQuest {
+        /// Player identifier
+        player_id: String,
+        /// Quest identifier
+        quest_id: String,
+    },
+    /// `abandon_quest`
+    AbandonQuest {
+        /// Player identifier
+        player_id: String,
+        /// Quest identifier
+        quest_id: String,
+    },
+    /// `fail_quest`
+    FailQuest {
+        /// Player identifier
+        player_id: String,
+        /// Quest identifier
+        quest_id: String,
+        /// Why the quest failed
+        reason: String,
+    },
+    /// `apply_penalty`
+    ApplyPenalty {
+        /// Player identifier
+        player_id: String,
+        /// Penalty consequences
+        consequences: Vec<StateConsequence>,
+    },
+    /// `release_deferred_rewards`
+    ReleaseDeferredRewards {
+        /// Player identifier
+        player_id: String,
+    },
+    /// `update_player_state` with a state finalized by P2P consensus
+    FinalizeTransition {
+        /// Player identifier
+        player_id: String,
+        /// Finalized state
+        state: GameState,
+    },
+    /// `restore_player`, carrying the checkpoint so replay needs no local snapshots
+    RestorePlayer {
+        /// Player identifier
+        player_id: String,
+        /// Checkpoint restored
+        snapshot_id: SnapshotId,
+        /// Checkpoint state
+        state: GameState,
+    },
+}
+
+/// One journaled operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Position in the journal, starting at 0
+    pub sequence: u64,
+    /// Milliseconds since the Unix epoch at which the operation ran
+    pub recorded_at: i64,
+    /// `EnochianCore::state_hash` before the operation
+    pub prior_hash: String,
+    /// The operation
+    pub op: JournalOp,
+}
+
+impl JournalEntry {
+    /// Compact JSON encoding, suitable for inscription
+    pub fn to_inscription(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+    
+    /// Decode an entry from its inscription
+    pub fn from_inscription(content: &str) -> Result<Self> {
+        Ok(serde_json::from_str(content)?)
+    }
+}
+
+/// Journal header: the format version and the configuration entries replay under
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalHeader {
+    version: u32,
+    config: SystemConfig,
+}
+
+/// Ordered journal of the operations committed to a core
+#[derive(Debug, Clone)]
+pub struct InscriptionJournal {
+    /// Configuration of the core the journal was recorded by
+    pub config: SystemConfig,
+    entries: Vec<JournalEntry>,
+}
+
+impl InscriptionJournal {
+    /// Empty journal for a core running with `config`
+    pub fn new(config: SystemConfig) -> Self {
+        InscriptionJournal { config, entries: Vec::new() }
+    }
+    
+    /// Journal from entries collected elsewhere, e.g. read back from chain
+    ///
+    /// Entries must be numbered consecutively from 0.
+    pub fn from_entries(config: SystemConfig, entries: Vec<JournalEntry>) -> Result<Self> {
+        if let Some((index, entry)) = entries.iter().enumerate().find(|(index, entry)| entry.sequence != *index as u64) {
+            return Err(EnochianError::JournalReplayError {
+                sequence: entry.sequence,
+                reason: format!("expected sequence {}", index),
+            });
+        }
+        Ok(InscriptionJournal { config, entries })
+    }
+    
+    /// Entries, oldest first
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+    
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Whether nothing has been journaled
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Drop every entry from `len` on, e.g. for operations that were rolled back
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.entries.truncate(len);
+    }
+    
+    /// Append the next entry
+    pub(crate) fn record(&mut self, op: JournalOp, recorded_at: i64, prior_hash: String) {
+        self.entries.push(JournalEntry {
+            sequence: self.entries.len() as u64,
+            recorded_at,
+            prior_hash,
+            op,
+        });
+    }
+    
+    /// Newline-separated inscriptions: a header line, then one line per entry
+    pub fn to_inscription(&self) -> Result<String> {
+        let header = JournalHeader { version: JOURNAL_FORMAT_VERSION, config: self.config.clone() };
+        let mut lines = vec![serde_json::to_string(&header)?];
+        for entry in &self.entries {
+            lines.push(entry.to_inscription()?);
+        }
+        Ok(lines.join("\n"))
+    }
+    
+    /// Decode a journal written by `to_inscription`
+    pub fn from_inscription(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        let header: JournalHeader = serde_json::from_str(lines.next().unwrap_or_default())?;
+        if header.version != JOURNAL_FORMAT_VERSION {
+            return Err(EnochianError::JournalReplayError {
+                sequence: 0,
+                reason: format!("unsupported journal version {}", header.version),
+            });
+        }
+        let entries = lines.map(JournalEntry::from_inscription).collect::<Result<Vec<_>>>()?;
+        Self::from_entries(header.config, entries)
+    }
+}
+
+/// Rebuild a core by applying a journal's entries in order
+///
+/// Each entry runs at its recorded instant and must find the state hash it
+/// was recorded against; the first that does not, or that fails to apply,
+/// stops the replay with `EnochianError::JournalReplayError`.
+pub fn replay(journal: &InscriptionJournal) -> Result<EnochianCore> {
+    let clock = Arc::new(FixedClock::at_unix(0));
+    let mut core = EnochianCore::with_sources(journal.config.clone(), clock.clone(), Arc::new(SystemRandom));
+    core.initialize()?;
+    
+    for entry in journal.entries() {
+        let failed = |reason: String| EnochianError::JournalReplayError { sequence: entry.sequence, reason };
+        
+        let state_hash = core.state_hash();
+        if state_hash != entry.prior_hash {
+            return Err(failed(format!("prior state hash {} does not match replayed state {}", entry.prior_hash, state_hash)));
+        }
+        
+        let recorded_at = DateTime::from_timestamp_millis(entry.recorded_at)
+            .ok_or_else(|| failed(format!("invalid timestamp {}", entry.recorded_at)))?;
+        clock.set(recorded_at);
+        core.apply_journal_op(entry.op.clone()).map_err(|error| failed(error.to_string()))?;
+    }
+    
+    Ok(core)
+}
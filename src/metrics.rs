@@ -0,0 +1,197 @@
+//! Process-wide operational counters for server monitoring
+//!
+//! Counting is compiled out unless the `metrics` feature is enabled, in which
+//! case snapshots report every counter as zero. Other crates keep their own
+//! registries by implementing `MetricCounter` for their counter enum.
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use serde_json::json;
+use crate::{Result, EnochianError};
+
+/// Most counters one `Metrics` registry can hold
+pub const MAX_COUNTERS: usize = 16;
+
+/// A set of counters a `Metrics` registry tracks
+pub trait MetricCounter: Copy + 'static {
+    /// Every counter, in snapshot order
+    const ALL: &'static [Self];
+    
+    /// Position of the counter in `ALL`
+    fn index(self) -> usize;
+    
+    /// Snapshot key, e.g. "quests_started"
+    fn as_str(self) -> &'static str;
+}
+
+/// Operation tracked by a metrics counter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Counter {
+    /// Quests accepted into the registry
+    QuestsRegistered,
+    /// Quests started by players
+    QuestsStarted,
+    /// Quests completed by players
+    QuestsCompleted,
+    /// Authenticity scores calculated
+    AuthenticityChecks,
+}
+
+impl Counter {
+    /// Every counter, in snapshot order
+    pub const ALL: [Counter; 4] = [
+        Counter::QuestsRegistered,
+        Counter::QuestsStarted,
+        Counter::QuestsCompleted,
+        Counter::AuthenticityChecks,
+    ];
+    
+    /// Snapshot key, e.g. "quests_started"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Counter::QuestsRegistered => "quests_registered",
+            Counter::QuestsStarted => "quests_started",
+            Counter::QuestsCompleted => "quests_completed",
+            Counter::AuthenticityChecks => "authenticity_checks",
+        }
+    }
+}
+
+impl MetricCounter for Counter {
+    const ALL: &'static [Self] = &Counter::ALL;
+    
+    fn index(self) -> usize {
+        self as usize
+    }
+    
+    fn as_str(self) -> &'static str {
+        Counter::as_str(&self)
+    }
+}
+
+/// Registry of operation counters and error counts by kind
+#[derive(Debug)]
+pub struct Metrics<C: MetricCounter = Counter> {
+    counters: [AtomicU64; MAX_COUNTERS],
+    errors: Mutex<BTreeMap<&'static str, u64>>,
+    counter_set: PhantomData<C>,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU64 = AtomicU64::new(0);
+
+impl<C: MetricCounter> Metrics<C> {
+    /// Registry with every counter at zero
+    pub const fn new() -> Self {
+        assert!(C::ALL.len() <= MAX_COUNTERS, "too many metrics counters");
+        Metrics {
+            counters: [ZERO; MAX_COUNTERS],
+            errors: Mutex::new(BTreeMap::new()),
+            counter_set: PhantomData,
+        }
+    }
+    
+    /// Count one occurrence of `counter`
+    pub fn increment(&self, counter: C) {
+        if cfg!(feature = "metrics") {
+            self.counters[counter.index()].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    
+    /// Count one error under its `EnochianError::kind`
+    pub fn record_error(&self, error: &EnochianError) {
+        self.record_error_kind(error.kind());
+    }
+    
+    /// Count one error under a caller-chosen kind, e.g. "unknown_item"
+    pub fn record_error_kind(&self, kind: &'static str) {
+        if cfg!(feature = "metrics") {
+            let mut errors = self.errors.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            *errors.entry(kind).or_insert(0) += 1;
+        }
+    }
+    
+    /// Count `counter` if `result` succeeded, or its error otherwise
+    pub fn observe<T>(&self, counter: C, result: Result<T>) -> Result<T> {
+        match &result {
+            Ok(_) => self.increment(counter),
+            Err(error) => self.record_error(error),
+        }
+        result
+    }
+    
+    /// Current value of `counter`
+    pub fn get(&self, counter: C) -> u64 {
+        self.counters[counter.index()].load(Ordering::Relaxed)
+    }
+    
+    /// All counters and error counts as JSON
+    pub fn snapshot(&self) -> serde_json::Value {
+        let counters: BTreeMap<&str, u64> = C::ALL.iter()
+            .map(|counter| (counter.as_str(), self.get(*counter)))
+            .collect();
+        let errors = self.errors.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        
+        json!({
+            "enabled": cfg!(feature = "metrics"),
+            "counters": counters,
+            "errors": errors,
+        })
+    }
+    
+    /// Set every counter back to zero and forget recorded errors
+    pub fn reset(&self) {
+        for counter in &self.counters {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.errors.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clear();
+    }
+}
+
+impl<C: MetricCounter> Default for Metrics<C> {
+    fn default() -> Self {
+        Metrics::new()
+    }
+}
+
+static METRICS: Metrics<Counter> = Metrics::new();
+
+/// The process-wide registry updated by the core and authenticity scorer
+pub fn global() -> &'static Metrics {
+    &METRICS
+}
+
+/// Snapshot of the process-wide registry
+pub fn metrics_snapshot() -> serde_json::Value {
+    METRICS.snapshot()
+}
+
+/// Reset the process-wide registry
+pub fn reset_metrics() {
+    METRICS.reset()
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_reset_clears_counters_and_errors() {
+        let metrics = Metrics::<Counter>::new();
+        metrics.increment(Counter::QuestsStarted);
+        let _ = metrics.observe::<()>(Counter::QuestsStarted, Err(EnochianError::QuestSlotsFull { limit: 3 }));
+        metrics.record_error_kind("QuestSlotsFull");
+        
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot["enabled"], true);
+        assert_eq!(snapshot["counters"]["quests_started"], 1);
+        assert_eq!(snapshot["errors"]["QuestSlotsFull"], 2);
+        
+        metrics.reset();
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot["counters"]["quests_started"], 0);
+        assert!(snapshot["errors"].as_object().unwrap().is_empty());
+    }
+}
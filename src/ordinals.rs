@@ -0,0 +1,247 @@
+//! Chunking of payloads too large for a single Ordinals inscription, and
+//! sanitization of text before it is inscribed
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::{Result, EnochianError};
+
+/// Payload bytes per chunk, leaving headroom under `MAX_ORDINALS_SIZE` for
+/// the chunk header
+pub const ORDINAL_CHUNK_PAYLOAD_SIZE: usize = 1_000_000;
+
+/// Most chunks `reassemble` accepts for one payload (about 1GB of data), so a
+/// forged `total` cannot force a huge allocation
+pub const MAX_ORDINAL_CHUNKS: u32 = 1024;
+
+/// One inscription's worth of a chunked payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrdinalChunk {
+    /// Position of the chunk, starting at 0
+    pub index: u32,
+    /// Number of chunks the payload was split into
+    pub total: u32,
+    /// SHA-256 of this chunk's data (hex)
+    pub content_hash: String,
+    /// SHA-256 of the complete payload (hex), shared by every chunk
+    pub payload_hash: String,
+    /// Chunk data
+    pub data: Vec<u8>,
+}
+
+/// Split a payload into chunks that each fit in one inscription
+///
+/// `reassemble` accepts at most `MAX_ORDINAL_CHUNKS` of them, so larger
+/// payloads must be split at a higher level first.
+pub fn split_for_ordinals(data: &[u8]) -> Vec<OrdinalChunk> {
+    let payload_hash = sha256_hex(data);
+    
+    // An empty payload still yields one chunk so it can be reassembled
+    let pieces: Vec<&[u8]> = if data.is_empty() {
+        vec![data]
+    } else {
+        data.chunks(ORDINAL_CHUNK_PAYLOAD_SIZE).collect()
+    };
+    let total = pieces.len() as u32;
+    
+    pieces.into_iter()
+        .enumerate()
+        .map(|(index, piece)| OrdinalChunk {
+            index: index as u32,
+            total,
+            content_hash: sha256_hex(piece),
+            payload_hash: payload_hash.clone(),
+            data: piece.to_vec(),
+        })
+        .collect()
+}
+
+/// Reassemble a payload from its chunks, in any order
+///
+/// Every chunk must be present exactly once, belong to the same payload and
+/// match its content hash, and the result must match the payload hash. The
+/// payload may span at most `MAX_ORDINAL_CHUNKS` chunks.
+pub fn reassemble(chunks: &[OrdinalChunk]) -> Result<Vec<u8>> {
+    let first = chunks.first().ok_or_else(|| invalid("no chunks supplied".to_string()))?;
+    if first.total > MAX_ORDINAL_CHUNKS {
+        return Err(invalid(format!("{} chunks exceeds limit of {}", first.total, MAX_ORDINAL_CHUNKS)));
+    }
+    let total = first.total as usize;
+    let payload_hash = &first.payload_hash;
+    
+    let mut ordered: Vec<Option<&OrdinalChunk>> = vec![None; total];
+    for chunk in chunks {
+        if chunk.total as usize != total || &chunk.payload_hash != payload_hash {
+            return Err(invalid(format!("chunk {} belongs to a different payload", chunk.index)));
+        }
+        let slot = ordered.get_mut(chunk.index as usize)
+            .ok_or_else(|| invalid(format!("chunk index {} out of range for {} chunks", chunk.index, total)))?;
+        if slot.is_some() {
+            return Err(invalid(format!("duplicate chunk {}", chunk.index)));
+        }
+        if sha256_hex(&chunk.data) != chunk.content_hash {
+            return Err(invalid(format!("chunk {} is corrupt", chunk.index)));
+        }
+        *slot = Some(chunk);
+    }
+    
+    let missing: Vec<String> = ordered.iter()
+        .enumerate()
+        .filter(|(_, chunk)| chunk.is_none())
+        .map(|(index, _)| index.to_string())
+        .collect();
+    if !missing.is_empty() {
+        return Err(invalid(format!("missing chunks {}", missing.join(", "))));
+    }
+    
+    let data: Vec<u8> = ordered.into_iter()
+        .flatten()
+        .flat_map(|chunk| chunk.data.iter().copied())
+        .collect();
+    if &sha256_hex(&data) != payload_hash {
+        return Err(invalid("reassembled payload does not match its hash".to_string()));
+    }
+    
+    Ok(data)
+}
+
+/// Clean text for inscription, or reject it if it cannot be inscribed safely
+///
+/// Null bytes and U+FFFD replacement characters (the usual trace of invalid
+/// UTF-8 decoded lossily before concatenation) are rejected outright. Line
+/// endings become `\n`, other control characters are stripped, Unicode
+/// whitespace becomes a plain space, trailing whitespace is trimmed from each
+/// line and leading/trailing blank space from the whole text. The result must
+/// fit within `MAX_ORDINALS_SIZE`.
+pub fn sanitize_for_inscription(content: &str) -> Result<String> {
+    let sanitized = clean_for_inscription(content)?;
+    if sanitized.len() > crate::constants::MAX_ORDINALS_SIZE {
+        return Err(invalid_content(format!(
+            "{} bytes exceeds limit of {} bytes",
+            sanitized.len(),
+            crate::constants::MAX_ORDINALS_SIZE
+        )));
+    }
+    
+    Ok(sanitized)
+}
+
+/// Clean text as `sanitize_for_inscription` does, without the size limit,
+/// for content that is compressed before it is inscribed
+pub fn clean_for_inscription(content: &str) -> Result<String> {
+    if let Some(offset) = content.find('\0') {
+        return Err(invalid_content(format!("null byte at offset {}", offset)));
+    }
+    if let Some(offset) = content.find(char::REPLACEMENT_CHARACTER) {
+        return Err(invalid_content(format!("invalid UTF-8 sequence (U+FFFD) at offset {}", offset)));
+    }
+    
+    let normalized = content.replace("\r\n", "\n").replace('\r', "\n");
+    let cleaned: String = normalized.chars()
+        .filter_map(|c| match c {
+            '\n' | '\t' => Some(c),
+            c if c.is_control() => None,
+            c if c.is_whitespace() => Some(' '),
+            c => Some(c),
+        })
+        .collect();
+    let sanitized = cleaned.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    
+    Ok(sanitized)
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+fn invalid(reason: String) -> EnochianError {
+    EnochianError::InvalidChunks { reason }
+}
+
+fn invalid_content(reason: String) -> EnochianError {
+    EnochianError::InvalidInscriptionContent { reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn payload(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 31 % 251) as u8).collect()
+    }
+    
+    #[test]
+    fn test_split_and_reassemble_large_payload() {
+        let data = payload(3 * 1024 * 1024);
+        let mut chunks = split_for_ordinals(&data);
+        
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks.iter().all(|chunk| chunk.total == 4));
+        assert!(chunks.iter().all(|chunk| chunk.data.len() <= crate::constants::MAX_ORDINALS_SIZE));
+        
+        chunks.reverse();
+        assert_eq!(reassemble(&chunks).unwrap(), data);
+        
+        let empty = split_for_ordinals(&[]);
+        assert_eq!(empty.len(), 1);
+        assert!(reassemble(&empty).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_reassembly_rejects_bad_chunks() {
+        let chunks = split_for_ordinals(&payload(3 * 1024 * 1024));
+        
+        let missing: Vec<OrdinalChunk> = chunks.iter().filter(|c| c.index != 2).cloned().collect();
+        match reassemble(&missing) {
+            Err(EnochianError::InvalidChunks { reason }) => assert_eq!(reason, "missing chunks 2"),
+            other => panic!("expected missing chunk error, got {:?}", other),
+        }
+        
+        let mut duplicate = chunks.clone();
+        duplicate.push(chunks[1].clone());
+        assert!(reassemble(&duplicate).is_err());
+        
+        let mut corrupt = chunks.clone();
+        corrupt[0].data[0] ^= 0xff;
+        assert!(reassemble(&corrupt).is_err());
+        
+        let mut foreign = chunks.clone();
+        foreign[3] = split_for_ordinals(&payload(10)).remove(0);
+        assert!(reassemble(&foreign).is_err());
+        
+        assert!(reassemble(&[]).is_err());
+        
+        // A forged total is refused before anything is allocated for it
+        let mut forged = chunks[0].clone();
+        forged.total = u32::MAX;
+        match reassemble(&[forged]) {
+            Err(EnochianError::InvalidChunks { reason }) => assert_eq!(reason, format!("{} chunks exceeds limit of {}", u32::MAX, MAX_ORDINAL_CHUNKS)),
+            other => panic!("expected chunk limit error, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_sanitize_for_inscription() {
+        let cleaned = sanitize_for_inscription("  Call of\u{7} the\u{a0}Aethyr \r\nZAX\u{1b}[0m\t\r\n\n").unwrap();
+        assert_eq!(cleaned, "Call of the Aethyr\nZAX[0m");
+        assert_eq!(sanitize_for_inscription("").unwrap(), "");
+        
+        match sanitize_for_inscription("OIP TEAA\0PDOCE") {
+            Err(EnochianError::InvalidInscriptionContent { reason }) => assert_eq!(reason, "null byte at offset 8"),
+            other => panic!("expected null byte rejection, got {:?}", other),
+        }
+        let lossy = String::from_utf8_lossy(b"MADRIAX \xff\xfe DS PRAF").into_owned();
+        assert!(sanitize_for_inscription(&lossy).is_err());
+        
+        let oversized = "a".repeat(crate::constants::MAX_ORDINALS_SIZE + 1);
+        assert!(sanitize_for_inscription(&oversized).is_err());
+        
+        // Content that is compressed first is only cleaned
+        assert_eq!(clean_for_inscription(&format!("{} \r\n", oversized)).unwrap(), oversized);
+        assert!(clean_for_inscription("OIP TEAA\0PDOCE").is_err());
+    }
+}
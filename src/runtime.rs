@@ -0,0 +1,178 @@
+//! Time and randomness sources and the state sink injected into `EnochianCore`
+//!
+//! Standalone play uses the system clock and operating system randomness.
+//! Tests and P2P nodes inject `FixedClock` and `SeededRandom` instead, so any
+//! two cores given the same sources compute the same player states. Server
+//! deployments inject a `StateSink` for `flush` and `shutdown` to persist to.
+//! The story engine's state manager takes the same `Clock` for the timestamps
+//! and block heights it records.
+
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use crate::core::GameState;
+use crate::journal::JournalEntry;
+use crate::Result;
+
+/// Bitcoin block height used as the reference point for estimated heights
+pub const REFERENCE_BLOCK_HEIGHT: u64 = 800_000;
+
+/// Unix time at which `REFERENCE_BLOCK_HEIGHT` was mined
+pub const REFERENCE_BLOCK_TIME: i64 = 1_690_168_629;
+
+/// Bitcoin's target interval between blocks, in seconds
+pub const TARGET_BLOCK_INTERVAL: i64 = 600;
+
+/// Source of the current time
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Current time
+    fn now(&self) -> DateTime<Utc>;
+    
+    /// Current Bitcoin block height. Estimated from `now` and the target
+    /// block interval; clocks backed by a Bitcoin node report the real height.
+    fn block_height(&self) -> u64 {
+        let elapsed = self.now().timestamp().saturating_sub(REFERENCE_BLOCK_TIME).max(0);
+        REFERENCE_BLOCK_HEIGHT + (elapsed / TARGET_BLOCK_INTERVAL) as u64
+    }
+}
+
+/// Source of random numbers
+pub trait RandomSource: std::fmt::Debug + Send + Sync {
+    /// Next random 64-bit value
+    fn next_u64(&self) -> u64;
+}
+
+/// Clock reading the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that stands still until advanced
+#[derive(Debug)]
+pub struct FixedClock {
+    /// Milliseconds since the Unix epoch
+    millis: AtomicI64,
+}
+
+impl FixedClock {
+    /// Clock stopped at `time`
+    pub fn new(time: DateTime<Utc>) -> Self {
+        FixedClock { millis: AtomicI64::new(time.timestamp_millis()) }
+    }
+    
+    /// Clock stopped at `seconds` after the Unix epoch
+    pub fn at_unix(seconds: i64) -> Self {
+        FixedClock { millis: AtomicI64::new(seconds.saturating_mul(1000)) }
+    }
+    
+    /// Clock stopped at `millis` milliseconds after the Unix epoch
+    pub fn at_unix_millis(millis: i64) -> Self {
+        FixedClock { millis: AtomicI64::new(millis) }
+    }
+    
+    /// Stop the clock at `time`
+    pub fn set(&self, time: DateTime<Utc>) {
+        self.millis.store(time.timestamp_millis(), Ordering::Relaxed);
+    }
+    
+    /// Move the clock forward by `seconds`
+    pub fn advance(&self, seconds: i64) {
+        self.millis.fetch_add(seconds.saturating_mul(1000), Ordering::Relaxed);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.millis.load(Ordering::Relaxed)).unwrap_or_default()
+    }
+}
+
+/// Randomness from the operating system
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRandom;
+
+impl RandomSource for SystemRandom {
+    fn next_u64(&self) -> u64 {
+        rand::random()
+    }
+}
+
+/// Reproducible SplitMix64 sequence from a seed
+#[derive(Debug)]
+pub struct SeededRandom {
+    state: AtomicU64,
+}
+
+impl SeededRandom {
+    /// Sequence starting from `seed`
+    pub fn new(seed: u64) -> Self {
+        SeededRandom { state: AtomicU64::new(seed) }
+    }
+}
+
+impl RandomSource for SeededRandom {
+    fn next_u64(&self) -> u64 {
+        const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut z = self.state.fetch_add(GAMMA, Ordering::Relaxed).wrapping_add(GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Destination `EnochianCore::flush` persists pending state to
+pub trait StateSink: std::fmt::Debug + Send + Sync {
+    /// Persist a player state that changed since it was last persisted
+    fn persist_player(&self, state: &GameState) -> Result<()>;
+    
+    /// Persist journal entries recorded since the last flush, oldest first
+    fn persist_journal(&self, _entries: &[JournalEntry]) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Sink that discards everything, for cores kept only in memory
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullSink;
+
+impl StateSink for NullSink {
+    fn persist_player(&self, _state: &GameState) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_fixed_sources_are_reproducible() {
+        let clock = FixedClock::at_unix(1_700_000_000);
+        assert_eq!(clock.now(), clock.now());
+        clock.advance(600);
+        assert_eq!(clock.now().timestamp(), 1_700_000_600);
+        
+        let (a, b) = (SeededRandom::new(42), SeededRandom::new(42));
+        let sequence: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        assert_eq!(sequence, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(sequence[0], sequence[1]);
+        assert_ne!(sequence[0], SeededRandom::new(43).next_u64());
+    }
+    
+    #[test]
+    fn test_block_height_follows_clock() {
+        let clock = FixedClock::at_unix(REFERENCE_BLOCK_TIME);
+        assert_eq!(clock.block_height(), REFERENCE_BLOCK_HEIGHT);
+        clock.advance(TARGET_BLOCK_INTERVAL - 1);
+        assert_eq!(clock.block_height(), REFERENCE_BLOCK_HEIGHT);
+        clock.advance(1);
+        assert_eq!(clock.block_height(), REFERENCE_BLOCK_HEIGHT + 1);
+        
+        assert_eq!(FixedClock::at_unix(0).block_height(), REFERENCE_BLOCK_HEIGHT);
+        assert!(SystemClock.block_height() > REFERENCE_BLOCK_HEIGHT);
+    }
+}
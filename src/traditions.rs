@@ -1,8 +1,12 @@
 //! Tradition management system for the 26 sacred traditions
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use crate::{Result, EnochianError};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use crate::{Mastery, Result, EnochianError};
+
+/// Tolerance for tradition weights summing to 1.0
+pub const WEIGHT_TOTAL_EPSILON: f64 = 1e-12;
 
 /// Tradition data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +37,67 @@ pub struct Tradition {
     pub core_principles: Vec<String>,
 }
 
+/// Foundation required before mastery in a tradition may grow past a ceiling
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MasteryPrerequisite {
+    /// Foundation tradition
+    pub tradition: String,
+    /// Mastery required in the foundation tradition
+    pub required_level: f64,
+    /// Mastery ceiling in the gated tradition until the foundation is met
+    pub gated_above: f64,
+}
+
+/// Lowest synergy derived for a pair of traditions with nothing in common
+pub const SYNERGY_FLOOR: f64 = 0.3;
+
+/// Synergy contributed by each key concept two traditions share
+const SHARED_CONCEPT_SYNERGY: f64 = 0.12;
+/// Cap on the synergy contributed by shared key concepts
+const MAX_SHARED_CONCEPT_SYNERGY: f64 = 0.36;
+/// Synergy contributed by each historical figure two traditions share
+const SHARED_FIGURE_SYNERGY: f64 = 0.1;
+/// Cap on the synergy contributed by shared historical figures
+const MAX_SHARED_FIGURE_SYNERGY: f64 = 0.2;
+/// Synergy contributed by traditions from the same historical period
+const PERIOD_SYNERGY: f64 = 0.14;
+/// Years apart at which historical periods stop contributing synergy
+const PERIOD_WINDOW_YEARS: f64 = 400.0;
+
+/// Largest combination size `strong_combinations` will enumerate
+pub const MAX_COMBINATION_SIZE: usize = 5;
+
+/// Maximum number of combinations returned by `strong_combinations`
+pub const MAX_COMBINATION_RESULTS: usize = 100;
+
+/// A single factor contributing to the synergy between two traditions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergyFactor {
+    /// Factor kind (shared_concept, shared_figure, period_proximity)
+    pub factor: String,
+    /// What matched, e.g. the shared concept
+    pub detail: String,
+    /// Synergy added by this factor
+    pub contribution: f64,
+}
+
+/// Breakdown of how the synergy between two traditions was reached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergyExplanation {
+    /// First tradition
+    pub tradition1: String,
+    /// Second tradition
+    pub tradition2: String,
+    /// Synergy derived from shared concepts, figures and periods
+    pub derived_synergy: f64,
+    /// Hand-tuned synergy replacing the derived value, if any
+    pub override_synergy: Option<f64>,
+    /// Effective synergy
+    pub synergy: f64,
+    /// Factors contributing to the derived synergy
+    pub factors: Vec<SynergyFactor>,
+}
+
 /// Tradition manager
 #[derive(Debug, Clone)]
 pub struct TraditionManager {
@@ -42,6 +107,8 @@ pub struct TraditionManager {
     weights: HashMap<String, f64>,
     /// Synergy matrix
     synergy_matrix: HashMap<String, HashMap<String, f64>>,
+    /// Mastery prerequisites keyed by gated tradition
+    mastery_prerequisites: HashMap<String, Vec<MasteryPrerequisite>>,
 }
 
 impl Default for TraditionManager {
@@ -57,11 +124,13 @@ impl TraditionManager {
             traditions: HashMap::new(),
             weights: HashMap::new(),
             synergy_matrix: HashMap::new(),
+            mastery_prerequisites: HashMap::new(),
         };
         
         manager.initialize_traditions();
         manager.initialize_weights();
         manager.initialize_synergies();
+        manager.initialize_mastery_prerequisites();
         manager
     }
     
@@ -70,9 +139,11 @@ impl TraditionManager {
         self.traditions.get(name)
     }
     
-    /// Get all tradition names
+    /// Get all tradition names, sorted
     pub fn get_tradition_names(&self) -> Vec<String> {
-        self.traditions.keys().cloned().collect()
+        let mut names: Vec<String> = self.traditions.keys().cloned().collect();
+        names.sort();
+        names
     }
     
     /// Get tradition count
@@ -85,6 +156,57 @@ impl TraditionManager {
         self.weights.get(name).copied().unwrap_or(0.0)
     }
     
+    /// Set tradition weight
+    pub fn set_tradition_weight(&mut self, name: &str, weight: f64) {
+        self.weights.insert(name.to_string(), weight);
+    }
+    
+    /// Snapshot of every tradition weight, ordered by tradition name
+    pub fn weights(&self) -> BTreeMap<String, f64> {
+        self.weights.iter().map(|(name, weight)| (name.clone(), *weight)).collect()
+    }
+    
+    /// Sum of all tradition weights, added in tradition name order so the
+    /// result doesn't depend on hash map iteration order
+    pub fn total_weight(&self) -> f64 {
+        self.weights().values().sum()
+    }
+    
+    /// Check the weights sum to 1.0 (within `WEIGHT_TOTAL_EPSILON`) and that
+    /// Enochian holds exactly `constants::ENOCHIAN_WEIGHTING`
+    pub fn validate_weights(&self) -> Result<()> {
+        let total = self.total_weight();
+        if (total - 1.0).abs() > WEIGHT_TOTAL_EPSILON {
+            return Err(EnochianError::SacredConstraintViolation {
+                constraint: format!("Tradition weights must sum to 1.0, found {}", total),
+            });
+        }
+        
+        let enochian = self.get_tradition_weight("Enochian");
+        if enochian != crate::constants::ENOCHIAN_WEIGHTING {
+            return Err(EnochianError::SacredConstraintViolation {
+                constraint: format!(
+                    "Enochian weighting must be {}, found {}",
+                    crate::constants::ENOCHIAN_WEIGHTING,
+                    enochian
+                ),
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Remove a tradition and its weight, synergies and prerequisites
+    pub fn remove_tradition(&mut self, name: &str) -> Option<Tradition> {
+        self.weights.remove(name);
+        self.synergy_matrix.remove(name);
+        for synergies in self.synergy_matrix.values_mut() {
+            synergies.remove(name);
+        }
+        self.mastery_prerequisites.remove(name);
+        self.traditions.remove(name)
+    }
+    
     /// Calculate tradition compatibility
     pub fn calculate_compatibility(&self, tradition1: &str, tradition2: &str) -> f64 {
         if let Some(tradition) = self.traditions.get(tradition1) {
@@ -103,6 +225,61 @@ impl TraditionManager {
             .unwrap_or(0.0)
     }
     
+    /// Explain the synergy between two traditions
+    ///
+    /// Synergy is derived from shared key concepts, shared historical figures
+    /// and how close the traditions' historical periods are, starting from
+    /// `SYNERGY_FLOOR`. Hand-tuned pairs override the derived value.
+    pub fn explain_synergy(&self, tradition1: &str, tradition2: &str) -> Result<SynergyExplanation> {
+        let lookup = |name: &str| self.traditions.get(name)
+            .ok_or_else(|| EnochianError::TraditionNotSupported {
+                tradition: name.to_string(),
+            });
+        let first = lookup(tradition1)?;
+        let second = lookup(tradition2)?;
+        
+        let mut factors = capped_factors(
+            "shared_concept",
+            shared_entries(&first.key_concepts, &second.key_concepts),
+            SHARED_CONCEPT_SYNERGY,
+            MAX_SHARED_CONCEPT_SYNERGY,
+        );
+        factors.extend(capped_factors(
+            "shared_figure",
+            shared_entries(&first.historical_figures, &second.historical_figures),
+            SHARED_FIGURE_SYNERGY,
+            MAX_SHARED_FIGURE_SYNERGY,
+        ));
+        
+        if let (Some(year1), Some(year2)) = (
+            approximate_period_year(&first.historical_period),
+            approximate_period_year(&second.historical_period),
+        ) {
+            let years_apart = (year1 - year2).abs();
+            let contribution = PERIOD_SYNERGY * (1.0 - years_apart / PERIOD_WINDOW_YEARS).max(0.0);
+            if contribution > 0.0 {
+                factors.push(SynergyFactor {
+                    factor: "period_proximity".to_string(),
+                    detail: format!("{} / {} (~{:.0} years apart)",
+                        first.historical_period, second.historical_period, years_apart),
+                    contribution,
+                });
+            }
+        }
+        
+        let derived_synergy = (SYNERGY_FLOOR + factors.iter().map(|f| f.contribution).sum::<f64>()).min(1.0);
+        let override_synergy = synergy_override(tradition1, tradition2);
+        
+        Ok(SynergyExplanation {
+            tradition1: tradition1.to_string(),
+            tradition2: tradition2.to_string(),
+            derived_synergy,
+            override_synergy,
+            synergy: override_synergy.unwrap_or(derived_synergy),
+            factors,
+        })
+    }
+    
     /// Validate tradition combination
     pub fn validate_combination(&self, traditions: &[String]) -> Result<f64> {
         if traditions.is_empty() {
@@ -157,6 +334,46 @@ impl TraditionManager {
         recommendations
     }
     
+    /// Enumerate combinations of `size` traditions whose average pairwise
+    /// synergy is at least `min_avg_synergy`, strongest first
+    ///
+    /// Sizes outside 2..=`MAX_COMBINATION_SIZE` yield nothing, and at most
+    /// `MAX_COMBINATION_RESULTS` combinations are returned.
+    pub fn strong_combinations(&self, size: usize, min_avg_synergy: f64) -> Vec<(Vec<String>, f64)> {
+        self.find_combinations(size, min_avg_synergy, false)
+    }
+    
+    /// Like `strong_combinations`, but only combinations including Enochian
+    pub fn strong_enochian_combinations(&self, size: usize, min_avg_synergy: f64) -> Vec<(Vec<String>, f64)> {
+        self.find_combinations(size, min_avg_synergy, true)
+    }
+    
+    /// Get mastery prerequisites for a tradition
+    pub fn get_mastery_prerequisites(&self, tradition: &str) -> &[MasteryPrerequisite] {
+        self.mastery_prerequisites.get(tradition)
+            .map(|prerequisites| prerequisites.as_slice())
+            .unwrap_or(&[])
+    }
+    
+    /// Add a mastery prerequisite gating a tradition
+    pub fn add_mastery_prerequisite(&mut self, tradition: &str, prerequisite: MasteryPrerequisite) {
+        self.mastery_prerequisites
+            .entry(tradition.to_string())
+            .or_default()
+            .push(prerequisite);
+    }
+    
+    /// Calculate the highest mastery a player may reach in a tradition given
+    /// their current mastery in its foundation traditions
+    pub fn get_mastery_ceiling(&self, tradition: &str, player_mastery: &BTreeMap<String, Mastery>) -> f64 {
+        self.get_mastery_prerequisites(tradition).iter()
+            .filter(|prerequisite| {
+                player_mastery.get(&prerequisite.tradition).copied().unwrap_or_default().value() < prerequisite.required_level
+            })
+            .map(|prerequisite| prerequisite.gated_above)
+            .fold(1.0, f64::min)
+    }
+    
     fn initialize_traditions(&mut self) {
         // 1. Enochian (Primary tradition - 60% weight)
         self.traditions.insert("Enochian".to_string(), Tradition {
@@ -417,21 +634,27 @@ impl TraditionManager {
     
     fn initialize_weights(&mut self) {
         // Sacred constraint: Enochian must have 60% weighting
-        self.weights.insert("Enochian".to_string(), 0.6);
+        self.weights.insert("Enochian".to_string(), crate::constants::ENOCHIAN_WEIGHTING);
         self.weights.insert("Hermetic_Qabalah".to_string(), 0.15);
         self.weights.insert("Thelema".to_string(), 0.08);
         self.weights.insert("Golden_Dawn".to_string(), 0.07);
         self.weights.insert("Chaos_Magic".to_string(), 0.05);
         
-        // Remaining traditions share the remaining 5%
-        let remaining_weight = 0.05;
-        let remaining_count = self.traditions.len() - 5;
-        let individual_weight = remaining_weight / remaining_count as f64;
+        // Remaining traditions share the remaining 5%, the last by name taking
+        // whatever is left so rounding never moves the total off 1.0
+        let mut remaining: Vec<String> = self.traditions.keys()
+            .filter(|name| !self.weights.contains_key(*name))
+            .cloned()
+            .collect();
+        remaining.sort();
         
-        for name in self.traditions.keys() {
-            if !self.weights.contains_key(name) {
-                self.weights.insert(name.clone(), individual_weight);
+        if let Some(last) = remaining.pop() {
+            let individual_weight = (1.0 - self.total_weight()) / (remaining.len() + 1) as f64;
+            for name in remaining {
+                self.weights.insert(name, individual_weight);
             }
+            let remainder = 1.0 - self.total_weight();
+            self.weights.insert(last, remainder);
         }
     }
     
@@ -451,21 +674,217 @@ impl TraditionManager {
         }
     }
     
+    fn initialize_mastery_prerequisites(&mut self) {
+        // Enochian is the foundation for the traditions built around it
+        self.add_mastery_prerequisite("Hermetic_Qabalah", MasteryPrerequisite {
+            tradition: "Enochian".to_string(),
+            required_level: 0.3,
+            gated_above: 0.3,
+        });
+        self.add_mastery_prerequisite("Golden_Dawn", MasteryPrerequisite {
+            tradition: "Hermetic_Qabalah".to_string(),
+            required_level: 0.3,
+            gated_above: 0.3,
+        });
+        self.add_mastery_prerequisite("Thelema", MasteryPrerequisite {
+            tradition: "Golden_Dawn".to_string(),
+            required_level: 0.3,
+            gated_above: 0.3,
+        });
+    }
+    
+    fn find_combinations(&self, size: usize, min_avg_synergy: f64, require_enochian: bool) -> Vec<(Vec<String>, f64)> {
+        if !(2..=MAX_COMBINATION_SIZE).contains(&size) {
+            return Vec::new();
+        }
+        
+        let mut names = self.get_tradition_names();
+        names.sort();
+        if require_enochian {
+            // Enochian first so every search starts from it
+            names.retain(|name| name != "Enochian");
+            names.insert(0, "Enochian".to_string());
+        }
+        
+        let pair_count = (size * (size - 1) / 2) as f64;
+        let max_synergy = self.synergy_matrix.values()
+            .flat_map(|synergies| synergies.values())
+            .copied()
+            .fold(0.0, f64::max);
+        
+        let mut results = BinaryHeap::with_capacity(MAX_COMBINATION_RESULTS + 1);
+        let mut chosen = Vec::with_capacity(size);
+        self.extend_combination(&names, 0, size, 0.0, pair_count, max_synergy, min_avg_synergy, require_enochian, &mut chosen, &mut results);
+        
+        results.into_sorted_vec()
+            .into_iter()
+            .map(|ranked| (ranked.combination, ranked.average))
+            .collect()
+    }
+    
+    /// Depth-first search over combinations, keeping the best
+    /// `MAX_COMBINATION_RESULTS` in `results`. Branches are pruned when they
+    /// cannot reach the floor, or once `results` is full the weakest kept
+    /// combination, even if every remaining pair had the maximum synergy
+    fn extend_combination(
+        &self,
+        names: &[String],
+        next: usize,
+        size: usize,
+        synergy_sum: f64,
+        pair_count: f64,
+        max_synergy: f64,
+        min_avg_synergy: f64,
+        require_enochian: bool,
+        chosen: &mut Vec<usize>,
+        results: &mut BinaryHeap<RankedCombination>,
+    ) {
+        let floor = match results.peek() {
+            Some(weakest) if results.len() == MAX_COMBINATION_RESULTS => min_avg_synergy.max(weakest.average),
+            _ => min_avg_synergy,
+        };
+        
+        if chosen.len() == size {
+            let average = synergy_sum / pair_count;
+            if average >= floor {
+                let mut combination: Vec<String> = chosen.iter().map(|&i| names[i].clone()).collect();
+                combination.sort();
+                results.push(RankedCombination { combination, average });
+                if results.len() > MAX_COMBINATION_RESULTS {
+                    results.pop();
+                }
+            }
+            return;
+        }
+        
+        let remaining_pairs = pair_count - (chosen.len() * chosen.len().saturating_sub(1) / 2) as f64;
+        if (synergy_sum + remaining_pairs * max_synergy) / pair_count < floor {
+            return;
+        }
+        
+        // With Enochian required it sits at index 0 and must be picked first
+        let last = if require_enochian && chosen.is_empty() { 1 } else { names.len() };
+        for i in next..last {
+            let added: f64 = chosen.iter().map(|&j| self.get_synergy(&names[i], &names[j])).sum();
+            chosen.push(i);
+            self.extend_combination(names, i + 1, size, synergy_sum + added, pair_count, max_synergy, min_avg_synergy, require_enochian, chosen, results);
+            chosen.pop();
+        }
+    }
+    
     fn calculate_base_synergy(&self, tradition1: &str, tradition2: &str) -> f64 {
-        // High synergy combinations
-        match (tradition1, tradition2) {
-            ("Enochian", "Hermetic_Qabalah") | ("Hermetic_Qabalah", "Enochian") => 0.9,
-            ("Enochian", "Golden_Dawn") | ("Golden_Dawn", "Enochian") => 0.85,
-            ("Enochian", "Thelema") | ("Thelema", "Enochian") => 0.8,
-            ("Hermetic_Qabalah", "Golden_Dawn") | ("Golden_Dawn", "Hermetic_Qabalah") => 0.9,
-            ("Hermetic_Qabalah", "Thelema") | ("Thelema", "Hermetic_Qabalah") => 0.85,
-            ("Golden_Dawn", "Thelema") | ("Thelema", "Golden_Dawn") => 0.8,
-            ("Chaos_Magic", "Thelema") | ("Thelema", "Chaos_Magic") => 0.75,
-            ("Alchemy", "Hermetic_Qabalah") | ("Hermetic_Qabalah", "Alchemy") => 0.8,
-            ("Astrology", "Hermetic_Qabalah") | ("Hermetic_Qabalah", "Astrology") => 0.75,
-            ("Tarot", "Golden_Dawn") | ("Golden_Dawn", "Tarot") => 0.8,
-            _ => 0.5, // Default neutral synergy
+        self.explain_synergy(tradition1, tradition2)
+            .map(|explanation| explanation.synergy)
+            .unwrap_or(SYNERGY_FLOOR)
+    }
+}
+
+/// A combination found by `find_combinations`, ordered weakest first so a
+/// `BinaryHeap` keeps the weakest kept result on top
+#[derive(Debug, PartialEq)]
+struct RankedCombination {
+    combination: Vec<String>,
+    average: f64,
+}
+
+impl Eq for RankedCombination {}
+
+impl Ord for RankedCombination {
+    /// Lower average synergy, then later names, rank as weaker (greater)
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.average.total_cmp(&self.average)
+            .then_with(|| self.combination.cmp(&other.combination))
+    }
+}
+
+impl PartialOrd for RankedCombination {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hand-tuned high synergy combinations
+fn synergy_override(tradition1: &str, tradition2: &str) -> Option<f64> {
+    let synergy = match (tradition1, tradition2) {
+        ("Enochian", "Hermetic_Qabalah") | ("Hermetic_Qabalah", "Enochian") => 0.9,
+        ("Enochian", "Golden_Dawn") | ("Golden_Dawn", "Enochian") => 0.85,
+        ("Enochian", "Thelema") | ("Thelema", "Enochian") => 0.8,
+        ("Hermetic_Qabalah", "Golden_Dawn") | ("Golden_Dawn", "Hermetic_Qabalah") => 0.9,
+        ("Hermetic_Qabalah", "Thelema") | ("Thelema", "Hermetic_Qabalah") => 0.85,
+        ("Golden_Dawn", "Thelema") | ("Thelema", "Golden_Dawn") => 0.8,
+        ("Chaos_Magic", "Thelema") | ("Thelema", "Chaos_Magic") => 0.75,
+        ("Alchemy", "Hermetic_Qabalah") | ("Hermetic_Qabalah", "Alchemy") => 0.8,
+        ("Astrology", "Hermetic_Qabalah") | ("Hermetic_Qabalah", "Astrology") => 0.75,
+        ("Tarot", "Golden_Dawn") | ("Golden_Dawn", "Tarot") => 0.8,
+        _ => return None,
+    };
+    Some(synergy)
+}
+
+/// Entries present in both lists, compared case-insensitively
+fn shared_entries(first: &[String], second: &[String]) -> Vec<String> {
+    let mut shared: Vec<String> = first.iter()
+        .filter(|entry| second.iter().any(|other| other.eq_ignore_ascii_case(entry)))
+        .cloned()
+        .collect();
+    shared.sort();
+    shared.dedup();
+    shared
+}
+
+/// One factor per matched entry, stopping once the cap is reached
+fn capped_factors(factor: &str, entries: Vec<String>, each: f64, cap: f64) -> Vec<SynergyFactor> {
+    let mut total = 0.0;
+    let mut factors = Vec::new();
+    for detail in entries {
+        let contribution = each.min(cap - total);
+        if contribution <= f64::EPSILON {
+            break;
         }
+        total += contribution;
+        factors.push(SynergyFactor {
+            factor: factor.to_string(),
+            detail,
+            contribution,
+        });
+    }
+    factors
+}
+
+/// Approximate midpoint year of a historical period such as "1582-1587",
+/// "Late 19th century" or "Medieval-Renaissance"
+fn approximate_period_year(period: &str) -> Option<f64> {
+    let years: Vec<f64> = period.split('-')
+        .filter_map(|part| {
+            let part = part.trim().to_lowercase();
+            if let Ok(year) = part.parse::<f64>() {
+                return Some(year);
+            }
+            match part.as_str() {
+                "ancient" => return Some(-500.0),
+                "medieval" => return Some(1000.0),
+                "renaissance" => return Some(1500.0),
+                _ => {}
+            }
+            
+            let century: f64 = part.split_whitespace()
+                .find(|word| word.ends_with("st") || word.ends_with("nd") || word.ends_with("rd") || word.ends_with("th"))
+                .and_then(|word| word.trim_end_matches(char::is_alphabetic).parse().ok())?;
+            let offset = if part.starts_with("early") {
+                25.0
+            } else if part.starts_with("late") {
+                75.0
+            } else {
+                50.0
+            };
+            Some((century - 1.0) * 100.0 + offset)
+        })
+        .collect();
+    
+    if years.is_empty() {
+        None
+    } else {
+        Some(years.iter().sum::<f64>() / years.len() as f64)
     }
 }
 
@@ -479,3 +898,139 @@ pub fn get_tradition_weight(tradition: &str) -> f64 {
     let manager = TraditionManager::new();
     manager.get_tradition_weight(tradition)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_tradition(name: &str, period: &str, concepts: &[&str], figures: &[&str]) -> Tradition {
+        Tradition {
+            name: name.to_string(),
+            description: String::new(),
+            historical_period: period.to_string(),
+            key_concepts: concepts.iter().map(|c| c.to_string()).collect(),
+            primary_sources: Vec::new(),
+            historical_figures: figures.iter().map(|f| f.to_string()).collect(),
+            practices: Vec::new(),
+            compatibility: HashMap::new(),
+            authenticity_weight: 0.7,
+            minimum_threshold: 0.7,
+            sacred_symbols: Vec::new(),
+            core_principles: Vec::new(),
+        }
+    }
+    
+    #[test]
+    fn test_default_weights_sum_to_one() {
+        let mut manager = TraditionManager::new();
+        let weights = manager.weights();
+        assert_eq!(weights.len(), 26);
+        assert_eq!(weights["Enochian"], crate::constants::ENOCHIAN_WEIGHTING);
+        assert!((manager.total_weight() - 1.0).abs() <= WEIGHT_TOTAL_EPSILON);
+        assert!(manager.validate_weights().is_ok());
+        
+        // Minor traditions share the remaining 5% evenly, up to rounding
+        assert!((weights["Alchemy"] - 0.05 / 21.0).abs() < 1e-12);
+        assert!((weights["Zen_Buddhism"] - 0.05 / 21.0).abs() < 1e-12);
+        
+        manager.set_tradition_weight("Alchemy", 0.1);
+        assert!(manager.validate_weights().is_err());
+        
+        let mut manager = TraditionManager::new();
+        manager.set_tradition_weight("Enochian", 0.55);
+        manager.set_tradition_weight("Hermetic_Qabalah", 0.2);
+        assert!(matches!(manager.validate_weights(), Err(EnochianError::SacredConstraintViolation { .. })));
+    }
+    
+    #[test]
+    fn test_shared_concepts_raise_synergy() {
+        let mut manager = TraditionManager::new();
+        manager.traditions.insert("Solar_Cult".to_string(),
+            test_tradition("Solar_Cult", "Various", &["Sun worship", "Divine emanation"], &[]));
+        manager.traditions.insert("Light_Path".to_string(),
+            test_tradition("Light_Path", "Various", &["sun worship", "Divine emanation", "Fasting"], &[]));
+        
+        let explanation = manager.explain_synergy("Solar_Cult", "Light_Path").unwrap();
+        assert!(explanation.synergy > 0.5);
+        assert!(explanation.override_synergy.is_none());
+        let details: Vec<&str> = explanation.factors.iter().map(|f| f.detail.as_str()).collect();
+        assert_eq!(details, vec!["Divine emanation", "Sun worship"]);
+        assert!(explanation.factors.iter().all(|f| f.factor == "shared_concept"));
+    }
+    
+    #[test]
+    fn test_unrelated_traditions_near_floor() {
+        let manager = TraditionManager::new();
+        let explanation = manager.explain_synergy("Runes", "Sufism").unwrap();
+        assert!(explanation.factors.is_empty());
+        assert!((explanation.synergy - SYNERGY_FLOOR).abs() < 1e-9);
+        assert_eq!(manager.get_synergy("Runes", "Sufism"), explanation.synergy);
+    }
+    
+    #[test]
+    fn test_hand_tuned_pairs_override_derived_synergy() {
+        let manager = TraditionManager::new();
+        let explanation = manager.explain_synergy("Hermetic_Qabalah", "Golden_Dawn").unwrap();
+        assert_eq!(explanation.override_synergy, Some(0.9));
+        assert_eq!(explanation.synergy, 0.9);
+        // Both draw on Mathers
+        assert!(explanation.factors.iter().any(|f| f.factor == "shared_figure"));
+        assert!(explanation.derived_synergy < explanation.synergy);
+        assert_eq!(manager.get_synergy("Golden_Dawn", "Hermetic_Qabalah"), 0.9);
+    }
+    
+    #[test]
+    fn test_strong_combinations() {
+        let manager = TraditionManager::new();
+        let golden_trio = vec![
+            "Enochian".to_string(),
+            "Golden_Dawn".to_string(),
+            "Hermetic_Qabalah".to_string(),
+        ];
+        
+        let combinations = manager.strong_combinations(3, 0.8);
+        assert!(!combinations.is_empty());
+        assert!(combinations.iter().any(|(combination, _)| *combination == golden_trio));
+        assert!(combinations.iter().all(|(_, synergy)| *synergy >= 0.8));
+        assert!(combinations.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(!combinations.iter().any(|(combination, _)| combination.contains(&"Runes".to_string())));
+        
+        let enochian = manager.strong_enochian_combinations(3, 0.8);
+        assert!(enochian.iter().all(|(combination, _)| combination.contains(&"Enochian".to_string())));
+        assert!(enochian.iter().any(|(combination, _)| *combination == golden_trio));
+        
+        // Weak builds only appear once the floor drops to their synergy
+        let weak_trio = vec!["Runes".to_string(), "Sufism".to_string(), "Voodoo".to_string()];
+        assert!(!manager.strong_combinations(3, 0.5).iter().any(|(combination, _)| *combination == weak_trio));
+        
+        assert!(manager.strong_combinations(1, 0.0).is_empty());
+        assert!(manager.strong_combinations(MAX_COMBINATION_SIZE + 1, 0.0).is_empty());
+        assert_eq!(manager.strong_combinations(2, 0.0).len(), MAX_COMBINATION_RESULTS);
+        
+        // The bounded search keeps exactly the best results of a full enumeration
+        let mut names = manager.get_tradition_names();
+        names.sort();
+        let mut every_pair = Vec::new();
+        for (i, first) in names.iter().enumerate() {
+            for second in &names[i + 1..] {
+                every_pair.push((vec![first.clone(), second.clone()], manager.get_synergy(first, second)));
+            }
+        }
+        every_pair.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        every_pair.truncate(MAX_COMBINATION_RESULTS);
+        assert_eq!(manager.strong_combinations(2, 0.0), every_pair);
+    }
+    
+    #[test]
+    fn test_close_periods_contribute_synergy() {
+        let manager = TraditionManager::new();
+        let explanation = manager.explain_synergy("Golden_Dawn", "Chaos_Magic").unwrap();
+        assert!(explanation.factors.iter().any(|f| f.factor == "period_proximity"));
+        assert!(explanation.synergy > SYNERGY_FLOOR);
+        
+        assert_eq!(approximate_period_year("1582-1587"), Some(1584.5));
+        assert_eq!(approximate_period_year("Late 19th century"), Some(1875.0));
+        assert_eq!(approximate_period_year("Various"), None);
+        assert!(manager.explain_synergy("Enochian", "Unknown").is_err());
+    }
+}
@@ -5,9 +5,17 @@ use wasm_bindgen::prelude::*;
 #[cfg(feature = "wasm")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "wasm")]
-use std::collections::HashMap;
+use std::cell::OnceCell;
 #[cfg(feature = "wasm")]
-use crate::{EnochianCore, SystemConfig, GameState, QuestData, AuthenticityScorer, Result};
+use std::collections::BTreeMap;
+#[cfg(feature = "wasm")]
+use std::sync::Arc;
+#[cfg(feature = "wasm")]
+use crate::{EnochianCore, SystemConfig, GameState, QuestChain, QuestData, StartingLoadout, AuthenticityScorer, Result};
+#[cfg(feature = "wasm")]
+use crate::{governors::{shared_manager, GovernorManager}, traditions::TraditionManager};
+#[cfg(feature = "wasm")]
+use crate::authenticity::{ContentType, LiveScore, TextEdit};
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
@@ -34,6 +42,8 @@ macro_rules! console_error {
 pub struct EnochianWasm {
     core: EnochianCore,
     authenticity_scorer: AuthenticityScorer,
+    tradition_manager: OnceCell<TraditionManager>,
+    governor_manager: OnceCell<Arc<GovernorManager>>,
     initialized: bool,
 }
 
@@ -51,6 +61,8 @@ impl EnochianWasm {
         EnochianWasm {
             core,
             authenticity_scorer,
+            tradition_manager: OnceCell::new(),
+            governor_manager: OnceCell::new(),
             initialized: false,
         }
     }
@@ -103,6 +115,26 @@ impl EnochianWasm {
         }
     }
     
+    #[wasm_bindgen]
+    pub fn create_player_with_loadout(&mut self, player_id: String, loadout_json: String) -> Result<String, JsValue> {
+        if !self.initialized {
+            return Err(JsValue::from_str("System not initialized"));
+        }
+        
+        let loadout: StartingLoadout = serde_json::from_str(&loadout_json)
+            .map_err(|e| JsValue::from_str(&format!("Loadout parsing error: {}", e)))?;
+        
+        match self.core.create_player_state_with_loadout(player_id, loadout) {
+            Ok(state) => {
+                match serde_json::to_string(state) {
+                    Ok(json) => Ok(json),
+                    Err(e) => Err(JsValue::from_str(&format!("Serialization error: {}", e)))
+                }
+            },
+            Err(e) => Err(JsValue::from_str(&format!("Player creation error: {}", e)))
+        }
+    }
+    
     #[wasm_bindgen]
     pub fn get_player_state(&self, player_id: String) -> Result<String, JsValue> {
         if !self.initialized {
@@ -120,8 +152,26 @@ impl EnochianWasm {
         }
     }
     
+    /// Player progression without financial fields, for leaderboards and sharing
+    #[wasm_bindgen]
+    pub fn get_public_player_state(&self, player_id: String) -> Result<String, JsValue> {
+        if !self.initialized {
+            return Err(JsValue::from_str("System not initialized"));
+        }
+        
+        match self.core.get_player_state(&player_id) {
+            Some(state) => {
+                match serde_json::to_string(&state.public_view()) {
+                    Ok(json) => Ok(json),
+                    Err(e) => Err(JsValue::from_str(&format!("Serialization error: {}", e)))
+                }
+            },
+            None => Err(JsValue::from_str("Player not found"))
+        }
+    }
+    
     #[wasm_bindgen]
-    pub fn register_quest(&mut self, quest_json: String) -> Result<(), JsValue> {
+    pub fn register_quest(&mut self, quest_json: String) -> Result<String, JsValue> {
         if !self.initialized {
             return Err(JsValue::from_str("System not initialized"));
         }
@@ -133,6 +183,36 @@ impl EnochianWasm {
             .map_err(|e| JsValue::from_str(&format!("Quest registration error: {}", e)))
     }
     
+    #[wasm_bindgen]
+    pub fn register_quest_chain(&mut self, chain_json: String) -> Result<(), JsValue> {
+        if !self.initialized {
+            return Err(JsValue::from_str("System not initialized"));
+        }
+        
+        let chain: QuestChain = serde_json::from_str(&chain_json)
+            .map_err(|e| JsValue::from_str(&format!("Quest chain parsing error: {}", e)))?;
+        
+        self.core.register_chain(chain)
+            .map_err(|e| JsValue::from_str(&format!("Quest chain registration error: {}", e)))
+    }
+    
+    #[wasm_bindgen]
+    pub fn get_chain_progress(&self, player_id: String, chain_id: String) -> Result<String, JsValue> {
+        if !self.initialized {
+            return Err(JsValue::from_str("System not initialized"));
+        }
+        
+        match self.core.chain_progress(&player_id, &chain_id) {
+            Ok(progress) => {
+                match serde_json::to_string(&progress) {
+                    Ok(json) => Ok(json),
+                    Err(e) => Err(JsValue::from_str(&format!("Serialization error: {}", e)))
+                }
+            },
+            Err(e) => Err(JsValue::from_str(&format!("Chain progress error: {}", e)))
+        }
+    }
+    
     #[wasm_bindgen]
     pub fn start_quest(&mut self, player_id: String, quest_id: String) -> Result<(), JsValue> {
         if !self.initialized {
@@ -143,6 +223,23 @@ impl EnochianWasm {
             .map_err(|e| JsValue::from_str(&format!("Quest start error: {}", e)))
     }
     
+    #[wasm_bindgen]
+    pub fn make_quest_choice(&mut self, player_id: String, quest_id: String, choice_id: String) -> Result<String, JsValue> {
+        if !self.initialized {
+            return Err(JsValue::from_str("System not initialized"));
+        }
+        
+        match self.core.make_quest_choice(&player_id, &quest_id, &choice_id) {
+            Ok(consequences) => {
+                match serde_json::to_string(&consequences) {
+                    Ok(json) => Ok(json),
+                    Err(e) => Err(JsValue::from_str(&format!("Serialization error: {}", e)))
+                }
+            },
+            Err(e) => Err(JsValue::from_str(&format!("Quest choice error: {}", e)))
+        }
+    }
+    
     #[wasm_bindgen]
     pub fn complete_quest(&mut self, player_id: String, quest_id: String) -> Result<String, JsValue> {
         if !self.initialized {
@@ -172,7 +269,7 @@ impl EnochianWasm {
             Vec::new()
         };
         
-        match self.authenticity_scorer.calculate_authenticity(&content, &tradition, &sources, None) {
+        match self.authenticity_scorer.calculate_authenticity(&content, &tradition, ContentType::Codex, &sources) {
             Ok(score) => {
                 match serde_json::to_string(&score) {
                     Ok(json) => Ok(json),
@@ -222,30 +319,35 @@ impl EnochianWasm {
             .map_err(|e| JsValue::from_str(&format!("Sacred constraint violation: {}", e)))
     }
     
+    #[wasm_bindgen]
+    pub fn run_all_sacred_checks(&self) -> String {
+        let checks = crate::run_sacred_checks_with(self.traditions(), self.governors(), &self.authenticity_scorer);
+        serde_json::to_string(&checks).unwrap_or_else(|_| "[]".to_string())
+    }
+    
     #[wasm_bindgen]
     pub fn get_tradition_names(&self) -> Vec<String> {
-        crate::traditions::TraditionManager::new().get_tradition_names()
+        self.traditions().get_tradition_names()
     }
     
     #[wasm_bindgen]
     pub fn get_governor_names(&self) -> Vec<String> {
-        crate::governors::GovernorManager::new().get_governor_names()
+        self.governors().get_governor_names()
     }
     
     #[wasm_bindgen]
     pub fn get_tradition_weight(&self, tradition: String) -> f64 {
-        crate::traditions::TraditionManager::new().get_tradition_weight(&tradition)
+        self.traditions().get_tradition_weight(&tradition)
     }
     
     #[wasm_bindgen]
     pub fn calculate_tradition_compatibility(&self, tradition1: String, tradition2: String) -> f64 {
-        crate::traditions::TraditionManager::new().calculate_compatibility(&tradition1, &tradition2)
+        self.traditions().calculate_compatibility(&tradition1, &tradition2)
     }
     
     #[wasm_bindgen]
     pub fn get_governor_by_name(&self, name: String) -> Result<String, JsValue> {
-        let manager = crate::governors::GovernorManager::new();
-        match manager.get_governor_by_name(&name) {
+        match self.governors().get_governor_by_name(&name) {
             Some(governor) => {
                 match serde_json::to_string(governor) {
                     Ok(json) => Ok(json),
@@ -258,8 +360,7 @@ impl EnochianWasm {
     
     #[wasm_bindgen]
     pub fn find_governors_by_tradition(&self, tradition: String, min_affinity: f64) -> Result<String, JsValue> {
-        let manager = crate::governors::GovernorManager::new();
-        let governors = manager.find_governors_by_tradition(&tradition, min_affinity);
+        let governors = self.governors().find_governors_by_tradition(&tradition, min_affinity);
         
         let governor_names: Vec<String> = governors.iter().map(|g| g.name.clone()).collect();
         match serde_json::to_string(&governor_names) {
@@ -270,14 +371,15 @@ impl EnochianWasm {
     
     #[wasm_bindgen]
     pub fn get_build_metadata(&self) -> String {
+        let build = crate::build_info();
         serde_json::json!({
-            "version": crate::VERSION,
-            "build_time": crate::BUILD_TIME,
-            "git_hash": crate::GIT_HASH,
-            "architecture_version": crate::SACRED_ARCHITECTURE_VERSION,
-            "tradition_count": crate::TRADITION_COUNT,
-            "governor_count": crate::GOVERNOR_COUNT,
-            "aethyr_count": crate::AETHYR_COUNT,
+            "version": build.version,
+            "build_time": build.build_time,
+            "git_hash": build.git_hash,
+            "architecture_version": build.architecture_version,
+            "tradition_count": crate::constants::TRADITION_COUNT,
+            "governor_count": crate::constants::GOVERNOR_COUNT,
+            "aethyr_count": crate::constants::AETHYR_COUNT,
         }).to_string()
     }
     
@@ -285,6 +387,83 @@ impl EnochianWasm {
     pub fn is_initialized(&self) -> bool {
         self.initialized
     }
+    
+    #[wasm_bindgen]
+    pub fn memory_stats(&self) -> String {
+        let mut stats = self.core.memory_stats();
+        let cached_managers = self.tradition_manager.get().is_some() as u32
+            + self.governor_manager.get().is_some() as u32;
+        stats["cached_managers"] = serde_json::json!(cached_managers);
+        
+        // Linear memory is measured in 64KiB pages
+        #[cfg(target_arch = "wasm32")]
+        {
+            stats["linear_memory_bytes"] = serde_json::json!(std::arch::wasm32::memory_size(0) * 65536);
+        }
+        
+        stats.to_string()
+    }
+    
+    #[wasm_bindgen]
+    pub fn compact(&mut self) -> String {
+        let pruned_history = self.core.compact_history();
+        self.tradition_manager = OnceCell::new();
+        self.governor_manager = OnceCell::new();
+        
+        console_log!("Compacted {} state snapshots", pruned_history);
+        self.memory_stats()
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl EnochianWasm {
+    fn traditions(&self) -> &TraditionManager {
+        self.tradition_manager.get_or_init(TraditionManager::new)
+    }
+    
+    fn governors(&self) -> &GovernorManager {
+        self.governor_manager.get_or_init(shared_manager)
+    }
+}
+
+/// Authenticity scorer for live editing, updated one edit at a time
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub struct WasmLiveScorer {
+    live: LiveScore,
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl WasmLiveScorer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmLiveScorer {
+        WasmLiveScorer {
+            live: LiveScore::new(AuthenticityScorer::new()),
+        }
+    }
+    
+    /// Apply an edit, e.g. `{"type": "append", "text": "..."}` or
+    /// `{"type": "replace_range", "start": 0, "end": 4, "text": "..."}`,
+    /// with character offsets, and return the updated score
+    #[wasm_bindgen]
+    pub fn update(&mut self, edit_json: String) -> Result<f64, JsValue> {
+        let edit: TextEdit = serde_json::from_str(&edit_json)
+            .map_err(|e| JsValue::from_str(&format!("Edit parsing error: {}", e)))?;
+        
+        self.live.apply(edit)
+            .map_err(|e| JsValue::from_str(&format!("Live scoring error: {}", e)))
+    }
+    
+    #[wasm_bindgen]
+    pub fn current_score(&self) -> f64 {
+        self.live.current_score()
+    }
+    
+    #[wasm_bindgen]
+    pub fn content(&self) -> String {
+        self.live.content().to_string()
+    }
 }
 
 // Utility functions for WASM
@@ -303,19 +482,19 @@ pub fn init_logger() {
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn get_version() -> String {
-    crate::VERSION.to_string()
+    crate::build_info().version
 }
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn get_build_time() -> String {
-    crate::BUILD_TIME.to_string()
+    crate::build_info().build_time
 }
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn get_git_hash() -> String {
-    crate::GIT_HASH.to_string()
+    crate::build_info().git_hash
 }
 
 #[cfg(feature = "wasm")]
@@ -329,6 +508,10 @@ pub fn validate_ordinals_size_limit(content: &str) -> bool {
 pub fn compress_for_ordinals(content: &str) -> Result<String, JsValue> {
     use std::io::Write;
     
+    // The size limit applies to the compressed output, not the input
+    let content = crate::ordinals::clean_for_inscription(content)
+        .map_err(|e| JsValue::from_str(&format!("Sanitization error: {}", e)))?;
+    
     let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
     encoder.write_all(content.as_bytes())
         .map_err(|e| JsValue::from_str(&format!("Compression error: {}", e)))?;
@@ -424,23 +607,36 @@ impl WasmConfig {
     
     #[wasm_bindgen]
     pub fn to_json(&self) -> String {
-        let mut tradition_weighting = HashMap::new();
-        tradition_weighting.insert("Enochian".to_string(), 0.6);
+        serde_json::to_string(&self.to_config()).unwrap_or_else(|_| "{}".to_string())
+    }
+    
+    /// Check the config before submitting it, as a JSON array of
+    /// `{name, passed, message}` constraint checks
+    #[wasm_bindgen]
+    pub fn validate(&self) -> String {
+        serde_json::to_string(&self.to_config().checks()).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl WasmConfig {
+    fn to_config(&self) -> SystemConfig {
+        let mut tradition_weighting = BTreeMap::new();
+        tradition_weighting.insert("Enochian".to_string(), crate::constants::ENOCHIAN_WEIGHTING);
         tradition_weighting.insert("Hermetic_Qabalah".to_string(), 0.15);
         tradition_weighting.insert("Thelema".to_string(), 0.08);
         tradition_weighting.insert("Golden_Dawn".to_string(), 0.07);
         tradition_weighting.insert("Chaos_Magic".to_string(), 0.05);
         
-        let config = SystemConfig {
+        SystemConfig {
             authenticity_threshold: self.authenticity_threshold,
             max_concurrent_quests: self.max_concurrent_quests,
             tradition_weighting,
             governor_interaction_cooldown: 144,
             enable_p2p_sync: self.enable_p2p_sync,
             enable_bitcoin_integration: self.enable_bitcoin_integration,
-        };
-        
-        serde_json::to_string(&config).unwrap_or_else(|_| "{}".to_string())
+            ..SystemConfig::default()
+        }
     }
 }
 
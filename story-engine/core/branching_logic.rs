@@ -3,7 +3,8 @@
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use crate::conditions::Condition;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestBranch {
@@ -43,17 +44,110 @@ pub enum ConsequenceDuration {
     Temporary,
     Permanent,
     QuestLine,
-    Conditional,
+    // In effect only while the condition holds against the player's state
+    Conditional(Condition),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BranchingContext {
-    pub player_reputation: HashMap<String, f64>,
-    pub tradition_mastery: HashMap<String, f64>,
-    pub governor_relationships: HashMap<String, f64>,
+    pub player_reputation: BTreeMap<String, f64>,
+    pub tradition_mastery: BTreeMap<String, f64>,
+    pub governor_relationships: BTreeMap<String, f64>,
     pub completed_quests: Vec<String>,
     pub current_aethyr_access: Vec<u32>,
     pub energy_level: u32,
+    // Branch IDs chosen earlier in this quest line, oldest first
+    #[serde(default)]
+    pub branch_history: Vec<String>,
+}
+
+// Path names for the three branch slots, shared by next-quest options
+// and follow-up branches so a chosen branch can be traced to its path
+const BRANCH_PATHS: [&str; 3] = ["traditional", "wisdom", "synthesis"];
+
+// Tradition emphasis and element of a hexagram. The element is the image of
+// the upper trigram over the lower one (a doubled trigram is named once); the
+// tradition is the one whose practice best answers the hexagram's judgement,
+// e.g. The Cauldron to Alchemy, Keeping Still to Zen_Buddhism.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HexagramEmphasis {
+    pub number: u32,
+    pub name: &'static str,
+    pub element: &'static str,
+    pub tradition: &'static str,
+}
+
+// All 64 hexagrams in King Wen order, so entry n - 1 is hexagram n
+pub const HEXAGRAM_EMPHASES: [HexagramEmphasis; 64] = [
+    HexagramEmphasis { number: 1, name: "The Creative", element: "Heaven", tradition: "Enochian" },
+    HexagramEmphasis { number: 2, name: "The Receptive", element: "Earth", tradition: "Celtic_Druidism" },
+    HexagramEmphasis { number: 3, name: "Difficulty at the Beginning", element: "Water over Thunder", tradition: "Chaos_Magic" },
+    HexagramEmphasis { number: 4, name: "Youthful Folly", element: "Mountain over Water", tradition: "Zen_Buddhism" },
+    HexagramEmphasis { number: 5, name: "Waiting", element: "Water over Heaven", tradition: "Astrology" },
+    HexagramEmphasis { number: 6, name: "Conflict", element: "Heaven over Water", tradition: "Jewish_Mysticism" },
+    HexagramEmphasis { number: 7, name: "The Army", element: "Earth over Water", tradition: "Runes" },
+    HexagramEmphasis { number: 8, name: "Holding Together", element: "Water over Earth", tradition: "Santeria" },
+    HexagramEmphasis { number: 9, name: "The Taming Power of the Small", element: "Wind over Heaven", tradition: "Sufism" },
+    HexagramEmphasis { number: 10, name: "Treading", element: "Heaven over Lake", tradition: "Golden_Dawn" },
+    HexagramEmphasis { number: 11, name: "Peace", element: "Earth over Heaven", tradition: "Hermetic_Qabalah" },
+    HexagramEmphasis { number: 12, name: "Standstill", element: "Heaven over Earth", tradition: "Gnosticism" },
+    HexagramEmphasis { number: 13, name: "Fellowship with Men", element: "Heaven over Fire", tradition: "Christian_Mysticism" },
+    HexagramEmphasis { number: 14, name: "Possession in Great Measure", element: "Fire over Heaven", tradition: "Alchemy" },
+    HexagramEmphasis { number: 15, name: "Modesty", element: "Earth over Mountain", tradition: "Zen_Buddhism" },
+    HexagramEmphasis { number: 16, name: "Enthusiasm", element: "Thunder over Earth", tradition: "Voodoo" },
+    HexagramEmphasis { number: 17, name: "Following", element: "Lake over Thunder", tradition: "Sufism" },
+    HexagramEmphasis { number: 18, name: "Work on What Has Been Spoiled", element: "Mountain over Wind", tradition: "Alchemy" },
+    HexagramEmphasis { number: 19, name: "Approach", element: "Earth over Lake", tradition: "Christian_Mysticism" },
+    HexagramEmphasis { number: 20, name: "Contemplation", element: "Wind over Earth", tradition: "Zen_Buddhism" },
+    HexagramEmphasis { number: 21, name: "Biting Through", element: "Fire over Thunder", tradition: "Thelema" },
+    HexagramEmphasis { number: 22, name: "Grace", element: "Mountain over Fire", tradition: "Tarot" },
+    HexagramEmphasis { number: 23, name: "Splitting Apart", element: "Mountain over Earth", tradition: "Discordianism" },
+    HexagramEmphasis { number: 24, name: "Return", element: "Earth over Thunder", tradition: "Witchcraft" },
+    HexagramEmphasis { number: 25, name: "Innocence", element: "Heaven over Thunder", tradition: "Shamanism" },
+    HexagramEmphasis { number: 26, name: "The Taming Power of the Great", element: "Mountain over Heaven", tradition: "Hermetic_Qabalah" },
+    HexagramEmphasis { number: 27, name: "Nourishment", element: "Mountain over Thunder", tradition: "Tantra" },
+    HexagramEmphasis { number: 28, name: "Preponderance of the Great", element: "Lake over Wind", tradition: "Luciferianism" },
+    HexagramEmphasis { number: 29, name: "The Abysmal", element: "Water", tradition: "Gnosticism" },
+    HexagramEmphasis { number: 30, name: "The Clinging", element: "Fire", tradition: "Egyptian_Magic" },
+    HexagramEmphasis { number: 31, name: "Influence", element: "Lake over Mountain", tradition: "Tantra" },
+    HexagramEmphasis { number: 32, name: "Duration", element: "Thunder over Wind", tradition: "Christian_Mysticism" },
+    HexagramEmphasis { number: 33, name: "Retreat", element: "Heaven over Mountain", tradition: "Gnosticism" },
+    HexagramEmphasis { number: 34, name: "The Power of the Great", element: "Thunder over Heaven", tradition: "Thelema" },
+    HexagramEmphasis { number: 35, name: "Progress", element: "Fire over Earth", tradition: "Egyptian_Magic" },
+    HexagramEmphasis { number: 36, name: "Darkening of the Light", element: "Earth over Fire", tradition: "Luciferianism" },
+    HexagramEmphasis { number: 37, name: "The Family", element: "Wind over Fire", tradition: "Santeria" },
+    HexagramEmphasis { number: 38, name: "Opposition", element: "Fire over Lake", tradition: "Discordianism" },
+    HexagramEmphasis { number: 39, name: "Obstruction", element: "Water over Mountain", tradition: "Runes" },
+    HexagramEmphasis { number: 40, name: "Deliverance", element: "Thunder over Water", tradition: "Voodoo" },
+    HexagramEmphasis { number: 41, name: "Decrease", element: "Mountain over Lake", tradition: "Zen_Buddhism" },
+    HexagramEmphasis { number: 42, name: "Increase", element: "Wind over Thunder", tradition: "Hermetic_Qabalah" },
+    HexagramEmphasis { number: 43, name: "Breakthrough", element: "Lake over Heaven", tradition: "Chaos_Magic" },
+    HexagramEmphasis { number: 44, name: "Coming to Meet", element: "Heaven over Wind", tradition: "Tantra" },
+    HexagramEmphasis { number: 45, name: "Gathering Together", element: "Lake over Earth", tradition: "Golden_Dawn" },
+    HexagramEmphasis { number: 46, name: "Pushing Upward", element: "Earth over Wind", tradition: "Celtic_Druidism" },
+    HexagramEmphasis { number: 47, name: "Oppression", element: "Lake over Water", tradition: "Satanism" },
+    HexagramEmphasis { number: 48, name: "The Well", element: "Water over Wind", tradition: "Celtic_Druidism" },
+    HexagramEmphasis { number: 49, name: "Revolution", element: "Lake over Fire", tradition: "Thelema" },
+    HexagramEmphasis { number: 50, name: "The Cauldron", element: "Fire over Wind", tradition: "Alchemy" },
+    HexagramEmphasis { number: 51, name: "The Arousing", element: "Thunder", tradition: "Runes" },
+    HexagramEmphasis { number: 52, name: "Keeping Still", element: "Mountain", tradition: "Zen_Buddhism" },
+    HexagramEmphasis { number: 53, name: "Development", element: "Wind over Mountain", tradition: "Golden_Dawn" },
+    HexagramEmphasis { number: 54, name: "The Marrying Maiden", element: "Thunder over Lake", tradition: "Witchcraft" },
+    HexagramEmphasis { number: 55, name: "Abundance", element: "Thunder over Fire", tradition: "Astrology" },
+    HexagramEmphasis { number: 56, name: "The Wanderer", element: "Fire over Mountain", tradition: "Sufism" },
+    HexagramEmphasis { number: 57, name: "The Gentle", element: "Wind", tradition: "Shamanism" },
+    HexagramEmphasis { number: 58, name: "The Joyous", element: "Lake", tradition: "Discordianism" },
+    HexagramEmphasis { number: 59, name: "Dispersion", element: "Wind over Water", tradition: "Gnosticism" },
+    HexagramEmphasis { number: 60, name: "Limitation", element: "Water over Lake", tradition: "Jewish_Mysticism" },
+    HexagramEmphasis { number: 61, name: "Inner Truth", element: "Wind over Lake", tradition: "Christian_Mysticism" },
+    HexagramEmphasis { number: 62, name: "Preponderance of the Small", element: "Thunder over Mountain", tradition: "Witchcraft" },
+    HexagramEmphasis { number: 63, name: "After Completion", element: "Water over Fire", tradition: "Alchemy" },
+    HexagramEmphasis { number: 64, name: "Before Completion", element: "Fire over Water", tradition: "Hermetic_Qabalah" },
+];
+
+pub fn hexagram_emphasis(hexagram_number: u32) -> Option<&'static HexagramEmphasis> {
+    let index = usize::try_from(hexagram_number).ok()?.checked_sub(1)?;
+    HEXAGRAM_EMPHASES.get(index)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -164,7 +258,7 @@ impl BranchingEngine {
             hexagram_name: "Peace".to_string(),
             changing_lines: vec![],
             guidance_text: "Harmony between heaven and earth creates perfect conditions for spiritual growth.".to_string(),
-            elemental_influence: "Heaven over Earth".to_string(),
+            elemental_influence: "Earth over Heaven".to_string(),
             recommended_action: "Seek balance in all mystical practices".to_string(),
             caution_areas: vec!["Maintain vigilance during peaceful times".to_string()],
         });
@@ -245,20 +339,38 @@ impl BranchingEngine {
     ) -> Vec<QuestBranch> {
         let mut branches = Vec::new();
         
+        // Follow-up branches hang off the last chosen branch so each
+        // choice leads to its own set of options
+        let prior_choice = context.branch_history.last();
+        let prior_path = prior_choice.and_then(|branch_id| self.branch_path_index(branch_id));
+        let branch_root = prior_choice.map(String::as_str).unwrap_or(quest_id);
+        let depth = context.branch_history.len() as u32;
+        
         // Generate 3 branches based on I Ching guidance
         for i in 0..3 {
             let branch_seed = seed + i;
-            let difficulty = self.calculate_contextual_difficulty(context, i);
+            let difficulty = (self.calculate_contextual_difficulty(context, i) + depth).min(5);
+            
+            let mut choice_description = self.generate_choice_description(guidance, i);
+            let mut tradition_requirements = self.determine_tradition_requirements(guidance, i);
+            if let Some(path) = prior_path {
+                choice_description = format!("Continuing the {} path: {}", BRANCH_PATHS[path], choice_description);
+                for tradition in self.determine_tradition_requirements(guidance, path) {
+                    if !tradition_requirements.contains(&tradition) {
+                        tradition_requirements.push(tradition);
+                    }
+                }
+            }
             
             let branch = QuestBranch {
-                branch_id: format!("{}_{}", quest_id, i + 1),
+                branch_id: format!("{}_{}", branch_root, i + 1),
                 parent_quest_id: quest_id.to_string(),
-                choice_description: self.generate_choice_description(guidance, i),
+                choice_description,
                 consequences: self.generate_contextual_consequences(context, guidance, i),
-                tradition_requirements: self.determine_tradition_requirements(context, i),
+                tradition_requirements,
                 difficulty_level: difficulty,
                 authenticity_impact: self.calculate_authenticity_impact(guidance, i),
-                next_quest_options: self.generate_next_options(quest_id, i),
+                next_quest_options: self.generate_next_options(branch_root, i),
             };
             
             branches.push(branch);
@@ -300,7 +412,7 @@ impl BranchingEngine {
                     description: "Unlock deeper mystical understanding".to_string(),
                     impact_value: 0.15,
                     duration: ConsequenceDuration::Permanent,
-                    tradition_alignment: "Universal".to_string(),
+                    tradition_alignment: hexagram_tradition(guidance).to_string(),
                 });
             },
             2 => {
@@ -309,7 +421,7 @@ impl BranchingEngine {
                     description: "Develop advanced spiritual techniques".to_string(),
                     impact_value: 0.2,
                     duration: ConsequenceDuration::QuestLine,
-                    tradition_alignment: "Mixed".to_string(),
+                    tradition_alignment: hexagram_tradition(guidance).to_string(),
                 });
             },
             _ => {}
@@ -333,11 +445,15 @@ impl BranchingEngine {
         (base_difficulty + mastery_modifier).min(5)
     }
 
-    fn determine_tradition_requirements(&self, context: &BranchingContext, branch_index: usize) -> Vec<String> {
+    // The traditional path keeps to Enochian practice, the wisdom path follows
+    // the hexagram's tradition and the synthesis path joins the two
+    fn determine_tradition_requirements(&self, guidance: &IChingGuidance, branch_index: usize) -> Vec<String> {
+        let emphasis = hexagram_tradition(guidance).to_string();
         match branch_index {
             0 => vec!["Enochian".to_string()],
-            1 => vec!["Hermetic_Qabalah".to_string()],
-            2 => vec!["Enochian".to_string(), "Hermetic_Qabalah".to_string()],
+            1 => vec![emphasis],
+            2 if emphasis == "Enochian" => vec![emphasis],
+            2 => vec!["Enochian".to_string(), emphasis],
             _ => vec![],
         }
     }
@@ -352,12 +468,22 @@ impl BranchingEngine {
     }
 
     fn generate_next_options(&self, quest_id: &str, branch_index: usize) -> Vec<String> {
-        match branch_index {
-            0 => vec![format!("{}_traditional_path", quest_id)],
-            1 => vec![format!("{}_wisdom_path", quest_id)],
-            2 => vec![format!("{}_synthesis_path", quest_id)],
-            _ => vec![],
+        match BRANCH_PATHS.get(branch_index) {
+            Some(path) => vec![format!("{}_{}_path", quest_id, path)],
+            None => vec![],
+        }
+    }
+
+    // Recover the branch slot from a chosen branch ID ("<root>_<n>") or
+    // one of its next-quest options ("<root>_<path>_path")
+    fn branch_path_index(&self, branch_id: &str) -> Option<usize> {
+        if let Some(position) = BRANCH_PATHS.iter()
+            .position(|path| branch_id.ends_with(&format!("_{}_path", path)))
+        {
+            return Some(position);
         }
+        let slot: usize = branch_id.rsplit('_').next()?.parse().ok()?;
+        (1..=BRANCH_PATHS.len()).contains(&slot).then(|| slot - 1)
     }
 
     fn calculate_consequences(&self, branch_id: &str, context: &BranchingContext) -> Vec<Consequence> {
@@ -375,30 +501,115 @@ impl BranchingEngine {
 
     fn create_default_context(&self) -> BranchingContext {
         BranchingContext {
-            player_reputation: HashMap::new(),
-            tradition_mastery: HashMap::new(),
-            governor_relationships: HashMap::new(),
+            player_reputation: BTreeMap::new(),
+            tradition_mastery: BTreeMap::new(),
+            governor_relationships: BTreeMap::new(),
             completed_quests: vec![],
             current_aethyr_access: vec![],
             energy_level: 25,
+            branch_history: vec![],
         }
     }
 
     fn create_default_guidance(&self, hexagram_number: u32) -> IChingGuidance {
+        let emphasis = hexagram_emphasis(hexagram_number);
         IChingGuidance {
             hexagram_number,
-            hexagram_name: format!("Hexagram {}", hexagram_number),
+            hexagram_name: emphasis.map(|e| e.name.to_string())
+                .unwrap_or_else(|| format!("Hexagram {}", hexagram_number)),
             changing_lines: vec![],
             guidance_text: "Seek wisdom through authentic spiritual practice".to_string(),
-            elemental_influence: "Universal".to_string(),
+            elemental_influence: emphasis.map(|e| e.element).unwrap_or("Universal").to_string(),
             recommended_action: "Follow the path of truth".to_string(),
             caution_areas: vec!["Avoid spiritual materialism".to_string()],
         }
     }
 }
 
+// Tradition emphasized by the guidance's hexagram, Enochian if it has none
+fn hexagram_tradition(guidance: &IChingGuidance) -> &'static str {
+    hexagram_emphasis(guidance.hexagram_number).map(|e| e.tradition).unwrap_or("Enochian")
+}
+
 pub struct ConsequenceRule {
     pub rule_id: String,
     pub condition: String,
     pub consequence: Consequence,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branches_after(engine: &BranchingEngine, history: &[&str]) -> Vec<QuestBranch> {
+        let mut context = engine.create_default_context();
+        context.tradition_mastery.insert("Enochian".to_string(), 0.2);
+        context.branch_history = history.iter().map(|id| id.to_string()).collect();
+        let json = engine.generate_quest_branches("quest_1_42", &serde_json::to_string(&context).unwrap(), 42);
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn branch_ids(branches: &[QuestBranch]) -> Vec<String> {
+        branches.iter().map(|branch| branch.branch_id.clone()).collect()
+    }
+
+    #[test]
+    fn test_step_two_branches_follow_step_one_choice() {
+        let engine = BranchingEngine::new();
+        let step_one = branches_after(&engine, &[]);
+        assert_eq!(branch_ids(&step_one), vec!["quest_1_42_1", "quest_1_42_2", "quest_1_42_3"]);
+
+        let after_a = branches_after(&engine, &[&step_one[0].branch_id]);
+        let after_b = branches_after(&engine, &[&step_one[1].branch_id]);
+
+        assert_ne!(branch_ids(&after_a), branch_ids(&after_b));
+        assert_eq!(after_a[0].branch_id, "quest_1_42_1_1");
+        assert_eq!(after_b[0].branch_id, "quest_1_42_2_1");
+        assert!(after_a[0].choice_description.starts_with("Continuing the traditional path"));
+        assert!(after_b[0].choice_description.starts_with("Continuing the wisdom path"));
+        // Seed 42 casts hexagram 43, Breakthrough, emphasizing Chaos_Magic
+        assert!(after_b[0].tradition_requirements.contains(&"Chaos_Magic".to_string()));
+        assert_eq!(after_a[0].next_quest_options, vec!["quest_1_42_1_traditional_path"]);
+    }
+
+    #[test]
+    fn test_history_accepts_next_quest_options() {
+        let engine = BranchingEngine::new();
+        let step_one = branches_after(&engine, &[]);
+        let via_option = branches_after(&engine, &[&step_one[2].next_quest_options[0]]);
+        assert!(via_option[0].choice_description.starts_with("Continuing the synthesis path"));
+    }
+
+    #[test]
+    fn test_every_hexagram_maps_to_a_known_tradition() {
+        for (index, emphasis) in HEXAGRAM_EMPHASES.iter().enumerate() {
+            assert_eq!(emphasis.number as usize, index + 1);
+            assert!(crate::is_known_tradition(emphasis.tradition), "{}", emphasis.tradition);
+            assert_eq!(hexagram_emphasis(emphasis.number), Some(emphasis));
+        }
+        assert!(hexagram_emphasis(0).is_none());
+        assert!(hexagram_emphasis(65).is_none());
+    }
+
+    #[test]
+    fn test_hexagram_sets_branch_traditions() {
+        let engine = BranchingEngine::new();
+        let context = serde_json::to_string(&engine.create_default_context()).unwrap();
+        // Seeds 49 and 113 both cast hexagram 50, The Cauldron
+        for seed in [49, 113] {
+            let branches: Vec<QuestBranch> = serde_json::from_str(&engine.generate_quest_branches("quest_1", &context, seed)).unwrap();
+            assert_eq!(branches[1].tradition_requirements, vec!["Alchemy"]);
+            assert_eq!(branches[2].tradition_requirements, vec!["Enochian", "Alchemy"]);
+            assert_eq!(branches[1].consequences[0].tradition_alignment, "Alchemy");
+            assert!(branches[0].choice_description.contains("Fire over Wind"));
+        }
+    }
+
+    #[test]
+    fn test_deeper_branches_grow_harder() {
+        let engine = BranchingEngine::new();
+        let step_one = branches_after(&engine, &[]);
+        let step_two = branches_after(&engine, &["quest_1_42_1"]);
+        assert_eq!(step_two[0].difficulty_level, step_one[0].difficulty_level + 1);
+    }
+}
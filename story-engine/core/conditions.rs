@@ -0,0 +1,108 @@
+// Enochian Cyphers Story Engine - Conditions
+// Serializable predicates over story state, used by conditional consequences
+
+use serde::{Deserialize, Serialize};
+use crate::trac_state_manager::StoryState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    Above,
+    AtLeast,
+    Below,
+    AtMost,
+}
+
+impl Comparison {
+    pub fn compare(&self, actual: f64, value: f64) -> bool {
+        match self {
+            Comparison::Above => actual > value,
+            Comparison::AtLeast => actual >= value,
+            Comparison::Below => actual < value,
+            Comparison::AtMost => actual <= value,
+        }
+    }
+}
+
+// Externally tagged like the other wire enums, e.g.
+// {"Mastery": {"tradition": "Enochian", "comparison": "Above", "value": 0.5}}.
+// Missing mastery, relationship and reputation entries count as 0.0.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Condition {
+    Mastery { tradition: String, comparison: Comparison, value: f64 },
+    Relationship { governor: String, comparison: Comparison, value: f64 },
+    Reputation { target: String, comparison: Comparison, value: f64 },
+    Energy { comparison: Comparison, value: f64 },
+    HoldsItem(String),
+    CompletedQuest(String),
+    AethyrAccess(u32),
+    // True when empty
+    All(Vec<Condition>),
+    // False when empty
+    Any(Vec<Condition>),
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    pub fn holds(&self, state: &StoryState) -> bool {
+        match self {
+            Condition::Mastery { tradition, comparison, value } => {
+                comparison.compare(state.tradition_mastery.get(tradition).copied().unwrap_or(0.0), *value)
+            },
+            Condition::Relationship { governor, comparison, value } => {
+                comparison.compare(state.governor_relationships.get(governor).copied().unwrap_or(0.0), *value)
+            },
+            Condition::Reputation { target, comparison, value } => {
+                comparison.compare(state.reputation_scores.get(target).copied().unwrap_or(0.0), *value)
+            },
+            Condition::Energy { comparison, value } => comparison.compare(state.energy_level as f64, *value),
+            Condition::HoldsItem(item_id) => state.sacred_items.contains(item_id),
+            Condition::CompletedQuest(quest_id) => state.completed_quests.contains(quest_id),
+            Condition::AethyrAccess(aethyr_id) => state.aethyr_access.contains(aethyr_id),
+            Condition::All(conditions) => conditions.iter().all(|condition| condition.holds(state)),
+            Condition::Any(conditions) => conditions.iter().any(|condition| condition.holds(state)),
+            Condition::Not(condition) => !condition.holds(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trac_state_manager::TracStateManager;
+
+    fn state() -> StoryState {
+        let mut manager = TracStateManager::new();
+        serde_json::from_str(&manager.initialize_player_state("player")).unwrap()
+    }
+
+    #[test]
+    fn test_conditions_combine_over_state() {
+        // Starting state: Enochian mastery 0.1, energy 25, no items
+        let state = state();
+        let mastery = |comparison, value| Condition::Mastery { tradition: "Enochian".to_string(), comparison, value };
+
+        assert!(mastery(Comparison::Above, 0.05).holds(&state));
+        assert!(!mastery(Comparison::Above, 0.5).holds(&state));
+        assert!(mastery(Comparison::AtMost, 0.1).holds(&state));
+        assert!(Condition::Relationship { governor: "ABRIOND".to_string(), comparison: Comparison::AtMost, value: 0.0 }.holds(&state));
+
+        let held = Condition::HoldsItem("wand".to_string());
+        assert!(!held.holds(&state));
+        assert!(Condition::Not(Box::new(held.clone())).holds(&state));
+        assert!(Condition::Any(vec![held.clone(), Condition::Energy { comparison: Comparison::AtLeast, value: 25.0 }]).holds(&state));
+        assert!(!Condition::All(vec![held, mastery(Comparison::Above, 0.05)]).holds(&state));
+        assert!(Condition::All(vec![]).holds(&state));
+        assert!(!Condition::Any(vec![]).holds(&state));
+    }
+
+    #[test]
+    fn test_condition_json_round_trip() {
+        let json = r#"{"All":[{"Mastery":{"tradition":"Enochian","comparison":"Above","value":0.5}},{"Not":{"HoldsItem":"wand"}}]}"#;
+        let condition: Condition = serde_json::from_str(json).unwrap();
+        assert_eq!(condition, Condition::All(vec![
+            Condition::Mastery { tradition: "Enochian".to_string(), comparison: Comparison::Above, value: 0.5 },
+            Condition::Not(Box::new(Condition::HoldsItem("wand".to_string()))),
+        ]));
+        assert_eq!(serde_json::to_string(&condition).unwrap(), json);
+    }
+}
@@ -0,0 +1,97 @@
+// Enochian Cyphers Story Engine - Governor Mood
+// Per-player governor disposition that warms or cools with recent conduct
+
+use serde::{Deserialize, Serialize};
+
+// Blocks for a mood to decay halfway back to neutral (about a day)
+pub const MOOD_HALF_LIFE_BLOCKS: u64 = 144;
+// Interaction authenticity that leaves mood unchanged; higher warms, lower cools
+pub const MOOD_AUTHENTICITY_BASELINE: f64 = 0.9;
+// Mood shift per point of authenticity above or below the baseline
+pub const MOOD_AUTHENTICITY_SENSITIVITY: f64 = 2.0;
+// Additional cooling when an interaction fails
+pub const MOOD_FAILURE_PENALTY: f64 = 0.2;
+// Relationship rewards scale by 1 + this * mood, so 0.5x-1.5x
+pub const MOOD_REWARD_SCALE: f64 = 0.5;
+
+// Moods beyond these colour governor dialogue
+const WARM_MOOD: f64 = 0.3;
+const COLD_MOOD: f64 = -0.3;
+
+// Mood in -1.0 (cold) to 1.0 (warm) as of the block it was last changed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GovernorMood {
+    pub value: f64,
+    pub updated_at_block: u64,
+}
+
+impl GovernorMood {
+    pub fn neutral(block_height: u64) -> GovernorMood {
+        GovernorMood { value: 0.0, updated_at_block: block_height }
+    }
+
+    // Mood at `block_height`, halving towards neutral every MOOD_HALF_LIFE_BLOCKS
+    pub fn at(&self, block_height: u64) -> f64 {
+        let elapsed = block_height.saturating_sub(self.updated_at_block) as f64;
+        self.value * 0.5f64.powf(elapsed / MOOD_HALF_LIFE_BLOCKS as f64)
+    }
+
+    // Decay to `block_height`, then apply `change`
+    pub fn shifted(&self, change: f64, block_height: u64) -> GovernorMood {
+        GovernorMood {
+            value: (self.at(block_height) + change).clamp(-1.0, 1.0),
+            updated_at_block: block_height.max(self.updated_at_block),
+        }
+    }
+}
+
+// Mood change from one interaction with the given authenticity score
+pub fn mood_shift(authenticity: f64, succeeded: bool) -> f64 {
+    let shift = (authenticity - MOOD_AUTHENTICITY_BASELINE) * MOOD_AUTHENTICITY_SENSITIVITY;
+    if succeeded {
+        shift
+    } else {
+        shift - MOOD_FAILURE_PENALTY
+    }
+}
+
+pub fn reward_multiplier(mood: f64) -> f64 {
+    1.0 + MOOD_REWARD_SCALE * mood.clamp(-1.0, 1.0)
+}
+
+// Localization key for a dialogue line reflecting the mood, if it is notable
+pub fn mood_dialogue_key(mood: f64) -> Option<&'static str> {
+    if mood >= WARM_MOOD {
+        Some("dialogue.mood.warm")
+    } else if mood <= COLD_MOOD {
+        Some("dialogue.mood.cold")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mood_decays_toward_neutral() {
+        let mood = GovernorMood::neutral(100).shifted(0.8, 100);
+        assert_eq!(mood.at(100), 0.8);
+        assert!((mood.at(100 + MOOD_HALF_LIFE_BLOCKS) - 0.4).abs() < 1e-12);
+        assert!(mood.at(100 + 20 * MOOD_HALF_LIFE_BLOCKS).abs() < 1e-6);
+
+        // A later shift starts from the decayed value
+        let shifted = mood.shifted(-0.1, 100 + MOOD_HALF_LIFE_BLOCKS);
+        assert!((shifted.value - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_failure_cools_mood() {
+        assert!(mood_shift(1.0, true) > 0.0);
+        assert!(mood_shift(1.0, false) < mood_shift(1.0, true));
+        assert!(mood_shift(0.85, true) < 0.0);
+        assert_eq!(mood_dialogue_key(0.5), Some("dialogue.mood.warm"));
+        assert_eq!(mood_dialogue_key(0.0), None);
+    }
+}
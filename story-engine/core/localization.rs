@@ -0,0 +1,116 @@
+// Enochian Cyphers Story Engine - Localization
+// Locale tables for generated narrative phrases. Templates carry only the
+// surrounding prose; sacred terms (governor names, Enochian keywords) are
+// substituted in untranslated.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+pub const DEFAULT_LOCALE: &str = "en";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocaleTable {
+    pub locale: String,
+    pub phrases: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    tables: HashMap<String, LocaleTable>,
+}
+
+impl Localizer {
+    pub fn new() -> Localizer {
+        let mut localizer = Localizer {
+            tables: HashMap::new(),
+        };
+        localizer.add_table(english_table());
+        localizer
+    }
+
+    // Later tables for the same locale extend and override earlier ones
+    pub fn add_table(&mut self, table: LocaleTable) {
+        let existing = self.tables.entry(table.locale.clone()).or_insert_with(|| LocaleTable {
+            locale: table.locale.clone(),
+            phrases: BTreeMap::new(),
+        });
+        existing.phrases.extend(table.phrases);
+    }
+
+    // Look up a phrase, falling back to English and finally to the key itself
+    pub fn phrase(&self, locale: &str, key: &str) -> String {
+        [locale, DEFAULT_LOCALE].iter()
+            .filter_map(|l| self.tables.get(*l))
+            .find_map(|table| table.phrases.get(key))
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    // Look up a phrase and fill its `{name}` placeholders
+    pub fn format(&self, locale: &str, key: &str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.phrase(locale, key), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+}
+
+fn english_table() -> LocaleTable {
+    let phrases = [
+        ("narrative.title", "The Sacred Path of {domain}"),
+        ("narrative.base_story", "In the sacred realm of {aethyr}, Governor {governor} manifests their divine wisdom through the mystical properties of {properties}. \
+            The seeker approaches this celestial being, drawn by the {domain} energies that emanate from the {tier} tier of existence. \
+            Through authentic Enochian invocations and sacred geometry patterns of {geometry}, the path of enlightenment unfolds."),
+        ("narrative.wisdom_taught", "Enhanced {domain} mastery through authentic Enochian practices"),
+        ("narrative.objective.study", "Study the enhanced principles of {domain}"),
+        ("narrative.objective.meditate", "Practice {domain_lower}-based meditation with Enochian invocations"),
+        ("narrative.objective.integrate", "Integrate {governor}'s enhanced wisdom into spiritual practice"),
+        ("narrative.objective.master", "Achieve mastery through authentic {tradition} methods"),
+        ("narrative.objective.blessing", "Receive governor's enhanced blessing"),
+        ("narrative.fallback.title", "Sacred Enochian Invocation"),
+        ("narrative.fallback.description", "A fundamental quest in Enochian wisdom: approach the Governor of your first Aethyr through the Watchtowers recorded by Dee and Kelley, advancing spiritually through authentic angelic communication."),
+        ("narrative.fallback.objective.study", "Study basic Enochian principles"),
+        ("narrative.fallback.objective.invoke", "Practice angelic invocation"),
+        ("narrative.fallback.objective.awareness", "Develop spiritual awareness"),
+        ("narrative.fallback.wisdom_taught", "Foundation Enochian practices"),
+        ("dialogue.greeting", "Governor {governor} responds to your {action} with {domain_lower} wisdom: \"Through the sacred domain of {domain}, I guide you toward authentic understanding.\""),
+        ("dialogue.fallback", "Governor {governor} speaks with divine authority: \"Seek wisdom through authentic practice.\""),
+        ("dialogue.tier.stranger", "{governor} keeps their deeper counsel, waiting to see whether you return."),
+        ("dialogue.tier.acquaintance", "{governor} speaks of {specialization}: \"Begin through {method}.\""),
+        ("dialogue.tier.trusted", "{governor} confides the inner practice of {specialization}: \"Learn it through {method}, and read {text}.\""),
+        ("dialogue.tier.intimate", "{governor} opens the whole mystery of {specialization}: \"Through {method} and {text}, you will come to {application}.\""),
+        ("dialogue.mood.warm", "{governor} regards you warmly, pleased by your recent devotion."),
+        ("dialogue.mood.cold", "{governor} regards you coolly, unconvinced by your recent conduct."),
+    ];
+
+    LocaleTable {
+        locale: DEFAULT_LOCALE.to_string(),
+        phrases: phrases.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn french_table() -> LocaleTable {
+        let mut phrases = BTreeMap::new();
+        phrases.insert(
+            "dialogue.greeting".to_string(),
+            "Le Gouverneur {governor} répond à votre {action} : « Par le domaine sacré de {domain}, je vous guide. »".to_string(),
+        );
+        LocaleTable { locale: "fr".to_string(), phrases }
+    }
+
+    #[test]
+    fn test_locale_overrides_and_falls_back_to_english() {
+        let mut localizer = Localizer::new();
+        localizer.add_table(french_table());
+
+        let greeting = localizer.format("fr", "dialogue.greeting", &[("governor", "ABRIOND"), ("action", "invocation"), ("domain", "Creation Mastery")]);
+        assert!(greeting.starts_with("Le Gouverneur ABRIOND"));
+
+        assert_eq!(localizer.phrase("fr", "narrative.fallback.title"), "Sacred Enochian Invocation");
+        assert_eq!(localizer.phrase("de", "narrative.fallback.title"), "Sacred Enochian Invocation");
+        assert_eq!(localizer.phrase("fr", "missing.key"), "missing.key");
+    }
+}
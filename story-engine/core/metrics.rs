@@ -0,0 +1,66 @@
+// Enochian Cyphers Story Engine - Metrics
+// Story engine counters in a registry from the core crate's metrics module,
+// compiled out unless the `metrics` feature is enabled
+
+use enochian_cyphers::metrics::{MetricCounter, Metrics};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Counter {
+    QuestsGenerated,
+    TransitionsProposed,
+    TransitionsReceived,
+    TransitionsFinalized,
+}
+
+impl MetricCounter for Counter {
+    const ALL: &'static [Counter] = &[
+        Counter::QuestsGenerated,
+        Counter::TransitionsProposed,
+        Counter::TransitionsReceived,
+        Counter::TransitionsFinalized,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Counter::QuestsGenerated => "quests_generated",
+            Counter::TransitionsProposed => "transitions_proposed",
+            Counter::TransitionsReceived => "transitions_received",
+            Counter::TransitionsFinalized => "transitions_finalized",
+        }
+    }
+}
+
+// Operation counters plus rejected requests counted by kind, e.g. "unknown_item"
+static METRICS: Metrics<Counter> = Metrics::new();
+
+pub fn global() -> &'static Metrics<Counter> {
+    &METRICS
+}
+
+pub fn metrics_snapshot() -> serde_json::Value {
+    METRICS.snapshot()
+}
+
+pub fn reset_metrics() {
+    METRICS.reset()
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_uses_story_engine_counters() {
+        let metrics = Metrics::<Counter>::new();
+        metrics.increment(Counter::TransitionsProposed);
+        metrics.record_error_kind("unknown_item");
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot["counters"]["transitions_proposed"], 1);
+        assert_eq!(snapshot["counters"]["quests_generated"], 0);
+        assert_eq!(snapshot["errors"]["unknown_item"], 1);
+    }
+}
@@ -0,0 +1,96 @@
+// Enochian Cyphers Story Engine - Sacred Items
+// Registry of sacred item definitions and the effects they grant when used
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ItemUsage {
+    // Removed from the player's inventory when used
+    Consumable,
+    // Kept after use and may be used again
+    Permanent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SacredItem {
+    pub id: String,
+    pub name: String,
+    pub tradition: String,
+    pub energy_change: f64,
+    pub mastery_change: f64,
+    pub authenticity_impact: f64,
+    pub usage: ItemUsage,
+}
+
+#[derive(Debug, Clone)]
+pub struct SacredItemRegistry {
+    items: HashMap<String, SacredItem>,
+}
+
+impl SacredItemRegistry {
+    pub fn new() -> SacredItemRegistry {
+        let mut registry = SacredItemRegistry {
+            items: HashMap::new(),
+        };
+
+        for item in default_items() {
+            registry.items.insert(item.id.clone(), item);
+        }
+
+        registry
+    }
+
+    pub fn register(&mut self, item: SacredItem) -> Result<(), String> {
+        if item.id.trim().is_empty() {
+            return Err("Sacred item ID must not be empty".to_string());
+        }
+        if self.items.contains_key(&item.id) {
+            return Err(format!("Sacred item {} already registered", item.id));
+        }
+
+        self.items.insert(item.id.clone(), item);
+        Ok(())
+    }
+
+    pub fn get(&self, item_id: &str) -> Option<&SacredItem> {
+        self.items.get(item_id)
+    }
+
+    pub fn contains(&self, item_id: &str) -> bool {
+        self.items.contains_key(item_id)
+    }
+}
+
+// Items drawn from John Dee's ritual furniture
+fn default_items() -> Vec<SacredItem> {
+    vec![
+        SacredItem {
+            id: "sigillum_dei_aemeth".to_string(),
+            name: "Sigillum Dei Aemeth".to_string(),
+            tradition: "Enochian".to_string(),
+            energy_change: 0.0,
+            mastery_change: 0.05,
+            authenticity_impact: 0.1,
+            usage: ItemUsage::Permanent,
+        },
+        SacredItem {
+            id: "shew_stone".to_string(),
+            name: "Shew-stone".to_string(),
+            tradition: "Enochian".to_string(),
+            energy_change: 0.0,
+            mastery_change: 0.02,
+            authenticity_impact: 0.08,
+            usage: ItemUsage::Permanent,
+        },
+        SacredItem {
+            id: "consecrated_incense".to_string(),
+            name: "Consecrated Incense".to_string(),
+            tradition: "Enochian".to_string(),
+            energy_change: 5.0,
+            mastery_change: 0.0,
+            authenticity_impact: 0.02,
+            usage: ItemUsage::Consumable,
+        },
+    ]
+}
@@ -3,7 +3,18 @@
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Arc;
+use crate::sacred_items::{ItemUsage, SacredItem, SacredItemRegistry};
+use crate::governor_mood::{mood_shift, reward_multiplier, GovernorMood};
+use crate::conditions::Condition;
+use crate::metrics::{self, Counter};
+use enochian_cyphers::{AuthenticityScorer, ContentType, Energy};
+use enochian_cyphers::runtime::{Clock, SystemClock, TARGET_BLOCK_INTERVAL};
+
+// Tradition proofs are scored against when an action declares none
+const DEFAULT_PROOF_TRADITION: &str = "Enochian";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryState {
@@ -11,18 +22,100 @@ pub struct StoryState {
     pub current_quest_id: String,
     pub completed_quests: Vec<String>,
     pub active_branches: Vec<String>,
-    pub governor_relationships: HashMap<String, f64>,
-    pub tradition_mastery: HashMap<String, f64>,
-    pub reputation_scores: HashMap<String, f64>,
+    pub governor_relationships: BTreeMap<String, f64>,
+    pub tradition_mastery: BTreeMap<String, f64>,
+    pub reputation_scores: BTreeMap<String, f64>,
     pub energy_level: u32,
     pub aethyr_access: Vec<u32>,
     pub sacred_items: Vec<String>,
     pub timestamp: u64,
     pub state_hash: String,
+    // Keyed by governor name, like governor_relationships
+    #[serde(default)]
+    pub governor_moods: BTreeMap<String, GovernorMood>,
+    // Conditional consequences received so far, in the order they arrived
+    #[serde(default)]
+    pub conditional_effects: Vec<ConditionalEffect>,
+}
+
+// Blocks a conditional effect stays on the state, about a week
+pub const CONDITIONAL_EFFECT_LIFETIME_BLOCKS: u64 = 1008;
+
+// Most conditional effects a state holds; the oldest are retired first
+pub const MAX_CONDITIONAL_EFFECTS: usize = 32;
+
+// A conditional consequence kept on the state so it can be applied while its
+// condition holds and withdrawn when it lapses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionalEffect {
+    pub consequence: StateConsequence,
+    // Change actually made when applied, after clamping, which withdrawal
+    // reverses; None while the condition does not hold
+    pub applied: Option<f64>,
+    // Block height at which the effect is withdrawn and dropped; None for
+    // effects recorded before effects expired
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+// Compact postcard encoding for Ordinals inscriptions and P2P payloads.
+// Field order is fixed and all maps are BTreeMaps, so output is deterministic.
+#[cfg(feature = "binary")]
+impl StoryState {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        postcard::to_allocvec(self).map_err(|e| format!("Error encoding story state: {}", e))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<StoryState, String> {
+        postcard::from_bytes(bytes).map_err(|e| format!("Error decoding story state: {}", e))
+    }
+}
+
+// Wire format version for state transitions exchanged between peers.
+// Bump whenever StateTransition or its nested types change shape.
+// Version 3 attached a condition to ConsequenceDuration::Conditional.
+pub const STATE_TRANSITION_SCHEMA_VERSION: u32 = 3;
+
+// Transitions from peers predating schema versioning carry no version field
+fn legacy_schema_version() -> u32 {
+    0
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkError {
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
+    MalformedMessage(String),
+}
+
+impl std::fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkError::UnsupportedSchemaVersion { found, supported } => write!(
+                f,
+                "Network error: unsupported schema version {} (supported up to {})",
+                found, supported
+            ),
+            NetworkError::MalformedMessage(reason) => write!(f, "Network error: malformed message: {}", reason),
+        }
+    }
+}
+
+impl NetworkError {
+    // Metrics key for the error
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NetworkError::UnsupportedSchemaVersion { .. } => "unsupported_schema_version",
+            NetworkError::MalformedMessage(_) => "malformed_message",
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StateTransition {
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
     pub transition_id: String,
     pub from_state_hash: String,
     pub to_state_hash: String,
@@ -33,12 +126,75 @@ pub struct StateTransition {
     pub block_height: u64,
 }
 
+impl StateTransition {
+    // Decode a transition received from a peer, up-converting legacy
+    // unversioned payloads and rejecting versions newer than ours
+    pub fn from_json(json: &str) -> Result<StateTransition, NetworkError> {
+        let mut transition: StateTransition = serde_json::from_str(json)
+            .map_err(|e| NetworkError::MalformedMessage(e.to_string()))?;
+
+        if transition.schema_version > STATE_TRANSITION_SCHEMA_VERSION {
+            return Err(NetworkError::UnsupportedSchemaVersion {
+                found: transition.schema_version,
+                supported: STATE_TRANSITION_SCHEMA_VERSION,
+            });
+        }
+
+        // Version 0 differs from version 1 only by the missing version field
+        transition.schema_version = STATE_TRANSITION_SCHEMA_VERSION;
+        Ok(transition)
+    }
+
+    // SHA-256 of everything but the validator signatures, which accumulate
+    // in a different order on each node
+    pub fn content_hash(&self) -> String {
+        let content = serde_json::json!({
+            "schema_version": self.schema_version,
+            "transition_id": self.transition_id,
+            "from_state_hash": self.from_state_hash,
+            "to_state_hash": self.to_state_hash,
+            "quest_action": self.quest_action,
+            "consequences": self.consequences,
+            "timestamp": self.timestamp,
+            "block_height": self.block_height,
+        });
+        hex::encode(Sha256::digest(content.to_string().as_bytes()))
+    }
+
+    // Canonical finalization order shared by every node: block height, then content hash
+    pub fn finalization_key(&self) -> (u64, String) {
+        (self.block_height, self.content_hash())
+    }
+
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, NetworkError> {
+        postcard::to_allocvec(self).map_err(|e| NetworkError::MalformedMessage(e.to_string()))
+    }
+
+    // Binary payloads always carry a schema version, so there is no legacy
+    // form to up-convert; only newer versions are rejected
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<StateTransition, NetworkError> {
+        let transition: StateTransition = postcard::from_bytes(bytes)
+            .map_err(|e| NetworkError::MalformedMessage(e.to_string()))?;
+
+        if transition.schema_version > STATE_TRANSITION_SCHEMA_VERSION {
+            return Err(NetworkError::UnsupportedSchemaVersion {
+                found: transition.schema_version,
+                supported: STATE_TRANSITION_SCHEMA_VERSION,
+            });
+        }
+
+        Ok(transition)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestAction {
     pub action_type: ActionType,
     pub quest_id: String,
     pub choice_id: Option<String>,
-    pub parameters: HashMap<String, String>,
+    pub parameters: BTreeMap<String, String>,
     pub authenticity_proof: String,
 }
 
@@ -50,6 +206,10 @@ pub enum ActionType {
     InteractWithGovernor,
     UseSacredItem,
     PerformRitual,
+    // Objective actions shared with the core crate's ObjectiveKind
+    Study,
+    Practice,
+    Divination,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,7 +221,35 @@ pub struct StateConsequence {
     pub authenticity_impact: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl StateConsequence {
+    pub fn is_conditional(&self) -> bool {
+        matches!(self.duration, ConsequenceDuration::Conditional(_))
+    }
+
+    // Consequence undoing `applied`, the change this one actually made, if any
+    fn withdrawal(&self, applied: f64) -> Option<StateConsequence> {
+        if applied == 0.0 {
+            return None;
+        }
+        let consequence_type = match self.consequence_type {
+            additive if additive.is_additive() => additive,
+            ConsequenceType::ItemGain => ConsequenceType::ItemLoss,
+            ConsequenceType::ItemLoss => ConsequenceType::ItemGain,
+            ConsequenceType::AethyrAccess => ConsequenceType::AethyrRevoke,
+            ConsequenceType::AethyrRevoke => ConsequenceType::AethyrAccess,
+            _ => return None,
+        };
+        Some(StateConsequence {
+            consequence_type,
+            target: self.target.clone(),
+            value_change: -applied,
+            duration: ConsequenceDuration::Permanent,
+            authenticity_impact: 0.0,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConsequenceType {
     ReputationChange,
     TraditionMastery,
@@ -71,6 +259,47 @@ pub enum ConsequenceType {
     ItemLoss,
     AethyrAccess,
     WisdomUnlock,
+    // Never removes STARTING_AETHYR
+    AethyrRevoke,
+    // Target is a governor name; value_change shifts their mood
+    GovernorMood,
+}
+
+impl ConsequenceType {
+    // Numeric adjustments, which can be summed; the rest are set operations
+    // whose relative order matters
+    pub fn is_additive(&self) -> bool {
+        matches!(
+            self,
+            ConsequenceType::ReputationChange
+                | ConsequenceType::TraditionMastery
+                | ConsequenceType::GovernorRelationship
+                | ConsequenceType::EnergyModification
+                | ConsequenceType::GovernorMood
+        )
+    }
+}
+
+// Additive consequences sharing a (consequence_type, target) are summed into
+// the first one, so clamping applies once to the net change rather than after
+// each step. Set operations such as ItemGain and AethyrRevoke keep their order,
+// as do conditional consequences, which are applied and withdrawn on their own.
+pub fn merge_consequences(consequences: &[StateConsequence]) -> Vec<StateConsequence> {
+    let mut merged: Vec<StateConsequence> = Vec::with_capacity(consequences.len());
+    for consequence in consequences {
+        if consequence.consequence_type.is_additive() && !consequence.is_conditional() {
+            let existing = merged.iter_mut().find(|m| {
+                m.consequence_type == consequence.consequence_type && m.target == consequence.target && !m.is_conditional()
+            });
+            if let Some(existing) = existing {
+                existing.value_change += consequence.value_change;
+                existing.authenticity_impact += consequence.authenticity_impact;
+                continue;
+            }
+        }
+        merged.push(consequence.clone());
+    }
+    merged
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,7 +307,8 @@ pub enum ConsequenceDuration {
     Temporary,
     Permanent,
     QuestLine,
-    Conditional,
+    // In effect only while the condition holds against the current state
+    Conditional(Condition),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,41 +319,73 @@ pub struct ValidatorSignature {
     pub authenticity_score: f64,
 }
 
+// Announces the local state to peers so they can reconcile once sync resumes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconciliationRequest {
+    pub player_id: String,
+    pub state_hash: String,
+    pub block_height: u64,
+    pub timestamp: u64,
+    // Transitions held while sync was paused, now pending again
+    pub pending_transition_ids: Vec<String>,
+}
+
+// Transitions settled by one finalization pass, each list in finalization order
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FinalizationPass {
+    pub finalized: Vec<String>,
+    // Reached consensus, but built on a state that is no longer current
+    pub rejected_stale: Vec<String>,
+}
+
+// Why a pending transition has not yet reached consensus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTransitionStatus {
+    pub transition_id: String,
+    pub signature_count: usize,
+    pub required_signatures: usize,
+    // Known validators that have not signed, in validator order
+    pub missing_validators: Vec<String>,
+    pub age_blocks: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusState {
     pub canonical_state: StoryState,
     pub pending_transitions: Vec<StateTransition>,
-    pub validator_weights: HashMap<String, f64>,
+    pub validator_weights: BTreeMap<String, f64>,
     pub consensus_threshold: f64,
     pub last_finalized_block: u64,
 }
 
+// Aethyr that revocation consequences leave in place, matching the core crate
+const STARTING_AETHYR: u32 = 1;
+
 #[wasm_bindgen]
 pub struct TracStateManager {
     current_state: Option<StoryState>,
     pending_transitions: Vec<StateTransition>,
     validator_network: HashMap<String, ValidatorNode>,
     consensus_rules: ConsensusRules,
-    state_history: Vec<StoryState>,
+    // Recent canonical states, oldest first, at most max_state_history long
+    state_history: VecDeque<StoryState>,
     authenticity_validators: Vec<String>,
+    clock: Arc<dyn Clock>,
+    item_registry: SacredItemRegistry,
+    // Pending transitions set aside while P2P sync is paused
+    quarantined_transitions: Vec<StateTransition>,
+    // Set while P2P sync is paused; no transition is proposed, accepted or signed
+    sync_paused: bool,
+    // Reconciliation requests awaiting broadcast
+    reconciliation_queue: Vec<ReconciliationRequest>,
+    authenticity_scorer: AuthenticityScorer,
 }
 
 #[wasm_bindgen]
 impl TracStateManager {
     #[wasm_bindgen(constructor)]
     pub fn new() -> TracStateManager {
-        TracStateManager {
-            current_state: None,
-            pending_transitions: Vec::new(),
-            validator_network: HashMap::new(),
-            consensus_rules: ConsensusRules::default(),
-            state_history: Vec::new(),
-            authenticity_validators: vec![
-                "enochian_validator".to_string(),
-                "hermetic_validator".to_string(),
-                "tradition_validator".to_string(),
-            ],
-        }
+        TracStateManager::with_clock(Arc::new(SystemClock))
     }
 
     #[wasm_bindgen]
@@ -133,19 +395,21 @@ impl TracStateManager {
             current_quest_id: "welcome_quest".to_string(),
             completed_quests: vec![],
             active_branches: vec![],
-            governor_relationships: HashMap::new(),
+            governor_relationships: BTreeMap::new(),
             tradition_mastery: {
-                let mut mastery = HashMap::new();
+                let mut mastery = BTreeMap::new();
                 mastery.insert("Enochian".to_string(), 0.1);
                 mastery.insert("Hermetic_Qabalah".to_string(), 0.05);
                 mastery
             },
-            reputation_scores: HashMap::new(),
+            reputation_scores: BTreeMap::new(),
             energy_level: 25,
             aethyr_access: vec![],
             sacred_items: vec![],
             timestamp: self.get_current_timestamp(),
             state_hash: String::new(),
+            governor_moods: BTreeMap::new(),
+            conditional_effects: vec![],
         };
 
         let state_hash = self.calculate_state_hash(&initial_state);
@@ -153,7 +417,7 @@ impl TracStateManager {
         final_state.state_hash = state_hash;
 
         self.current_state = Some(final_state.clone());
-        self.state_history.push(final_state.clone());
+        self.record_state(final_state.clone());
 
         serde_json::to_string(&final_state).unwrap_or_else(|_| "{}".to_string())
     }
@@ -164,51 +428,146 @@ impl TracStateManager {
         quest_action: &str,
         authenticity_proof: &str
     ) -> String {
+        if self.sync_paused {
+            metrics::global().record_error_kind("sync_paused");
+            return "P2P sync paused".to_string();
+        }
+
         let action: QuestAction = match serde_json::from_str(quest_action) {
             Ok(action) => action,
-            Err(_) => return "Invalid quest action format".to_string(),
+            Err(_) => {
+                metrics::global().record_error_kind("invalid_action_format");
+                return "Invalid quest action format".to_string();
+            }
         };
 
         let current_state = match &self.current_state {
             Some(state) => state.clone(),
-            None => return "No current state initialized".to_string(),
+            None => {
+                metrics::global().record_error_kind("no_current_state");
+                return "No current state initialized".to_string();
+            }
         };
 
+        if let Err(e) = self.check_authenticity(&action) {
+            metrics::global().record_error_kind("insufficient_authenticity");
+            return e;
+        }
+
+        if let ActionType::UseSacredItem = action.action_type {
+            if let Err(e) = self.check_item_use(&action, &current_state) {
+                metrics::global().record_error_kind("item_unavailable");
+                return e;
+            }
+        }
+
         // Calculate consequences of the action
         let consequences = self.calculate_action_consequences(&action, &current_state);
         
-        // Apply consequences to create new state
-        let new_state = self.apply_consequences(&current_state, &consequences);
+        // Apply consequences to create new state, at the instant every peer will apply them
+        let (block_height, timestamp) = (self.get_current_block_height(), self.get_current_timestamp());
+        let new_state = self.apply_consequences(&current_state, &consequences, block_height, timestamp);
         
         // Create state transition
         let transition = StateTransition {
-            transition_id: format!("{}_{}", action.quest_id, self.get_current_timestamp()),
+            schema_version: STATE_TRANSITION_SCHEMA_VERSION,
+            transition_id: format!("{}_{}", action.quest_id, timestamp),
             from_state_hash: current_state.state_hash.clone(),
-            to_state_hash: self.calculate_state_hash(&new_state),
+            to_state_hash: new_state.state_hash.clone(),
             quest_action: action,
             consequences,
             validator_signatures: vec![],
-            timestamp: self.get_current_timestamp(),
-            block_height: self.get_current_block_height(),
+            timestamp,
+            block_height,
         };
 
         // Add to pending transitions for validation
         self.pending_transitions.push(transition.clone());
+        metrics::global().increment(Counter::TransitionsProposed);
 
         serde_json::to_string(&transition).unwrap_or_else(|_| "{}".to_string())
     }
 
+    #[wasm_bindgen]
+    pub fn receive_transition(&mut self, transition_json: &str) -> String {
+        self.receive(StateTransition::from_json(transition_json))
+    }
+
+    // Binary counterpart of receive_transition, for peers using postcard payloads
+    #[cfg(feature = "binary")]
+    #[wasm_bindgen]
+    pub fn receive_transition_bytes(&mut self, transition_bytes: &[u8]) -> String {
+        self.receive(StateTransition::from_bytes(transition_bytes))
+    }
+
+    // Postcard encoding of a pending transition, for broadcast to binary peers
+    #[cfg(feature = "binary")]
+    #[wasm_bindgen]
+    pub fn get_pending_transition_bytes(&self, transition_id: &str) -> Result<Vec<u8>, String> {
+        let transition = self.pending_transitions.iter()
+            .find(|t| t.transition_id == transition_id)
+            .ok_or_else(|| format!("Transition {} not pending", transition_id))?;
+        transition.to_bytes().map_err(|e| e.to_string())
+    }
+
+    // Postcard encoding of the current state, compact enough for an Ordinals inscription
+    #[cfg(feature = "binary")]
+    #[wasm_bindgen]
+    pub fn get_current_state_bytes(&self) -> Result<Vec<u8>, String> {
+        match &self.current_state {
+            Some(state) => state.to_bytes(),
+            None => Err("No current state".to_string()),
+        }
+    }
+
+    // Restore the current state from get_current_state_bytes output, such as an
+    // inscribed state. The stored hash must match the decoded content.
+    #[cfg(feature = "binary")]
+    #[wasm_bindgen]
+    pub fn load_state_bytes(&mut self, state_bytes: &[u8]) -> String {
+        let state = match StoryState::from_bytes(state_bytes) {
+            Ok(state) => state,
+            Err(e) => {
+                metrics::global().record_error_kind("malformed_message");
+                return e;
+            }
+        };
+        if state.state_hash != self.calculate_state_hash(&state) {
+            metrics::global().record_error_kind("state_hash_mismatch");
+            return "State hash does not match its content".to_string();
+        }
+
+        let message = format!("State loaded for {}", state.player_id);
+        self.current_state = Some(state.clone());
+        self.record_state(state);
+        message
+    }
+
     #[wasm_bindgen]
     pub fn validate_transition(&mut self, transition_id: &str, validator_id: &str) -> String {
+        if self.sync_paused {
+            metrics::global().record_error_kind("sync_paused");
+            return "P2P sync paused".to_string();
+        }
+
         let transition_index = match self.pending_transitions.iter().position(|t| t.transition_id == transition_id) {
             Some(index) => index,
-            None => return "Transition not found".to_string(),
+            None => {
+                metrics::global().record_error_kind("transition_not_found");
+                return "Transition not found".to_string();
+            }
         };
 
         let transition = &self.pending_transitions[transition_index];
         
-        // Perform authenticity validation
-        let authenticity_score = self.validate_authenticity(&transition.quest_action);
+        // Never sign a transition whose action falls short of the authenticity minimum
+        let authenticity_score = match self.check_authenticity(&transition.quest_action) {
+            Ok(score) => score,
+            Err(e) => {
+                metrics::global().record_error_kind("insufficient_authenticity");
+                return e;
+            }
+        };
         
         // Create validator signature
         let signature = ValidatorSignature {
@@ -223,12 +582,70 @@ impl TracStateManager {
 
         // Check if consensus is reached
         if self.check_consensus(&self.pending_transitions[transition_index]) {
-            self.finalize_transition(transition_index);
+            self.finalize_ready_transitions();
         }
 
         serde_json::to_string(&signature).unwrap_or_else(|_| "{}".to_string())
     }
 
+    // Finalize every pending transition that has reached consensus, e.g. ones
+    // received from peers with their signatures, as a FinalizationPass
+    #[wasm_bindgen]
+    pub fn process_ready_transitions(&mut self) -> String {
+        let pass = self.finalize_ready_transitions();
+        serde_json::to_string(&pass).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    // Drop pending transitions older than the validator timeout as of
+    // `current_block`. Returns how many were dropped.
+    #[wasm_bindgen]
+    pub fn timeout_stale_transitions(&mut self, current_block: u64) -> u32 {
+        let timeout_blocks = self.consensus_rules.timeout_blocks();
+        let before = self.pending_transitions.len();
+        self.pending_transitions.retain(|t| current_block.saturating_sub(t.block_height) <= timeout_blocks);
+
+        let dropped = (before - self.pending_transitions.len()) as u32;
+        for _ in 0..dropped {
+            metrics::global().record_error_kind("transition_timed_out");
+        }
+        dropped
+    }
+
+    #[wasm_bindgen]
+    pub fn set_consensus_threshold(&mut self, threshold: f64) -> String {
+        match ConsensusRules::validate_threshold(threshold) {
+            Ok(()) => {
+                self.consensus_rules.consensus_threshold = threshold;
+                "Consensus threshold updated".to_string()
+            },
+            Err(e) => e,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_max_state_history(&mut self, max_states: u32) -> String {
+        if max_states == 0 {
+            return "State history must keep at least one state".to_string();
+        }
+        self.consensus_rules.max_state_history = max_states as usize;
+        self.trim_state_history();
+        format!("State history capped at {} states", max_states)
+    }
+
+    #[wasm_bindgen]
+    pub fn register_sacred_item(&mut self, item_json: &str) -> String {
+        let item: SacredItem = match serde_json::from_str(item_json) {
+            Ok(item) => item,
+            Err(e) => return format!("Sacred item parsing error: {}", e),
+        };
+
+        let item_id = item.id.clone();
+        match self.item_registry.register(item) {
+            Ok(()) => format!("Sacred item {} registered", item_id),
+            Err(e) => e,
+        }
+    }
+
     #[wasm_bindgen]
     pub fn get_current_state(&self) -> String {
         match &self.current_state {
@@ -237,6 +654,49 @@ impl TracStateManager {
         }
     }
 
+    // Pause sync, setting every pending transition aside until it resumes and
+    // leaving the current state untouched. Returns how many were quarantined.
+    #[wasm_bindgen]
+    pub fn quarantine_pending_transitions(&mut self) -> u32 {
+        self.sync_paused = true;
+        let count = self.pending_transitions.len() as u32;
+        self.quarantined_transitions.append(&mut self.pending_transitions);
+        count
+    }
+
+    // Resume sync, returning quarantined transitions to pending and queueing a
+    // request announcing the current state hash. Returns the request as JSON.
+    #[wasm_bindgen]
+    pub fn request_reconciliation(&mut self) -> String {
+        self.sync_paused = false;
+        for transition in self.quarantined_transitions.drain(..) {
+            if !self.pending_transitions.iter().any(|t| t.transition_id == transition.transition_id) {
+                self.pending_transitions.push(transition);
+            }
+        }
+
+        let state = self.current_state.clone().unwrap_or_else(|| self.create_empty_state());
+        let request = ReconciliationRequest {
+            player_id: state.player_id,
+            state_hash: state.state_hash,
+            block_height: self.get_current_block_height(),
+            timestamp: self.get_current_timestamp(),
+            pending_transition_ids: self.pending_transitions.iter()
+                .map(|t| t.transition_id.clone())
+                .collect(),
+        };
+        self.reconciliation_queue.push(request.clone());
+
+        serde_json::to_string(&request).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    // Drain queued reconciliation requests for broadcast, as a JSON array
+    #[wasm_bindgen]
+    pub fn take_reconciliation_requests(&mut self) -> String {
+        let requests: Vec<ReconciliationRequest> = self.reconciliation_queue.drain(..).collect();
+        serde_json::to_string(&requests).unwrap_or_else(|_| "[]".to_string())
+    }
+
     #[wasm_bindgen]
     pub fn get_consensus_status(&self) -> String {
         let consensus_state = ConsensusState {
@@ -274,15 +734,34 @@ impl TracStateManager {
                 });
             },
             ActionType::InteractWithGovernor => {
-                // Governor relationship improvement
+                // Governor relationship improvement, larger while the governor is warm
                 let governor_name = action.parameters.get("governor_name").unwrap_or(&"unknown".to_string()).clone();
+                let mood = current_state.governor_moods.get(&governor_name)
+                    .map_or(0.0, |mood| mood.at(self.get_current_block_height()));
                 consequences.push(StateConsequence {
                     consequence_type: ConsequenceType::GovernorRelationship,
-                    target: governor_name,
-                    value_change: 0.15,
+                    target: governor_name.clone(),
+                    value_change: 0.15 * reward_multiplier(mood),
                     duration: ConsequenceDuration::Permanent,
                     authenticity_impact: 0.1,
                 });
+
+                // Authentic, successful interactions warm the governor; failures cool them
+                let succeeded = action.parameters.get("outcome").is_none_or(|outcome| outcome != "failure");
+                consequences.push(StateConsequence {
+                    consequence_type: ConsequenceType::GovernorMood,
+                    target: governor_name,
+                    value_change: mood_shift(self.check_authenticity(action).unwrap_or(0.0), succeeded),
+                    duration: ConsequenceDuration::Temporary,
+                    authenticity_impact: 0.0,
+                });
+            },
+            ActionType::UseSacredItem => {
+                let item = action.parameters.get("item_id")
+                    .and_then(|item_id| self.item_registry.get(item_id));
+                if let Some(item) = item {
+                    consequences.extend(self.item_effects(item));
+                }
             },
             ActionType::PerformRitual => {
                 // Energy cost
@@ -318,60 +797,282 @@ impl TracStateManager {
         consequences
     }
 
-    fn apply_consequences(&self, current_state: &StoryState, consequences: &[StateConsequence]) -> StoryState {
+    // Consequences are merged first (see merge_consequences), so duplicates
+    // for the same target are clamped once on their net change. Conditional
+    // consequences are held on the state, and every held condition is
+    // re-evaluated against the result. The block height and timestamp are the
+    // transition's, never this node's clock, so every peer reaches the same hash.
+    fn apply_consequences(&self, current_state: &StoryState, consequences: &[StateConsequence], block_height: u64, timestamp: u64) -> StoryState {
         let mut new_state = current_state.clone();
 
-        for consequence in consequences {
-            match consequence.consequence_type {
-                ConsequenceType::ReputationChange => {
-                    let current_rep = new_state.reputation_scores.get(&consequence.target).unwrap_or(&0.0);
-                    new_state.reputation_scores.insert(consequence.target.clone(), current_rep + consequence.value_change);
-                },
-                ConsequenceType::TraditionMastery => {
-                    let current_mastery = new_state.tradition_mastery.get(&consequence.target).unwrap_or(&0.0);
-                    new_state.tradition_mastery.insert(consequence.target.clone(), (current_mastery + consequence.value_change).min(1.0));
-                },
-                ConsequenceType::GovernorRelationship => {
-                    let current_rel = new_state.governor_relationships.get(&consequence.target).unwrap_or(&0.0);
-                    new_state.governor_relationships.insert(consequence.target.clone(), (current_rel + consequence.value_change).min(1.0));
-                },
-                ConsequenceType::EnergyModification => {
-                    new_state.energy_level = ((new_state.energy_level as f64) + consequence.value_change).max(0.0).min(25.0) as u32;
-                },
-                ConsequenceType::ItemGain => {
-                    new_state.sacred_items.push(consequence.target.clone());
-                },
-                ConsequenceType::AethyrAccess => {
-                    if let Ok(aethyr_id) = consequence.target.parse::<u32>() {
-                        if !new_state.aethyr_access.contains(&aethyr_id) {
-                            new_state.aethyr_access.push(aethyr_id);
-                        }
-                    }
-                },
-                _ => {} // Handle other consequence types as needed
+        for consequence in &merge_consequences(consequences) {
+            if consequence.is_conditional() {
+                new_state.conditional_effects.push(ConditionalEffect {
+                    consequence: consequence.clone(),
+                    applied: None,
+                    expires_at: Some(block_height + CONDITIONAL_EFFECT_LIFETIME_BLOCKS),
+                });
+            } else {
+                self.apply_consequence(&mut new_state, consequence, block_height);
             }
         }
+        self.retire_conditional_effects(&mut new_state, block_height);
+        self.reevaluate_conditions(&mut new_state, block_height);
 
-        new_state.timestamp = self.get_current_timestamp();
+        new_state.timestamp = timestamp;
         new_state.state_hash = self.calculate_state_hash(&new_state);
         new_state
     }
 
-    fn validate_authenticity(&self, action: &QuestAction) -> f64 {
-        // Simplified authenticity validation
-        let mut score = 0.85;
+    // Apply one consequence, returning the change actually made: the net
+    // change after clamping for additive types, 1.0 or 0.0 for set operations
+    fn apply_consequence(&self, state: &mut StoryState, consequence: &StateConsequence, block_height: u64) -> f64 {
+        match consequence.consequence_type {
+            ConsequenceType::ReputationChange => {
+                let current_rep = state.reputation_scores.get(&consequence.target).copied().unwrap_or(0.0);
+                state.reputation_scores.insert(consequence.target.clone(), current_rep + consequence.value_change);
+                consequence.value_change
+            },
+            ConsequenceType::TraditionMastery => {
+                let current_mastery = state.tradition_mastery.get(&consequence.target).copied().unwrap_or(0.0);
+                let new_mastery = (current_mastery + consequence.value_change).min(1.0);
+                state.tradition_mastery.insert(consequence.target.clone(), new_mastery);
+                new_mastery - current_mastery
+            },
+            ConsequenceType::GovernorRelationship => {
+                let current_rel = state.governor_relationships.get(&consequence.target).copied().unwrap_or(0.0);
+                let new_rel = (current_rel + consequence.value_change).min(1.0);
+                state.governor_relationships.insert(consequence.target.clone(), new_rel);
+                new_rel - current_rel
+            },
+            ConsequenceType::EnergyModification => {
+                // Same rounding as the core crate, so both land on the same whole energy
+                let current_energy = state.energy_level;
+                state.energy_level = Energy::saturating(current_energy).adjusted(consequence.value_change).value();
+                state.energy_level as f64 - current_energy as f64
+            },
+            ConsequenceType::ItemGain
+                if self.item_registry.contains(&consequence.target)
+                    && !state.sacred_items.contains(&consequence.target) =>
+            {
+                state.sacred_items.push(consequence.target.clone());
+                1.0
+            },
+            ConsequenceType::ItemLoss => {
+                let held = state.sacred_items.len();
+                state.sacred_items.retain(|item| item != &consequence.target);
+                if state.sacred_items.len() < held { 1.0 } else { 0.0 }
+            },
+            ConsequenceType::AethyrAccess => {
+                match consequence.target.parse::<u32>() {
+                    Ok(aethyr_id) if !state.aethyr_access.contains(&aethyr_id) => {
+                        state.aethyr_access.push(aethyr_id);
+                        1.0
+                    },
+                    _ => 0.0,
+                }
+            },
+            ConsequenceType::GovernorMood => {
+                let mood = state.governor_moods.get(&consequence.target)
+                    .copied()
+                    .unwrap_or_else(|| GovernorMood::neutral(block_height));
+                state.governor_moods.insert(consequence.target.clone(), mood.shifted(consequence.value_change, block_height));
+                consequence.value_change
+            },
+            ConsequenceType::AethyrRevoke => {
+                match consequence.target.parse::<u32>() {
+                    Ok(aethyr_id) if aethyr_id != STARTING_AETHYR && state.aethyr_access.contains(&aethyr_id) => {
+                        state.aethyr_access.retain(|aethyr| *aethyr != aethyr_id);
+                        1.0
+                    },
+                    _ => 0.0,
+                }
+            },
+            _ => 0.0, // Handle other consequence types as needed
+        }
+    }
+
+    // Drop expired effects, then the oldest beyond MAX_CONDITIONAL_EFFECTS,
+    // withdrawing whatever each still applies
+    fn retire_conditional_effects(&self, state: &mut StoryState, block_height: u64) {
+        let mut effects = std::mem::take(&mut state.conditional_effects);
+        effects.retain(|effect| {
+            let expired = effect.expires_at.is_some_and(|expires_at| block_height >= expires_at);
+            if expired {
+                self.withdraw_effect(state, effect, block_height);
+            }
+            !expired
+        });
+        let excess = effects.len().saturating_sub(MAX_CONDITIONAL_EFFECTS);
+        for effect in effects.drain(..excess) {
+            self.withdraw_effect(state, &effect, block_height);
+        }
+        state.conditional_effects = effects;
+    }
+
+    fn withdraw_effect(&self, state: &mut StoryState, effect: &ConditionalEffect, block_height: u64) {
+        if let Some(withdrawal) = effect.applied.and_then(|applied| effect.consequence.withdrawal(applied)) {
+            self.apply_consequence(state, &withdrawal, block_height);
+        }
+    }
+
+    // Apply held conditional effects whose condition now holds and withdraw
+    // those whose condition has lapsed. Each change can flip other conditions,
+    // so passes repeat until nothing changes, capped at one per effect so
+    // effects that undo each other's conditions cannot cycle forever. An
+    // effect that would falsify its own condition is never applied.
+    fn reevaluate_conditions(&self, state: &mut StoryState, block_height: u64) {
+        for _ in 0..=state.conditional_effects.len() {
+            let mut changed = false;
+            for index in 0..state.conditional_effects.len() {
+                let effect = state.conditional_effects[index].clone();
+                let condition = match &effect.consequence.duration {
+                    ConsequenceDuration::Conditional(condition) => Some(condition),
+                    _ => None,
+                };
+                let holds = condition.is_none_or(|condition| condition.holds(state));
+
+                match (holds, effect.applied) {
+                    (true, None) => {
+                        let mut trial = state.clone();
+                        let applied = self.apply_consequence(&mut trial, &effect.consequence, block_height);
+                        if !condition.is_none_or(|condition| condition.holds(&trial)) {
+                            continue;
+                        }
+                        *state = trial;
+                        state.conditional_effects[index].applied = Some(applied);
+                    },
+                    (false, Some(_)) => {
+                        self.withdraw_effect(state, &effect, block_height);
+                        state.conditional_effects[index].applied = None;
+                    },
+                    _ => continue,
+                }
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    fn check_item_use(&self, action: &QuestAction, current_state: &StoryState) -> Result<(), String> {
+        let item_id = action.parameters.get("item_id")
+            .ok_or_else(|| "Sacred item use requires an item_id parameter".to_string())?;
+
+        if !self.item_registry.contains(item_id) {
+            return Err(format!("Unknown sacred item {}", item_id));
+        }
+        if !current_state.sacred_items.contains(item_id) {
+            return Err(format!("Sacred item {} not held", item_id));
+        }
+
+        Ok(())
+    }
+
+    fn item_effects(&self, item: &SacredItem) -> Vec<StateConsequence> {
+        let mut effects = Vec::new();
+
+        if item.energy_change != 0.0 {
+            effects.push(StateConsequence {
+                consequence_type: ConsequenceType::EnergyModification,
+                target: "energy_level".to_string(),
+                value_change: item.energy_change,
+                duration: ConsequenceDuration::Temporary,
+                authenticity_impact: 0.0,
+            });
+        }
+
+        if item.mastery_change != 0.0 {
+            effects.push(StateConsequence {
+                consequence_type: ConsequenceType::TraditionMastery,
+                target: item.tradition.clone(),
+                value_change: item.mastery_change,
+                duration: ConsequenceDuration::Permanent,
+                authenticity_impact: 0.0,
+            });
+        }
+
+        if item.usage == ItemUsage::Consumable {
+            effects.push(StateConsequence {
+                consequence_type: ConsequenceType::ItemLoss,
+                target: item.id.clone(),
+                value_change: -1.0,
+                duration: ConsequenceDuration::Permanent,
+                authenticity_impact: 0.0,
+            });
+        }
+
+        // Credit the item's authenticity once rather than per effect
+        if let Some(first) = effects.first_mut() {
+            first.authenticity_impact = item.authenticity_impact;
+        }
+
+        effects
+    }
+
+    // Accept a transition relayed by a peer into the pending set, whichever
+    // encoding it arrived in
+    fn receive(&mut self, decoded: Result<StateTransition, NetworkError>) -> String {
+        if self.sync_paused {
+            metrics::global().record_error_kind("sync_paused");
+            return "P2P sync paused".to_string();
+        }
+
+        let transition = match decoded {
+            Ok(transition) => transition,
+            Err(e) => {
+                metrics::global().record_error_kind(e.kind());
+                return e.to_string();
+            }
+        };
+
+        if self.pending_transitions.iter().any(|t| t.transition_id == transition.transition_id) {
+            metrics::global().record_error_kind("duplicate_transition");
+            return "Transition already pending".to_string();
+        }
 
-        // Check for Enochian elements
-        if action.authenticity_proof.contains("enochian") {
-            score += 0.1;
+        // Peers may only grant items this node knows how to apply
+        if let Some(unknown) = transition.consequences.iter()
+            .filter(|c| matches!(c.consequence_type, ConsequenceType::ItemGain))
+            .find(|c| !self.item_registry.contains(&c.target))
+        {
+            metrics::global().record_error_kind("unknown_item");
+            return format!("Unknown sacred item {}", unknown.target);
         }
 
-        // Check for proper tradition alignment
-        if action.parameters.contains_key("tradition") {
-            score += 0.05;
+        if let Err(e) = self.check_authenticity(&transition.quest_action) {
+            metrics::global().record_error_kind("insufficient_authenticity");
+            return e;
         }
 
-        score.min(1.0)
+        self.pending_transitions.push(transition.clone());
+        metrics::global().increment(Counter::TransitionsReceived);
+        serde_json::to_string(&transition).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    // Score the action's proof with the AuthenticityScorer against its declared
+    // tradition. The scorer scales each tradition's scores by its weight, so the
+    // authenticity minimum is weighted the same way; the returned score is
+    // divided back out, putting every tradition on one scale.
+    fn check_authenticity(&self, action: &QuestAction) -> Result<f64, String> {
+        let tradition = action.parameters.get("tradition").map_or(DEFAULT_PROOF_TRADITION, String::as_str);
+        let weight = self.authenticity_scorer.tradition_weight(tradition)
+            .filter(|weight| *weight > 0.0)
+            .ok_or_else(|| format!("Tradition {} cannot be validated", tradition))?;
+        let score = self.authenticity_scorer
+            .calculate_authenticity(&action.authenticity_proof, tradition, ContentType::Quest, &[])
+            .map_err(|e| e.to_string())?
+            .overall_score;
+
+        let required = self.consensus_rules.authenticity_minimum * weight;
+        if score < required {
+            return Err(format!(
+                "Insufficient authenticity for {}: {:.3} below required {:.3}",
+                tradition, score, required
+            ));
+        }
+        Ok(score / weight)
     }
 
     fn create_signature(&self, transition: &StateTransition, validator_id: &str) -> String {
@@ -380,43 +1081,70 @@ impl TracStateManager {
     }
 
     fn check_consensus(&self, transition: &StateTransition) -> bool {
-        let required_signatures = (self.authenticity_validators.len() as f64 * self.consensus_rules.consensus_threshold).ceil() as usize;
+        let required_signatures = self.consensus_rules.required_signatures(self.authenticity_validators.len());
         transition.validator_signatures.len() >= required_signatures
     }
 
-    fn finalize_transition(&mut self, transition_index: usize) {
-        let transition = self.pending_transitions.remove(transition_index);
-        
-        // Apply the transition to current state
-        if let Some(current_state) = &self.current_state {
-            let new_state = self.apply_consequences(current_state, &transition.consequences);
+    // Finalize ready transitions in finalization_key order, so every node
+    // applies them identically; each must build on the state left by the one
+    // before, and any whose parent is no longer current is dropped
+    fn finalize_ready_transitions(&mut self) -> FinalizationPass {
+        let (mut ready, pending): (Vec<StateTransition>, Vec<StateTransition>) = std::mem::take(&mut self.pending_transitions)
+            .into_iter()
+            .partition(|transition| self.check_consensus(transition));
+        self.pending_transitions = pending;
+        ready.sort_by_cached_key(|transition| transition.finalization_key());
+
+        let mut pass = FinalizationPass::default();
+        for transition in ready {
+            let current_state = match &self.current_state {
+                Some(state) if state.state_hash == transition.from_state_hash => state,
+                _ => {
+                    metrics::global().record_error_kind("stale_parent");
+                    pass.rejected_stale.push(transition.transition_id);
+                    continue;
+                }
+            };
+
+            let new_state = self.apply_consequences(current_state, &transition.consequences, transition.block_height, transition.timestamp);
             self.current_state = Some(new_state.clone());
-            self.state_history.push(new_state);
+            self.record_state(new_state);
+            metrics::global().increment(Counter::TransitionsFinalized);
+            pass.finalized.push(transition.transition_id);
         }
+        pass
+    }
+
+    fn record_state(&mut self, state: StoryState) {
+        self.state_history.push_back(state);
+        self.trim_state_history();
     }
 
+    // Evict the oldest states beyond the cap
+    fn trim_state_history(&mut self) {
+        let excess = self.state_history.len().saturating_sub(self.consensus_rules.max_state_history);
+        self.state_history.drain(..excess);
+    }
+
+    // SHA-256 of the state's JSON with the hash itself blanked, so any change
+    // to the state, not just its quest count, yields a new hash
     fn calculate_state_hash(&self, state: &StoryState) -> String {
-        // Simplified hash calculation (in real implementation, use proper cryptographic hashing)
-        format!("hash_{}_{}_{}_{}", 
-            state.player_id, 
-            state.current_quest_id, 
-            state.timestamp,
-            state.completed_quests.len()
-        )
+        let mut content = state.clone();
+        content.state_hash = String::new();
+        let json = serde_json::to_string(&content).unwrap_or_default();
+        hex::encode(Sha256::digest(json.as_bytes()))
     }
 
     fn get_current_timestamp(&self) -> u64 {
-        // Simplified timestamp (in real implementation, use proper time source)
-        1642680000 + (self.state_history.len() as u64 * 600) // Simulate 10-minute intervals
+        self.clock.now().timestamp().max(0) as u64
     }
 
     fn get_current_block_height(&self) -> u64 {
-        // Simplified block height (in real implementation, query actual blockchain)
-        800000 + (self.state_history.len() as u64)
+        self.clock.block_height()
     }
 
-    fn get_validator_weights(&self) -> HashMap<String, f64> {
-        let mut weights = HashMap::new();
+    fn get_validator_weights(&self) -> BTreeMap<String, f64> {
+        let mut weights = BTreeMap::new();
         for validator in &self.authenticity_validators {
             weights.insert(validator.clone(), 1.0 / self.authenticity_validators.len() as f64);
         }
@@ -429,18 +1157,66 @@ impl TracStateManager {
             current_quest_id: "none".to_string(),
             completed_quests: vec![],
             active_branches: vec![],
-            governor_relationships: HashMap::new(),
-            tradition_mastery: HashMap::new(),
-            reputation_scores: HashMap::new(),
+            governor_relationships: BTreeMap::new(),
+            tradition_mastery: BTreeMap::new(),
+            reputation_scores: BTreeMap::new(),
             energy_level: 0,
             aethyr_access: vec![],
             sacred_items: vec![],
             timestamp: 0,
             state_hash: "empty".to_string(),
+            governor_moods: BTreeMap::new(),
+            conditional_effects: vec![],
         }
     }
 }
 
+// Clock injection isn't expressible across the WASM boundary
+impl TracStateManager {
+    pub fn with_clock(clock: Arc<dyn Clock>) -> TracStateManager {
+        TracStateManager {
+            current_state: None,
+            pending_transitions: Vec::new(),
+            validator_network: HashMap::new(),
+            consensus_rules: ConsensusRules::default(),
+            state_history: VecDeque::new(),
+            authenticity_validators: vec![
+                "enochian_validator".to_string(),
+                "hermetic_validator".to_string(),
+                "tradition_validator".to_string(),
+            ],
+            clock,
+            item_registry: SacredItemRegistry::new(),
+            quarantined_transitions: Vec::new(),
+            sync_paused: false,
+            reconciliation_queue: Vec::new(),
+            authenticity_scorer: AuthenticityScorer::new(),
+        }
+    }
+}
+
+// Consensus diagnostics for operators; the report isn't a WASM-compatible type
+impl TracStateManager {
+    // Each pending transition with its signatures against quorum, in pending order
+    pub fn pending_report(&self) -> Vec<PendingTransitionStatus> {
+        let required_signatures = self.consensus_rules.required_signatures(self.authenticity_validators.len());
+        let current_block = self.get_current_block_height();
+
+        self.pending_transitions.iter()
+            .map(|transition| PendingTransitionStatus {
+                transition_id: transition.transition_id.clone(),
+                signature_count: transition.validator_signatures.len(),
+                required_signatures,
+                missing_validators: self.authenticity_validators.iter()
+                    .filter(|validator| !transition.validator_signatures.iter().any(|s| &s.validator_id == *validator))
+                    .cloned()
+                    .collect(),
+                age_blocks: current_block.saturating_sub(transition.block_height),
+            })
+            .collect()
+    }
+}
+
 // Supporting structures
 pub struct ValidatorNode {
     pub node_id: String,
@@ -455,15 +1231,864 @@ pub struct ConsensusRules {
     pub max_pending_transitions: usize,
     pub authenticity_minimum: f64,
     pub validator_timeout: u64,
+    // Canonical states kept in history; older ones are evicted
+    pub max_state_history: usize,
+}
+
+impl ConsensusRules {
+    // Thresholds at or below one half would allow two conflicting
+    // transitions to both reach quorum
+    pub fn validate_threshold(threshold: f64) -> Result<(), String> {
+        if threshold > 0.5 && threshold <= 1.0 {
+            Ok(())
+        } else {
+            Err(format!("Consensus threshold must be in (0.5, 1.0], got {}", threshold))
+        }
+    }
+
+    // Signatures needed for quorum among `validator_count` validators.
+    // With the default 2/3 threshold:
+    //
+    //   validators | 1 | 2 | 3 | 4 | 5 | 6 | 7
+    //   required   | 1 | 2 | 2 | 3 | 4 | 4 | 5
+    pub fn required_signatures(&self, validator_count: usize) -> usize {
+        // A lone validator (or an empty set) still needs one signature
+        if validator_count <= 1 {
+            return 1;
+        }
+
+        // Tolerate float error so 3 * (2/3) yields 2 rather than 3
+        let by_threshold = (validator_count as f64 * self.consensus_threshold - 1e-9).ceil() as usize;
+
+        // Even counts must still clear a strict majority
+        let strict_majority = validator_count / 2 + 1;
+
+        by_threshold.max(strict_majority).min(validator_count)
+    }
+
+    // validator_timeout is in seconds; pending transitions expire after this
+    // many blocks at the 10-minute target interval
+    pub fn timeout_blocks(&self) -> u64 {
+        (self.validator_timeout / TARGET_BLOCK_INTERVAL as u64).max(1)
+    }
 }
 
 impl Default for ConsensusRules {
     fn default() -> Self {
         ConsensusRules {
-            consensus_threshold: 0.67, // 2/3 majority
+            consensus_threshold: 2.0 / 3.0, // 2/3 majority
             max_pending_transitions: 100,
             authenticity_minimum: 0.85,
             validator_timeout: 3600, // 1 hour
+            max_state_history: 256,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use enochian_cyphers::calibration::CORPUS;
+    use enochian_cyphers::runtime::FixedClock;
+
+    // Clock shared with the manager under test, so a test can advance it after
+    // injecting it
+    fn test_clock() -> Arc<FixedClock> {
+        Arc::new(FixedClock::at_unix(1_700_000_000))
+    }
+
+    // Genuine passages of `tradition` from the core calibration corpus, joined;
+    // no single passage clears the default authenticity minimum
+    fn corpus_proof(tradition: &str, passages: usize) -> String {
+        CORPUS.iter()
+            .filter(|sample| sample.tradition == tradition && sample.authentic)
+            .take(passages)
+            .map(|sample| sample.text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // Clears the default authenticity minimum and the mood baseline (0.93)
+    fn authentic_proof() -> String {
+        corpus_proof("Enochian", 3)
+    }
+
+    // Clears the default authenticity minimum but not the mood baseline (0.89)
+    fn weak_proof() -> String {
+        corpus_proof("Enochian", 2)
+    }
+
+    fn proposed_transition(manager: &mut TracStateManager) -> serde_json::Value {
+        manager.initialize_player_state("player");
+        let action = serde_json::json!({
+            "action_type": "CompleteQuest",
+            "quest_id": "quest_1_42",
+            "choice_id": null,
+            "parameters": {},
+            "authenticity_proof": authentic_proof()
+        });
+        serde_json::from_str(&manager.propose_state_transition(&action.to_string(), "proof")).unwrap()
+    }
+
+    #[test]
+    fn test_transitions_record_injected_clock() {
+        let clock = test_clock();
+        let mut manager = TracStateManager::with_clock(clock.clone());
+        let start_block = clock.block_height();
+
+        let transition = proposed_transition(&mut manager);
+        assert_eq!(transition["block_height"], start_block);
+        assert_eq!(transition["timestamp"], 1_700_000_000u64);
+        assert_eq!(manager.current_state.as_ref().unwrap().timestamp, 1_700_000_000);
+
+        clock.advance(600);
+        let transition = proposed_transition(&mut manager);
+        assert_eq!(transition["block_height"], start_block + 1);
+        assert_eq!(transition["timestamp"], 1_700_000_600u64);
+
+        let status: serde_json::Value = serde_json::from_str(&manager.get_consensus_status()).unwrap();
+        assert_eq!(status["last_finalized_block"], start_block + 1);
+    }
+
+    fn propose(manager: &mut TracStateManager, quest_id: &str) -> serde_json::Value {
+        let action = serde_json::json!({
+            "action_type": "CompleteQuest",
+            "quest_id": quest_id,
+            "choice_id": null,
+            "parameters": {},
+            "authenticity_proof": authentic_proof()
+        });
+        serde_json::from_str(&manager.propose_state_transition(&action.to_string(), "proof")).unwrap()
+    }
+
+    // The transition as a peer would relay it once every validator has signed
+    fn signed(transition: &serde_json::Value) -> String {
+        let mut transition = transition.clone();
+        transition["validator_signatures"] = ["enochian_validator", "hermetic_validator", "tradition_validator"].iter()
+            .map(|validator| serde_json::json!({
+                "validator_id": validator,
+                "signature": format!("{}_signature", validator),
+                "validation_timestamp": 1_700_000_000u64,
+                "authenticity_score": 1.0
+            }))
+            .collect();
+        transition.to_string()
+    }
+
+    fn receiving_node(transitions: &[&serde_json::Value]) -> (TracStateManager, FinalizationPass) {
+        let mut node = TracStateManager::with_clock(test_clock());
+        node.initialize_player_state("player");
+        for transition in transitions {
+            node.receive_transition(&signed(transition));
+        }
+        let pass = serde_json::from_str(&node.process_ready_transitions()).unwrap();
+        (node, pass)
+    }
+
+    #[test]
+    fn test_ready_transitions_finalize_in_canonical_order() {
+        let clock = test_clock();
+        let mut proposer = TracStateManager::with_clock(clock.clone());
+        proposer.initialize_player_state("player");
+        let first = propose(&mut proposer, "quest_a");
+        for validator in ["enochian_validator", "hermetic_validator", "tradition_validator"] {
+            proposer.validate_transition(first["transition_id"].as_str().unwrap(), validator);
+        }
+        clock.advance(TARGET_BLOCK_INTERVAL);
+        let second = propose(&mut proposer, "quest_b");
+        assert_eq!(second["from_state_hash"], proposer.current_state.as_ref().unwrap().state_hash.as_str());
+
+        // Arrival order does not matter: block height puts the parent first
+        let (node, pass) = receiving_node(&[&second, &first]);
+        let (other, other_pass) = receiving_node(&[&first, &second]);
+        assert_eq!(pass.finalized, vec![first["transition_id"].as_str().unwrap(), second["transition_id"].as_str().unwrap()]);
+        assert!(pass.rejected_stale.is_empty());
+        assert_eq!(pass, other_pass);
+        assert_eq!(node.get_current_state(), other.get_current_state());
+        assert!(node.pending_transitions.is_empty());
+    }
+
+    #[test]
+    fn test_same_inputs_serialize_identically() {
+        // Each run builds its maps afresh, so hash-ordered maps would differ
+        let run = || {
+            let mut manager = TracStateManager::with_clock(test_clock());
+            let transition = proposed_transition(&mut manager);
+            (manager.get_current_state(), transition.to_string())
+        };
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn test_peers_with_different_clocks_reach_same_state() {
+        let mut proposer = TracStateManager::with_clock(test_clock());
+        proposer.initialize_player_state("player");
+        let mut peer = TracStateManager::with_clock(Arc::new(FixedClock::at_unix(1_700_090_000)));
+        peer.current_state = proposer.current_state.clone();
+
+        // Mood shifts and decay depend on the block height they are applied at
+        let action = serde_json::json!({
+            "action_type": "InteractWithGovernor",
+            "quest_id": "quest_1_42",
+            "choice_id": null,
+            "parameters": { "governor_name": "ABRIOND" },
+            "authenticity_proof": authentic_proof()
+        });
+        let transition: serde_json::Value = serde_json::from_str(&proposer.propose_state_transition(&action.to_string(), "proof")).unwrap();
+        assert!(transition["consequences"].as_array().unwrap().iter().any(|c| c["consequence_type"] == "GovernorMood"));
+        for validator in ["enochian_validator", "hermetic_validator", "tradition_validator"] {
+            proposer.validate_transition(transition["transition_id"].as_str().unwrap(), validator);
         }
+        peer.receive_transition(&signed(&transition));
+        let pass: FinalizationPass = serde_json::from_str(&peer.process_ready_transitions()).unwrap();
+        assert_eq!(pass.finalized.len(), 1);
+
+        let proposer_state = proposer.current_state.as_ref().unwrap();
+        assert_eq!(proposer_state.state_hash, transition["to_state_hash"].as_str().unwrap());
+        assert_eq!(peer.current_state.as_ref().unwrap().state_hash, proposer_state.state_hash);
+        assert_eq!(proposer_state.governor_moods["ABRIOND"], peer.current_state.as_ref().unwrap().governor_moods["ABRIOND"]);
+    }
+
+    #[test]
+    fn test_stale_parent_transition_rejected() {
+        let mut proposer = TracStateManager::with_clock(test_clock());
+        proposer.initialize_player_state("player");
+        let left = propose(&mut proposer, "quest_a");
+        let right = propose(&mut proposer, "quest_b");
+        assert_eq!(left["from_state_hash"], right["from_state_hash"]);
+
+        // Same block and parent: the lower content hash wins, the other is stale
+        let content_hash = |transition: &serde_json::Value| StateTransition::from_json(&signed(transition)).unwrap().content_hash();
+        let (winner, loser) = if content_hash(&left) < content_hash(&right) { (&left, &right) } else { (&right, &left) };
+        for arrivals in [[&left, &right], [&right, &left]] {
+            let (node, pass) = receiving_node(&arrivals);
+            assert_eq!(pass.finalized, vec![winner["transition_id"].as_str().unwrap()]);
+            assert_eq!(pass.rejected_stale, vec![loser["transition_id"].as_str().unwrap()]);
+            assert!(node.pending_transitions.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_state_history_stays_within_cap() {
+        let clock = test_clock();
+        let mut manager = TracStateManager::with_clock(clock.clone());
+        assert_eq!(manager.set_max_state_history(0), "State history must keep at least one state");
+        assert_eq!(manager.set_max_state_history(4), "State history capped at 4 states");
+
+        for i in 0..10 {
+            manager.initialize_player_state(&format!("player_{}", i));
+            assert!(manager.state_history.len() <= 4);
+        }
+        assert_eq!(manager.state_history.len(), 4);
+        assert_eq!(manager.state_history.front().unwrap().player_id, "player_6");
+        assert_eq!(manager.state_history.back().unwrap().player_id, "player_9");
+
+        // Timestamps follow the clock, not how many states have been recorded
+        assert!(manager.state_history.iter().all(|state| state.timestamp == 1_700_000_000));
+        clock.advance(600);
+        let state: StoryState = serde_json::from_str(&manager.initialize_player_state("late")).unwrap();
+        assert_eq!(state.timestamp, 1_700_000_600);
+
+        manager.set_max_state_history(2);
+        assert_eq!(manager.state_history.len(), 2);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_transitions_increment_metrics() {
+        // Other tests share the process-wide registry, so compare deltas
+        let metrics = metrics::global();
+        let proposed = metrics.get(Counter::TransitionsProposed);
+        let finalized = metrics.get(Counter::TransitionsFinalized);
+        let not_found = || metrics.snapshot()["errors"]["transition_not_found"].as_u64().unwrap_or(0);
+        let not_found_before = not_found();
+
+        let mut manager = TracStateManager::new();
+        let transition = proposed_transition(&mut manager);
+        let transition_id = transition["transition_id"].as_str().unwrap();
+        manager.validate_transition(transition_id, "enochian_validator");
+        manager.validate_transition(transition_id, "hermetic_validator");
+        manager.validate_transition(transition_id, "tradition_validator");
+        manager.validate_transition(transition_id, "enochian_validator");
+
+        assert!(metrics.get(Counter::TransitionsProposed) > proposed);
+        assert!(metrics.get(Counter::TransitionsFinalized) > finalized);
+        // Finalized transitions are no longer pending
+        assert!(not_found() > not_found_before);
+    }
+
+    #[test]
+    fn test_quarantine_and_reconcile_keep_state() {
+        let mut manager = TracStateManager::with_clock(test_clock());
+        let transition = proposed_transition(&mut manager);
+        let state_before = manager.get_current_state();
+
+        assert_eq!(manager.quarantine_pending_transitions(), 1);
+        assert!(manager.pending_transitions.is_empty());
+        assert_eq!(manager.get_current_state(), state_before);
+
+        let request: ReconciliationRequest = serde_json::from_str(&manager.request_reconciliation()).unwrap();
+        assert_eq!(request.player_id, "player");
+        assert_eq!(request.state_hash, manager.current_state.as_ref().unwrap().state_hash);
+        assert_eq!(request.pending_transition_ids, vec![transition["transition_id"].as_str().unwrap().to_string()]);
+        assert_eq!(manager.pending_transitions.len(), 1);
+
+        let queued: Vec<ReconciliationRequest> = serde_json::from_str(&manager.take_reconciliation_requests()).unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(manager.take_reconciliation_requests(), "[]");
+    }
+
+    fn use_item(manager: &mut TracStateManager, item_id: &str) -> String {
+        let action = serde_json::json!({
+            "action_type": "UseSacredItem",
+            "quest_id": "quest_1_42",
+            "choice_id": null,
+            "parameters": { "item_id": item_id },
+            "authenticity_proof": authentic_proof()
+        });
+        manager.propose_state_transition(&action.to_string(), "proof")
+    }
+
+    // Apply consequences at the manager's own clock, as a proposal does
+    fn apply_now(manager: &TracStateManager, state: &StoryState, consequences: &[StateConsequence]) -> StoryState {
+        manager.apply_consequences(state, consequences, manager.get_current_block_height(), manager.get_current_timestamp())
+    }
+
+    fn apply_transition(manager: &TracStateManager, transition_json: &str) -> StoryState {
+        let transition = StateTransition::from_json(transition_json).unwrap();
+        apply_now(manager, manager.current_state.as_ref().unwrap(), &transition.consequences)
+    }
+
+    #[test]
+    fn test_consumable_item_restores_energy_and_is_consumed() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+        let state = manager.current_state.as_mut().unwrap();
+        state.energy_level = 10;
+        state.sacred_items.push("consecrated_incense".to_string());
+
+        let transition = use_item(&mut manager, "consecrated_incense");
+        let new_state = apply_transition(&manager, &transition);
+        assert_eq!(new_state.energy_level, 15);
+        assert!(!new_state.sacred_items.contains(&"consecrated_incense".to_string()));
+
+        // Using it again without holding it is rejected
+        manager.current_state = Some(new_state);
+        assert_eq!(use_item(&mut manager, "consecrated_incense"), "Sacred item consecrated_incense not held");
+    }
+
+    #[test]
+    fn test_permanent_item_boosts_mastery_and_is_kept() {
+        let mut manager = TracStateManager::new();
+        let item = serde_json::json!({
+            "id": "tree_of_life_diagram",
+            "name": "Tree of Life Diagram",
+            "tradition": "Hermetic_Qabalah",
+            "energy_change": 0.0,
+            "mastery_change": 0.1,
+            "authenticity_impact": 0.05,
+            "usage": "Permanent"
+        });
+        assert_eq!(manager.register_sacred_item(&item.to_string()), "Sacred item tree_of_life_diagram registered");
+        manager.initialize_player_state("player");
+        manager.current_state.as_mut().unwrap().sacred_items.push("tree_of_life_diagram".to_string());
+
+        let transition = use_item(&mut manager, "tree_of_life_diagram");
+        let new_state = apply_transition(&manager, &transition);
+        assert!((new_state.tradition_mastery["Hermetic_Qabalah"] - 0.15).abs() < 1e-9);
+        assert!(new_state.sacred_items.contains(&"tree_of_life_diagram".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_items_rejected() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+        assert_eq!(use_item(&mut manager, "philosophers_stone"), "Unknown sacred item philosophers_stone");
+
+        let mut transition = proposed_transition(&mut manager);
+        transition["transition_id"] = serde_json::json!("remote_1");
+        transition["consequences"] = serde_json::json!([{
+            "consequence_type": "ItemGain",
+            "target": "philosophers_stone",
+            "value_change": 1.0,
+            "duration": "Permanent",
+            "authenticity_impact": 0.0
+        }]);
+        assert_eq!(manager.receive_transition(&transition.to_string()), "Unknown sacred item philosophers_stone");
+    }
+
+    // Authentic interactions carry a proof above the mood baseline, weak ones
+    // a proof that clears the authenticity minimum but falls below the baseline
+    fn interact(manager: &mut TracStateManager, authentic: bool) -> f64 {
+        let proof = if authentic { authentic_proof() } else { weak_proof() };
+        let action = serde_json::json!({
+            "action_type": "InteractWithGovernor",
+            "quest_id": "quest_1_42",
+            "choice_id": null,
+            "parameters": { "governor_name": "ABRIOND", "tradition": "Enochian" },
+            "authenticity_proof": proof
+        });
+        let before = manager.current_state.as_ref().unwrap().governor_relationships.get("ABRIOND").copied().unwrap_or(0.0);
+        let transition = manager.propose_state_transition(&action.to_string(), "proof");
+        let new_state = apply_transition(manager, &transition);
+        let gain = new_state.governor_relationships["ABRIOND"] - before;
+        manager.current_state = Some(new_state);
+        gain
+    }
+
+    fn mood(manager: &TracStateManager) -> f64 {
+        let height = manager.get_current_block_height();
+        manager.current_state.as_ref().unwrap().governor_moods["ABRIOND"].at(height)
+    }
+
+    #[test]
+    fn test_authentic_streak_warms_governor_and_rewards() {
+        let clock = test_clock();
+        let mut manager = TracStateManager::with_clock(clock.clone());
+        manager.initialize_player_state("player");
+
+        let gains: Vec<f64> = (0..3).map(|_| interact(&mut manager, true)).collect();
+        assert!((gains[0] - 0.15).abs() < 1e-9);
+        assert!(gains[1] > gains[0] && gains[2] > gains[1]);
+        let warm = mood(&manager);
+        assert!(warm > 0.0);
+
+        // Mood drifts back toward neutral as blocks pass
+        clock.advance(crate::governor_mood::MOOD_HALF_LIFE_BLOCKS as i64 * TARGET_BLOCK_INTERVAL);
+        assert!((mood(&manager) - warm / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weak_interactions_cool_governor() {
+        let mut manager = TracStateManager::with_clock(test_clock());
+        manager.initialize_player_state("player");
+
+        interact(&mut manager, false);
+        interact(&mut manager, false);
+        assert!(mood(&manager) < 0.0);
+        assert!(interact(&mut manager, false) < 0.15);
+    }
+
+    #[test]
+    fn test_aethyr_revoke_keeps_starting_aethyr() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+        manager.current_state.as_mut().unwrap().aethyr_access = vec![1, 12];
+
+        let revoke = |target: &str| StateConsequence {
+            consequence_type: ConsequenceType::AethyrRevoke,
+            target: target.to_string(),
+            value_change: 0.0,
+            duration: ConsequenceDuration::Permanent,
+            authenticity_impact: 0.0,
+        };
+        let new_state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &[revoke("12"), revoke("1")]);
+        assert_eq!(new_state.aethyr_access, vec![1]);
+    }
+
+    #[test]
+    fn test_duplicate_mastery_consequences_clamp_on_net_change() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+        manager.current_state.as_mut().unwrap().tradition_mastery.insert("Enochian".to_string(), 0.9);
+
+        let mastery = |change: f64| StateConsequence {
+            consequence_type: ConsequenceType::TraditionMastery,
+            target: "Enochian".to_string(),
+            value_change: change,
+            duration: ConsequenceDuration::Permanent,
+            authenticity_impact: 0.0,
+        };
+        let consequences = [mastery(0.3), mastery(-0.2)];
+
+        // Applied one at a time, the first would clamp at 1.0 and leave 0.8
+        let merged = merge_consequences(&consequences);
+        assert_eq!(merged.len(), 1);
+        assert!((merged[0].value_change - 0.1).abs() < 1e-12);
+
+        let new_state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &consequences);
+        assert!((new_state.tradition_mastery["Enochian"] - 1.0).abs() < 1e-12);
+
+        // Set operations are not merged
+        let gain = StateConsequence {
+            consequence_type: ConsequenceType::ItemGain,
+            target: "wand".to_string(),
+            ..mastery(0.0)
+        };
+        assert_eq!(merge_consequences(&[gain.clone(), gain]).len(), 2);
+    }
+
+    #[test]
+    fn test_fractional_energy_matches_core_rounding() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+        let energy_change = |change: f64| StateConsequence {
+            consequence_type: ConsequenceType::EnergyModification,
+            target: "energy_level".to_string(),
+            value_change: change,
+            duration: ConsequenceDuration::Permanent,
+            authenticity_impact: 0.0,
+        };
+
+        let cases = [(10, 2.5), (11, 2.5), (10, -0.5), (3, 3.7), (0, -0.4), (1, -1.5), (0, -5.0), (24, 1.5), (25, 0.5), (25, 3.0)];
+        for (energy, change) in cases {
+            manager.current_state.as_mut().unwrap().energy_level = energy;
+            let new_state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &[energy_change(change)]);
+            let core_energy = Energy::new(energy).unwrap().adjusted(change).value();
+            assert_eq!(new_state.energy_level, core_energy, "{} energy {:+}", energy, change);
+        }
+
+        // Ties round half to even on the change itself, not the resulting energy
+        manager.current_state.as_mut().unwrap().energy_level = 11;
+        let new_state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &[energy_change(2.5)]);
+        assert_eq!(new_state.energy_level, 13);
+    }
+
+    fn mastery_change(change: f64) -> StateConsequence {
+        StateConsequence {
+            consequence_type: ConsequenceType::TraditionMastery,
+            target: "Enochian".to_string(),
+            value_change: change,
+            duration: ConsequenceDuration::Permanent,
+            authenticity_impact: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_conditional_consequence_follows_its_condition() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+
+        // A peer's transition grants favour with ABRIOND only while Enochian mastery exceeds 0.5
+        let conditional: StateConsequence = serde_json::from_value(serde_json::json!({
+            "consequence_type": "GovernorRelationship",
+            "target": "ABRIOND",
+            "value_change": 0.2,
+            "duration": { "Conditional": { "Mastery": { "tradition": "Enochian", "comparison": "Above", "value": 0.5 } } },
+            "authenticity_impact": 0.0
+        })).unwrap();
+        let relationship = |state: &StoryState| state.governor_relationships.get("ABRIOND").copied().unwrap_or(0.0);
+
+        let state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &[conditional]);
+        assert_eq!(relationship(&state), 0.0);
+        assert_eq!(state.conditional_effects.len(), 1);
+        assert_eq!(state.conditional_effects[0].applied, None);
+
+        let state = apply_now(&manager, &state, &[mastery_change(0.5)]);
+        assert!((relationship(&state) - 0.2).abs() < 1e-12);
+
+        // Losing mastery withdraws the favour, and regaining it restores it
+        let state = apply_now(&manager, &state, &[mastery_change(-0.3)]);
+        assert!(relationship(&state).abs() < 1e-12);
+        assert_eq!(state.conditional_effects[0].applied, None);
+        let state = apply_now(&manager, &state, &[mastery_change(0.3)]);
+        assert!((relationship(&state) - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_conditional_withdrawal_reverses_only_what_was_applied() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+        let state = manager.current_state.as_mut().unwrap();
+        state.tradition_mastery.insert("Enochian".to_string(), 0.6);
+        state.governor_relationships.insert("ABRIOND".to_string(), 0.9);
+        state.aethyr_access = vec![1];
+
+        let condition = Condition::Mastery {
+            tradition: "Enochian".to_string(),
+            comparison: crate::conditions::Comparison::AtLeast,
+            value: 0.5,
+        };
+        let relationship = StateConsequence {
+            consequence_type: ConsequenceType::GovernorRelationship,
+            target: "ABRIOND".to_string(),
+            value_change: 0.3,
+            duration: ConsequenceDuration::Conditional(condition.clone()),
+            authenticity_impact: 0.0,
+        };
+        let access = |aethyr: &str| StateConsequence {
+            consequence_type: ConsequenceType::AethyrAccess,
+            target: aethyr.to_string(),
+            ..relationship.clone()
+        };
+
+        let state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &[relationship.clone(), access("12"), access("1")]);
+        assert_eq!(state.governor_relationships["ABRIOND"], 1.0);
+        assert_eq!(state.aethyr_access, vec![1, 12]);
+
+        // The relationship gain clamped at 0.1, so only that is taken back, and
+        // access the player already had before the condition is kept
+        let state = apply_now(&manager, &state, &[mastery_change(-0.2)]);
+        assert!((state.governor_relationships["ABRIOND"] - 0.9).abs() < 1e-12);
+        assert_eq!(state.aethyr_access, vec![1]);
+    }
+
+    #[test]
+    fn test_self_invalidating_condition_never_applies() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+
+        // The gain would lift mastery out of its own condition
+        let self_invalidating = StateConsequence {
+            duration: ConsequenceDuration::Conditional(Condition::Mastery {
+                tradition: "Enochian".to_string(),
+                comparison: crate::conditions::Comparison::Below,
+                value: 0.3,
+            }),
+            ..mastery_change(0.3)
+        };
+        let unrelated = StateConsequence {
+            target: "Thelema".to_string(),
+            ..self_invalidating.clone()
+        };
+
+        // The outcome no longer depends on how many effects set the pass count
+        for consequences in [vec![self_invalidating.clone()], vec![unrelated, self_invalidating]] {
+            let state = apply_now(&manager, manager.current_state.as_ref().unwrap(), &consequences);
+            assert!((state.tradition_mastery["Enochian"] - 0.1).abs() < 1e-12);
+            assert_eq!(state.conditional_effects.last().unwrap().applied, None);
+        }
+    }
+
+    #[test]
+    fn test_conditional_effects_expire_and_stay_capped() {
+        let clock = test_clock();
+        let mut manager = TracStateManager::with_clock(clock.clone());
+        manager.initialize_player_state("player");
+        let favour = |governor: String| StateConsequence {
+            consequence_type: ConsequenceType::GovernorRelationship,
+            target: governor,
+            value_change: 0.1,
+            duration: ConsequenceDuration::Conditional(Condition::All(vec![])),
+            authenticity_impact: 0.0,
+        };
+
+        let mut state = manager.current_state.clone().unwrap();
+        for i in 0..MAX_CONDITIONAL_EFFECTS + 5 {
+            state = apply_now(&manager, &state, &[favour(format!("governor_{}", i))]);
+        }
+        assert_eq!(state.conditional_effects.len(), MAX_CONDITIONAL_EFFECTS);
+        assert_eq!(state.conditional_effects[0].consequence.target, "governor_5");
+        // Retired effects take back what they granted
+        assert!(state.governor_relationships["governor_4"].abs() < 1e-12);
+        assert!((state.governor_relationships["governor_5"] - 0.1).abs() < 1e-12);
+
+        clock.advance(CONDITIONAL_EFFECT_LIFETIME_BLOCKS as i64 * TARGET_BLOCK_INTERVAL);
+        let state = apply_now(&manager, &state, &[mastery_change(0.0)]);
+        assert!(state.conditional_effects.is_empty());
+        assert!(state.governor_relationships.values().all(|relationship| relationship.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_pending_report_lists_missing_validators() {
+        let clock = test_clock();
+        let mut manager = TracStateManager::with_clock(clock.clone());
+        let transition_id = proposed_transition(&mut manager)["transition_id"].as_str().unwrap().to_string();
+        manager.validate_transition(&transition_id, "enochian_validator");
+        clock.advance(1200);
+
+        let report = manager.pending_report();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].transition_id, transition_id);
+        assert_eq!(report[0].signature_count, 1);
+        assert_eq!(report[0].required_signatures, 2);
+        assert_eq!(report[0].missing_validators, vec!["hermetic_validator", "tradition_validator"]);
+        assert_eq!(report[0].age_blocks, 2);
+    }
+
+    #[test]
+    fn test_stale_transitions_time_out() {
+        let clock = test_clock();
+        let mut manager = TracStateManager::with_clock(clock.clone());
+        let start_block = clock.block_height();
+        proposed_transition(&mut manager);
+        let timeout_blocks = manager.consensus_rules.timeout_blocks();
+        assert_eq!(timeout_blocks, 6);
+
+        assert_eq!(manager.timeout_stale_transitions(start_block + timeout_blocks), 0);
+        assert_eq!(manager.pending_report().len(), 1);
+
+        assert_eq!(manager.timeout_stale_transitions(start_block + timeout_blocks + 1), 1);
+        assert!(manager.pending_report().is_empty());
+    }
+
+    #[test]
+    fn test_quorum_table() {
+        let rules = ConsensusRules::default();
+        let expected = [(1, 1), (2, 2), (3, 2), (4, 3), (5, 4), (6, 4), (7, 5)];
+        for (validators, required) in expected {
+            assert_eq!(rules.required_signatures(validators), required, "{} validators", validators);
+        }
+    }
+
+    #[test]
+    fn test_consensus_threshold_validation() {
+        assert!(ConsensusRules::validate_threshold(0.5).is_err());
+        assert!(ConsensusRules::validate_threshold(1.01).is_err());
+        assert!(ConsensusRules::validate_threshold(0.51).is_ok());
+        assert!(ConsensusRules::validate_threshold(1.0).is_ok());
+
+        let mut manager = TracStateManager::new();
+        manager.set_consensus_threshold(0.4);
+        assert!((manager.consensus_rules.consensus_threshold - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_current_schema_version_accepted() {
+        let mut manager = TracStateManager::new();
+        let transition = proposed_transition(&mut manager);
+        assert_eq!(transition["schema_version"], STATE_TRANSITION_SCHEMA_VERSION);
+
+        let decoded = StateTransition::from_json(&transition.to_string()).unwrap();
+        assert_eq!(decoded.schema_version, STATE_TRANSITION_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_future_schema_version_rejected() {
+        let mut manager = TracStateManager::new();
+        let mut transition = proposed_transition(&mut manager);
+        transition["schema_version"] = serde_json::json!(STATE_TRANSITION_SCHEMA_VERSION + 1);
+
+        let err = StateTransition::from_json(&transition.to_string()).unwrap_err();
+        assert_eq!(err, NetworkError::UnsupportedSchemaVersion {
+            found: STATE_TRANSITION_SCHEMA_VERSION + 1,
+            supported: STATE_TRANSITION_SCHEMA_VERSION,
+        });
+    }
+
+    #[test]
+    fn test_legacy_transition_up_converted() {
+        let mut manager = TracStateManager::new();
+        let mut transition = proposed_transition(&mut manager);
+        transition.as_object_mut().unwrap().remove("schema_version");
+
+        let decoded = StateTransition::from_json(&transition.to_string()).unwrap();
+        assert_eq!(decoded.schema_version, STATE_TRANSITION_SCHEMA_VERSION);
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_story_state_binary_round_trip() {
+        let mut manager = TracStateManager::new();
+        assert!(manager.get_current_state_bytes().is_err());
+        manager.initialize_player_state("player");
+        let mut state = manager.current_state.clone().unwrap();
+        state.completed_quests = vec!["quest_1_42".to_string(), "quest_7_99".to_string()];
+        state.governor_relationships.insert("ABRIOND".to_string(), 0.4);
+        state.state_hash = manager.calculate_state_hash(&state);
+        manager.current_state = Some(state);
+
+        let bytes = manager.get_current_state_bytes().unwrap();
+        let mut restored = TracStateManager::new();
+        assert_eq!(restored.load_state_bytes(&bytes), "State loaded for player");
+        assert_eq!(restored.get_current_state(), manager.get_current_state());
+        assert_eq!(restored.get_current_state_bytes().unwrap(), bytes);
+
+        let json_len = manager.get_current_state().len();
+        assert!(bytes.len() * 2 < json_len, "binary {} bytes vs JSON {} bytes", bytes.len(), json_len);
+
+        // A state edited without rehashing, or a truncated one, is refused
+        let mut tampered = manager.current_state.clone().unwrap();
+        tampered.energy_level = 99;
+        assert_eq!(restored.load_state_bytes(&tampered.to_bytes().unwrap()), "State hash does not match its content");
+        assert!(restored.load_state_bytes(&bytes[..bytes.len() / 2]).starts_with("Error decoding story state"));
+        assert_eq!(restored.get_current_state(), manager.get_current_state());
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_state_transition_binary_round_trip() {
+        let mut manager = TracStateManager::new();
+        let transition = StateTransition::from_json(&proposed_transition(&mut manager).to_string()).unwrap();
+        assert!(manager.get_pending_transition_bytes("missing").is_err());
+
+        // Relayed to a peer in binary, the transition lands in its pending set unchanged
+        let bytes = manager.get_pending_transition_bytes(&transition.transition_id).unwrap();
+        let mut peer = TracStateManager::new();
+        peer.initialize_player_state("player");
+        let accepted = StateTransition::from_json(&peer.receive_transition_bytes(&bytes)).unwrap();
+        assert_eq!(serde_json::to_string(&accepted).unwrap(), serde_json::to_string(&transition).unwrap());
+        assert_eq!(peer.pending_transitions.len(), 1);
+        assert_eq!(peer.receive_transition_bytes(&bytes), "Transition already pending");
+        assert!(peer.receive_transition_bytes(&bytes[1..]).starts_with("Network error: malformed message"));
+        // Leaving aside the free-text proof, which neither encoding shrinks
+        let proof_len = transition.quest_action.authenticity_proof.len();
+        assert!((bytes.len() - proof_len) * 2 < serde_json::to_vec(&transition).unwrap().len() - proof_len);
+
+        let mut future = transition.clone();
+        future.schema_version = STATE_TRANSITION_SCHEMA_VERSION + 1;
+        assert!(matches!(
+            StateTransition::from_bytes(&future.to_bytes().unwrap()),
+            Err(NetworkError::UnsupportedSchemaVersion { .. })
+        ));
+        assert_eq!(
+            peer.receive_transition_bytes(&future.to_bytes().unwrap()),
+            NetworkError::UnsupportedSchemaVersion { found: future.schema_version, supported: STATE_TRANSITION_SCHEMA_VERSION }.to_string()
+        );
+    }
+
+    fn action_with_proof(tradition: &str, proof: &str) -> String {
+        serde_json::json!({
+            "action_type": "CompleteQuest",
+            "quest_id": "quest_1_42",
+            "choice_id": null,
+            "parameters": { "tradition": tradition },
+            "authenticity_proof": proof
+        }).to_string()
+    }
+
+    #[test]
+    fn test_low_authenticity_actions_rejected_before_signing() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+
+        // Keyword proofs that used to score highest no longer pass
+        for proof in ["enochian", "enochian angel aethyr governor dee kelley watchtower", ""] {
+            let response = manager.propose_state_transition(&action_with_proof("Enochian", proof), "proof");
+            assert!(response.starts_with("Insufficient authenticity for Enochian"), "{}", response);
+        }
+        let response = manager.propose_state_transition(&action_with_proof("Atlantean", &authentic_proof()), "proof");
+        assert_eq!(response, "Tradition Atlantean cannot be validated");
+        assert!(manager.pending_transitions.is_empty());
+
+        // Peers cannot relay one in either
+        let mut relayed = serde_json::to_value(StateTransition::from_json(&manager.propose_state_transition(
+            &action_with_proof("Enochian", &authentic_proof()), "proof"
+        )).unwrap()).unwrap();
+        manager.pending_transitions.clear();
+        relayed["quest_action"]["authenticity_proof"] = serde_json::json!("enochian");
+        assert!(manager.receive_transition(&relayed.to_string()).starts_with("Insufficient authenticity"));
+        assert!(manager.pending_transitions.is_empty());
+
+        // A pending transition is not signed once the minimum rises above it
+        let transition: StateTransition = serde_json::from_str(&manager.propose_state_transition(
+            &action_with_proof("Enochian", &weak_proof()), "proof"
+        )).unwrap();
+        manager.consensus_rules.authenticity_minimum = 0.9;
+        assert!(manager.validate_transition(&transition.transition_id, "enochian_validator").starts_with("Insufficient authenticity"));
+        assert!(manager.pending_transitions[0].validator_signatures.is_empty());
+    }
+
+    #[test]
+    fn test_authentic_actions_pass_with_tradition_weighting() {
+        let mut manager = TracStateManager::new();
+        manager.initialize_player_state("player");
+
+        let transition: StateTransition = serde_json::from_str(&manager.propose_state_transition(
+            &action_with_proof("Enochian", &authentic_proof()), "proof"
+        )).unwrap();
+        let signature: ValidatorSignature = serde_json::from_str(
+            &manager.validate_transition(&transition.transition_id, "enochian_validator")
+        ).unwrap();
+        assert!(signature.authenticity_score >= manager.consensus_rules.authenticity_minimum);
+
+        // Hermetic scores are weighted down by the scorer, and so is their minimum
+        let transition: StateTransition = serde_json::from_str(&manager.propose_state_transition(
+            &action_with_proof("Hermetic_Qabalah", &corpus_proof("Hermetic_Qabalah", 2)), "proof"
+        )).unwrap();
+        let signature: ValidatorSignature = serde_json::from_str(
+            &manager.validate_transition(&transition.transition_id, "hermetic_validator")
+        ).unwrap();
+        assert!(signature.authenticity_score >= manager.consensus_rules.authenticity_minimum);
     }
 }
@@ -3,28 +3,58 @@
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use enochian_cyphers::constants::{AETHYR_COUNT, ENOCHIAN_WEIGHTING};
+use enochian_cyphers::{Energy, ObjectiveKind, QuestId, TraditionManager};
+#[cfg(feature = "server")]
+use tokio_util::sync::CancellationToken;
 
 // Import our core modules
 mod narrative_generator;
 mod branching_logic;
 mod governor_integration;
 mod trac_state_manager;
+mod localization;
+mod sacred_items;
+mod governor_mood;
+mod conditions;
+mod metrics;
 
 use narrative_generator::NarrativeGenerator;
 use branching_logic::BranchingEngine;
 use governor_integration::GovernorIntegrator;
 use trac_state_manager::TracStateManager;
 
+// Span over a quest generation call, compiled in with the `tracing` feature
+macro_rules! quest_span {
+    ($name:literal $(, $field:ident = $value:expr)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($name $(, $field = %$value)*).entered();
+    };
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryEngineConfig {
     pub enable_p2p_sync: bool,
     pub authenticity_threshold: f64,
     pub max_concurrent_quests: u32,
-    pub tradition_weighting: HashMap<String, f64>,
+    pub tradition_weighting: BTreeMap<String, f64>,
     pub governor_interaction_cooldown: u32,
+    // Extra attempts, each with a perturbed seed, for quests below authenticity_threshold;
+    // clamped to MAX_GENERATION_RETRIES on initialize
+    #[serde(default = "default_max_generation_retries")]
+    pub max_generation_retries: u32,
+}
+
+fn default_max_generation_retries() -> u32 {
+    3
 }
 
+// Most retries a config may ask for, so one request's generation time stays bounded
+pub const MAX_GENERATION_RETRIES: u32 = 10;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestGenerationRequest {
     pub player_id: String,
@@ -33,13 +63,19 @@ pub struct QuestGenerationRequest {
     pub quest_seed: u32,
     pub difficulty_preference: u32,
     pub tradition_focus: Vec<String>,
+    #[serde(default = "default_locale")]
+    pub locale: String,
+}
+
+fn default_locale() -> String {
+    localization::DEFAULT_LOCALE.to_string()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerContext {
     pub completed_quests: Vec<String>,
-    pub tradition_mastery: HashMap<String, f64>,
-    pub governor_relationships: HashMap<String, f64>,
+    pub tradition_mastery: BTreeMap<String, f64>,
+    pub governor_relationships: BTreeMap<String, f64>,
     pub current_energy: u32,
     pub sacred_items: Vec<String>,
     pub aethyr_access: Vec<u32>,
@@ -57,6 +93,21 @@ pub struct GeneratedQuest {
     pub estimated_duration: u32,
     pub tradition_integration: Vec<String>,
     pub governor_dialogue: String,
+    #[serde(default)]
+    pub is_fallback: bool,
+    // Generation attempts made, including retries
+    #[serde(default)]
+    pub generation_attempts: u32,
+    // Set when no attempt reached authenticity_threshold; the quest is the best one produced
+    #[serde(default)]
+    pub below_authenticity_floor: bool,
+    // Tier of the governor's Aethyr; None for fallback quests
+    #[serde(default)]
+    pub aethyr_tier: Option<String>,
+    // Seed the quest was generated from; differs from the requested quest_seed,
+    // which still names the quest, when a retry produced it
+    #[serde(default)]
+    pub effective_seed: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,12 +120,162 @@ pub struct QuestChoice {
     pub authenticity_impact: f64,
 }
 
+// Valid Governor Angel ID range (91 governors across 30 Aethyrs)
+pub const MIN_GOVERNOR_ID: u32 = 1;
+pub const MAX_GOVERNOR_ID: u32 = 91;
+
+pub fn is_valid_governor_id(governor_id: u32) -> bool {
+    (MIN_GOVERNOR_ID..=MAX_GOVERNOR_ID).contains(&governor_id)
+}
+
+pub(crate) fn governor_id_error(governor_id: u32) -> String {
+    serde_json::json!({
+        "error": format!(
+            "Governor ID {} out of range ({}-{})",
+            governor_id, MIN_GOVERNOR_ID, MAX_GOVERNOR_ID
+        ),
+        "governor_id": governor_id,
+    })
+    .to_string()
+}
+
+// Seed for a generation retry; attempt 0 keeps the requested seed. Stepping by
+// the 32-bit golden ratio spreads retries across the seed space.
+fn perturbed_seed(seed: u32, attempt: u32) -> u32 {
+    seed.wrapping_add(attempt.wrapping_mul(0x9E37_79B9))
+}
+
+// Whether the core crate's `TraditionManager` knows the tradition
+pub fn is_known_tradition(tradition: &str) -> bool {
+    static TRADITIONS: OnceLock<TraditionManager> = OnceLock::new();
+    TRADITIONS.get_or_init(TraditionManager::new).get_tradition(tradition).is_some()
+}
+
+// A rejected field in an untrusted request, e.g. "player_context.current_energy"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        FieldError { field: field.into(), message: message.into() }
+    }
+}
+
+impl QuestGenerationRequest {
+    // Every invalid field, in request order; empty when the request is usable
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if !is_valid_governor_id(self.governor_id) {
+            errors.push(FieldError::new("governor_id", format!(
+                "Governor ID {} out of range ({}-{})",
+                self.governor_id, MIN_GOVERNOR_ID, MAX_GOVERNOR_ID
+            )));
+        }
+
+        for (i, tradition) in self.tradition_focus.iter().enumerate() {
+            if !is_known_tradition(tradition) {
+                errors.push(FieldError::new(format!("tradition_focus[{}]", i), format!("Unknown tradition {}", tradition)));
+            }
+        }
+
+        errors.extend(self.player_context.validate("player_context"));
+        errors
+    }
+}
+
+impl PlayerContext {
+    pub fn validate(&self, prefix: &str) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if self.current_energy > Energy::MAX {
+            errors.push(FieldError::new(
+                format!("{}.current_energy", prefix),
+                format!("Energy {} exceeds maximum of {}", self.current_energy, Energy::MAX),
+            ));
+        }
+
+        for (tradition, mastery) in &self.tradition_mastery {
+            let field = format!("{}.tradition_mastery.{}", prefix, tradition);
+            if !is_known_tradition(tradition) {
+                errors.push(FieldError::new(field, format!("Unknown tradition {}", tradition)));
+            } else if !(0.0..=1.0).contains(mastery) {
+                errors.push(FieldError::new(field, format!("Mastery {} outside 0.0-1.0", mastery)));
+            }
+        }
+
+        for (governor, relationship) in &self.governor_relationships {
+            if !(-1.0..=1.0).contains(relationship) {
+                errors.push(FieldError::new(
+                    format!("{}.governor_relationships.{}", prefix, governor),
+                    format!("Relationship {} outside -1.0-1.0", relationship),
+                ));
+            }
+        }
+
+        for (i, aethyr) in self.aethyr_access.iter().enumerate() {
+            if *aethyr == 0 || *aethyr as usize > AETHYR_COUNT {
+                errors.push(FieldError::new(
+                    format!("{}.aethyr_access[{}]", prefix, i),
+                    format!("Aethyr {} out of range (1-{})", aethyr, AETHYR_COUNT),
+                ));
+            }
+        }
+
+        errors
+    }
+}
+
+pub(crate) fn request_error(message: &str, fields: &[FieldError]) -> String {
+    serde_json::json!({
+        "error": message,
+        "fields": fields,
+    })
+    .to_string()
+}
+
+// A batch request that could not be generated, by position in the batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchFailure {
+    pub index: usize,
+    pub error: String,
+    pub fields: Vec<FieldError>,
+}
+
+// Outcome of a batch generation; `cancelled` is set when the batch stopped early
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuestBatch {
+    pub quests: Vec<GeneratedQuest>,
+    pub failures: Vec<BatchFailure>,
+    pub cancelled: bool,
+}
+
+impl QuestBatch {
+    fn record(&mut self, index: usize, result: Result<GeneratedQuest, (String, Vec<FieldError>)>) {
+        match result {
+            Ok(quest) => self.quests.push(quest),
+            Err((error, fields)) => self.failures.push(BatchFailure { index, error, fields }),
+        }
+    }
+}
+
+// Quest duration estimate in minutes, inferring each objective's kind from its wording
+pub fn estimate_duration(objectives: &[String], difficulty: u32, branch_count: usize) -> u32 {
+    enochian_cyphers::estimate_quest_duration(objectives.iter().map(|objective| ObjectiveKind::infer(objective)), difficulty, branch_count)
+}
+
 #[wasm_bindgen]
 pub struct EnochianStoryEngine {
     narrative_generator: NarrativeGenerator,
     branching_engine: BranchingEngine,
     governor_integrator: GovernorIntegrator,
-    trac_state_manager: TracStateManager,
+    // Borrowed per call rather than through `&mut self`, so a call made while
+    // another is still changing state (e.g. from an async JS callback) is
+    // refused with an error instead of corrupting pending transitions
+    trac_state_manager: RefCell<TracStateManager>,
     config: StoryEngineConfig,
     initialized: bool,
 }
@@ -89,7 +290,7 @@ impl EnochianStoryEngine {
             narrative_generator: NarrativeGenerator::new(),
             branching_engine: BranchingEngine::new(),
             governor_integrator: GovernorIntegrator::new(),
-            trac_state_manager: TracStateManager::new(),
+            trac_state_manager: RefCell::new(TracStateManager::new()),
             config: StoryEngineConfig::default(),
             initialized: false,
         }
@@ -104,100 +305,262 @@ impl EnochianStoryEngine {
             }
         };
 
+        let requested_retries = config.max_generation_retries;
         self.config = config;
+        self.config.max_generation_retries = requested_retries.min(MAX_GENERATION_RETRIES);
         self.initialized = true;
 
+        if requested_retries > MAX_GENERATION_RETRIES {
+            return format!(
+                "Story Engine initialized successfully; max_generation_retries {} clamped to {}",
+                requested_retries, MAX_GENERATION_RETRIES
+            );
+        }
         "Story Engine initialized successfully".to_string()
     }
 
     #[wasm_bindgen]
     pub fn generate_quest(&self, request_json: &str) -> String {
         if !self.initialized {
+            metrics::global().record_error_kind("not_initialized");
             return "Error: Story Engine not initialized".to_string();
         }
 
+        // Untrusted input: reject bad fields rather than generating from a degraded context
         let request: QuestGenerationRequest = match serde_json::from_str(request_json) {
             Ok(req) => req,
-            Err(e) => return format!("Request parsing error: {}", e),
+            Err(e) => {
+                metrics::global().record_error_kind("invalid_request");
+                return request_error(&format!("Request parsing error: {}", e), &[]);
+            }
+        };
+        quest_span!("generate_quest", player_id = request.player_id, governor_id = request.governor_id);
+
+        match self.generate_validated(&request) {
+            Ok(quest) => serde_json::to_string(&quest).unwrap_or_else(|_| "{}".to_string()),
+            Err((message, fields)) => request_error(&message, &fields),
+        }
+    }
+
+    // Quests for a JSON array of requests, generated in order. A rejected
+    // request is reported by index without stopping the rest of the batch.
+    #[wasm_bindgen]
+    pub fn generate_quest_batch(&self, requests_json: &str) -> String {
+        if !self.initialized {
+            metrics::global().record_error_kind("not_initialized");
+            return "Error: Story Engine not initialized".to_string();
+        }
+
+        let requests: Vec<QuestGenerationRequest> = match serde_json::from_str(requests_json) {
+            Ok(requests) => requests,
+            Err(e) => {
+                metrics::global().record_error_kind("invalid_request");
+                return request_error(&format!("Batch parsing error: {}", e), &[]);
+            }
         };
+        quest_span!("generate_quest_batch", count = requests.len());
+
+        let mut batch = QuestBatch::default();
+        for (index, request) in requests.iter().enumerate() {
+            batch.record(index, self.generate_validated(request));
+        }
+        serde_json::to_string(&batch).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    // Validate a parsed request and generate its quest, retrying below-threshold
+    // attempts; errors carry a message and the rejected fields
+    fn generate_validated(&self, request: &QuestGenerationRequest) -> Result<GeneratedQuest, (String, Vec<FieldError>)> {
+        let field_errors = request.validate();
+        if !field_errors.is_empty() {
+            metrics::global().record_error_kind("invalid_request");
+            return Err(("Invalid quest generation request".to_string(), field_errors));
+        }
 
+        let context_json = serde_json::to_string(&request.player_context)
+            .map_err(|e| (format!("Player context serialization error: {}", e), Vec::new()))?;
+
+        // Retry below-threshold quests with perturbed seeds, keeping the best attempt
+        let mut best: Option<GeneratedQuest> = None;
+        let mut attempts = 0;
+        for attempt in 0..=self.config.max_generation_retries {
+            attempts += 1;
+            let seed = perturbed_seed(request.quest_seed, attempt);
+            let quest = self.generate_attempt(request, seed, &context_json);
+            let passed = quest.authenticity_score >= self.config.authenticity_threshold;
+            if best.as_ref().is_none_or(|b| quest.authenticity_score > b.authenticity_score) {
+                best = Some(quest);
+            }
+            if passed {
+                break;
+            }
+        }
+
+        let mut quest = best.expect("at least one generation attempt");
+        quest.generation_attempts = attempts;
+        quest.below_authenticity_floor = quest.authenticity_score < self.config.authenticity_threshold;
+
+        metrics::global().increment(metrics::Counter::QuestsGenerated);
+        Ok(quest)
+    }
+
+    // Generate content from `seed`; the quest keeps the ID of the requested seed
+    fn generate_attempt(&self, request: &QuestGenerationRequest, seed: u32, context_json: &str) -> GeneratedQuest {
         // Generate base narrative
-        let narrative_json = self.narrative_generator.generate_quest_narrative(
+        let narrative_json = self.narrative_generator.generate_localized_quest_narrative(
             request.governor_id,
-            &serde_json::to_string(&request.player_context).unwrap_or_default(),
-            request.quest_seed
+            context_json,
+            seed,
+            &request.locale
         );
 
+        let quest_id = QuestId::new(request.governor_id, request.quest_seed).to_string();
+
         // Generate branching choices
         let branches_json = self.branching_engine.generate_quest_branches(
-            &format!("quest_{}", request.quest_seed),
-            &serde_json::to_string(&request.player_context).unwrap_or_default(),
-            request.quest_seed
+            &quest_id,
+            context_json,
+            seed
         );
 
         // Adapt for governor personality
         let adapted_narrative = self.governor_integrator.adapt_story_for_governor(
             &narrative_json,
             request.governor_id,
-            &serde_json::to_string(&request.player_context).unwrap_or_default()
+            context_json,
+            seed
         );
 
         // Generate governor dialogue
-        let dialogue = self.governor_integrator.generate_governor_dialogue(
+        let dialogue = self.governor_integrator.generate_localized_governor_dialogue(
             request.governor_id,
             "quest_introduction",
-            "player_approaches"
+            "player_approaches",
+            &request.locale
         );
 
         // Combine into final quest
-        let quest = self.create_complete_quest(
+        self.create_complete_quest(
             &narrative_json,
             &branches_json,
             &adapted_narrative,
             &dialogue,
-            &request
-        );
+            request,
+            seed
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn add_locale_table(&mut self, table_json: &str) -> String {
+        // Both generators keep their own copy of the phrase tables
+        let result = self.narrative_generator.add_locale_table(table_json);
+        self.governor_integrator.add_locale_table(table_json);
+        result
+    }
 
-        serde_json::to_string(&quest).unwrap_or_else(|_| "{}".to_string())
+    #[wasm_bindgen]
+    pub fn set_aethyr_tiers(&mut self, tiers_json: &str) -> String {
+        // Narrative text and governor adaptation must agree on every Aethyr's tier
+        let result = self.narrative_generator.set_aethyr_tiers(tiers_json);
+        self.governor_integrator.set_aethyr_tiers(tiers_json);
+        result
     }
 
     #[wasm_bindgen]
-    pub fn process_quest_choice(&mut self, choice_json: &str) -> String {
+    pub fn register_sacred_item(&self, item_json: &str) -> String {
+        match self.state_manager_mut("register_sacred_item") {
+            Ok(mut manager) => manager.register_sacred_item(item_json),
+            Err(e) => e,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn process_quest_choice(&self, choice_json: &str) -> String {
         if !self.initialized {
             return "Error: Story Engine not initialized".to_string();
         }
+        if !self.config.enable_p2p_sync {
+            return sync_disabled_error("process_quest_choice");
+        }
+
+        let mut manager = match self.state_manager_mut("process_quest_choice") {
+            Ok(manager) => manager,
+            Err(e) => return e,
+        };
 
         // Process the choice through the state manager
-        let transition_result = self.trac_state_manager.propose_state_transition(
+        let transition_result = manager.propose_state_transition(
             choice_json,
             "authenticity_proof_placeholder"
         );
 
-        // If P2P sync is enabled, handle consensus
-        if self.config.enable_p2p_sync {
-            // In a real implementation, this would trigger P2P validation
-            let _validation_result = self.trac_state_manager.validate_transition(
-                "transition_id_placeholder",
-                "local_validator"
-            );
-        }
+        // In a real implementation, this would trigger P2P validation
+        let _validation_result = manager.validate_transition(
+            "transition_id_placeholder",
+            "local_validator"
+        );
 
         transition_result
     }
 
+    #[wasm_bindgen]
+    pub fn validate_transition(&self, transition_id: &str, validator_id: &str) -> String {
+        if !self.config.enable_p2p_sync {
+            return sync_disabled_error("validate_transition");
+        }
+        match self.state_manager_mut("validate_transition") {
+            Ok(mut manager) => manager.validate_transition(transition_id, validator_id),
+            Err(e) => e,
+        }
+    }
+
+    // Pause or resume P2P sync without re-initializing. Pausing quarantines
+    // pending transitions; resuming restores them and queues a reconciliation
+    // request announcing the current state hash.
+    #[wasm_bindgen]
+    pub fn set_p2p_sync(&mut self, enabled: bool) -> String {
+        if enabled == self.config.enable_p2p_sync {
+            return format!("P2P sync already {}", if enabled { "enabled" } else { "disabled" });
+        }
+
+        self.config.enable_p2p_sync = enabled;
+        if enabled {
+            self.trac_state_manager.get_mut().request_reconciliation()
+        } else {
+            let quarantined = self.trac_state_manager.get_mut().quarantine_pending_transitions();
+            format!("P2P sync paused; {} pending transitions quarantined", quarantined)
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn take_reconciliation_requests(&self) -> String {
+        match self.state_manager_mut("take_reconciliation_requests") {
+            Ok(mut manager) => manager.take_reconciliation_requests(),
+            Err(e) => e,
+        }
+    }
+
     #[wasm_bindgen]
     pub fn get_player_state(&self, player_id: &str) -> String {
-        self.trac_state_manager.get_current_state()
+        match self.state_manager("get_player_state") {
+            Ok(manager) => manager.get_current_state(),
+            Err(e) => e,
+        }
     }
 
     #[wasm_bindgen]
-    pub fn initialize_player(&mut self, player_id: &str) -> String {
-        self.trac_state_manager.initialize_player_state(player_id)
+    pub fn initialize_player(&self, player_id: &str) -> String {
+        match self.state_manager_mut("initialize_player") {
+            Ok(mut manager) => manager.initialize_player_state(player_id),
+            Err(e) => e,
+        }
     }
 
     #[wasm_bindgen]
     pub fn get_consensus_status(&self) -> String {
-        self.trac_state_manager.get_consensus_status()
+        match self.state_manager("get_consensus_status") {
+            Ok(manager) => manager.get_consensus_status(),
+            Err(e) => e,
+        }
     }
 
     #[wasm_bindgen]
@@ -238,17 +601,40 @@ impl EnochianStoryEngine {
         serde_json::to_string(&status).unwrap_or_else(|_| "{}".to_string())
     }
 
+    // Exclusive access to the state manager, refused while another call holds it
+    fn state_manager_mut(&self, operation: &str) -> Result<RefMut<'_, TracStateManager>, String> {
+        self.trac_state_manager.try_borrow_mut().map_err(|_| busy_error(operation))
+    }
+
+    // Shared access to the state manager, refused while a call is changing it
+    fn state_manager(&self, operation: &str) -> Result<Ref<'_, TracStateManager>, String> {
+        self.trac_state_manager.try_borrow().map_err(|_| busy_error(operation))
+    }
+
     fn create_complete_quest(
         &self,
         narrative_json: &str,
         branches_json: &str,
         adapted_narrative: &str,
         dialogue: &str,
-        request: &QuestGenerationRequest
+        request: &QuestGenerationRequest,
+        seed: u32
     ) -> GeneratedQuest {
         // Parse the generated components
         let base_narrative: serde_json::Value = serde_json::from_str(narrative_json).unwrap_or_default();
         let branches: Vec<serde_json::Value> = serde_json::from_str(branches_json).unwrap_or_default();
+        let adaptation: serde_json::Value = serde_json::from_str(adapted_narrative).unwrap_or_default();
+
+        // Either stage falling back means the requested governor wasn't loaded
+        let is_fallback = [&base_narrative, &adaptation].iter()
+            .any(|v| v.get("is_fallback").and_then(|f| f.as_bool()).unwrap_or(false));
+
+        // The governor's Aethyr tier scales how demanding every branch is
+        let aethyr_tier = adaptation.get("aethyr_tier").filter(|tier| !tier.is_null());
+        let tier_difficulty = aethyr_tier
+            .and_then(|tier| tier.get("challenge_difficulty_modifier"))
+            .and_then(|v| v.as_f64())
+            .unwrap_or(1.0);
 
         // Create quest choices from branches
         let mut quest_choices = Vec::new();
@@ -266,15 +652,28 @@ impl EnochianStoryEngine {
                 ],
                 difficulty_modifier: branch.get("difficulty_level")
                     .and_then(|v| v.as_f64())
-                    .unwrap_or(1.0),
+                    .unwrap_or(1.0) * tier_difficulty,
                 tradition_alignment: 0.85,
                 authenticity_impact: 0.1,
             };
             quest_choices.push(choice);
         }
 
+        // Objectives shaped by the governor's teaching method take precedence
+        let objectives: Vec<String> = adaptation.get("objectives")
+            .and_then(|v| v.as_array())
+            .filter(|arr| !arr.is_empty())
+            .or_else(|| base_narrative.get("objectives").and_then(|v| v.as_array()))
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_else(|| vec![
+                "Study sacred principles".to_string(),
+                "Practice spiritual techniques".to_string(),
+                "Achieve enlightenment".to_string(),
+            ]);
+        let estimated_duration = estimate_duration(&objectives, request.difficulty_preference, quest_choices.len());
+
         GeneratedQuest {
-            quest_id: format!("quest_{}_{}", request.governor_id, request.quest_seed),
+            quest_id: QuestId::new(request.governor_id, request.quest_seed).to_string(),
             title: base_narrative.get("title")
                 .and_then(|v| v.as_str())
                 .unwrap_or("Sacred Enochian Quest")
@@ -283,14 +682,7 @@ impl EnochianStoryEngine {
                 .and_then(|v| v.as_str())
                 .unwrap_or("A mystical journey of spiritual advancement")
                 .to_string(),
-            objectives: base_narrative.get("objectives")
-                .and_then(|v| v.as_array())
-                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
-                .unwrap_or_else(|| vec![
-                    "Study sacred principles".to_string(),
-                    "Practice spiritual techniques".to_string(),
-                    "Achieve enlightenment".to_string(),
-                ]),
+            objectives,
             wisdom_taught: base_narrative.get("wisdom_taught")
                 .and_then(|v| v.as_str())
                 .unwrap_or("Fundamental mystical wisdom")
@@ -299,17 +691,58 @@ impl EnochianStoryEngine {
             authenticity_score: base_narrative.get("authenticity_score")
                 .and_then(|v| v.as_f64())
                 .unwrap_or(0.85),
-            estimated_duration: 30, // 30 minutes
+            estimated_duration,
             tradition_integration: request.tradition_focus.clone(),
             governor_dialogue: dialogue.to_string(),
+            is_fallback,
+            generation_attempts: 1,
+            below_authenticity_floor: false,
+            aethyr_tier: aethyr_tier
+                .and_then(|tier| tier.get("name"))
+                .and_then(|v| v.as_str())
+                .map(|name| name.to_string()),
+            effective_seed: seed,
         }
     }
 }
 
+// Async generation for server handlers; WASM callers use the sync methods above
+#[cfg(feature = "server")]
+impl EnochianStoryEngine {
+    // Generate quests in order, yielding to the runtime between quests. Once
+    // `cancel` fires no further quests are started, and the quests produced so
+    // far are returned with `cancelled` set.
+    pub async fn generate_quest_batch_async(
+        &self,
+        requests: &[QuestGenerationRequest],
+        cancel: &CancellationToken,
+    ) -> Result<QuestBatch, String> {
+        if !self.initialized {
+            metrics::global().record_error_kind("not_initialized");
+            return Err("Error: Story Engine not initialized".to_string());
+        }
+
+        let mut batch = QuestBatch::default();
+        for (index, request) in requests.iter().enumerate() {
+            if cancel.is_cancelled() {
+                batch.cancelled = true;
+                break;
+            }
+            {
+                // Entered per quest so no span is held across the yield
+                quest_span!("generate_quest_batch_async", index = index, player_id = request.player_id);
+                batch.record(index, self.generate_validated(request));
+            }
+            tokio::task::yield_now().await;
+        }
+        Ok(batch)
+    }
+}
+
 impl Default for StoryEngineConfig {
     fn default() -> Self {
-        let mut tradition_weighting = HashMap::new();
-        tradition_weighting.insert("Enochian".to_string(), 0.6);
+        let mut tradition_weighting = BTreeMap::new();
+        tradition_weighting.insert("Enochian".to_string(), ENOCHIAN_WEIGHTING);
         tradition_weighting.insert("Hermetic_Qabalah".to_string(), 0.2);
         tradition_weighting.insert("Thelema".to_string(), 0.1);
         tradition_weighting.insert("Golden_Dawn".to_string(), 0.1);
@@ -320,10 +753,22 @@ impl Default for StoryEngineConfig {
             max_concurrent_quests: 3,
             tradition_weighting,
             governor_interaction_cooldown: 144, // 144 blocks (24 hours)
+            max_generation_retries: default_max_generation_retries(),
         }
     }
 }
 
+fn busy_error(operation: &str) -> String {
+    metrics::global().record_error_kind("reentrant_call");
+    format!("Error: Story Engine busy: {} called while another state change is in progress", operation)
+}
+
+// Transitions are neither created nor signed while P2P sync is off
+fn sync_disabled_error(operation: &str) -> String {
+    metrics::global().record_error_kind("sync_paused");
+    format!("Error: P2P sync disabled: {} refused", operation)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EngineStatus {
     pub initialized: bool,
@@ -356,3 +801,305 @@ pub fn validate_quest_authenticity(content: &str) -> f64 {
     let engine = EnochianStoryEngine::new();
     engine.validate_authenticity(content)
 }
+
+// Operational counters as JSON; all zero unless built with the `metrics` feature
+#[wasm_bindgen]
+pub fn metrics_snapshot() -> String {
+    metrics::metrics_snapshot().to_string()
+}
+
+#[wasm_bindgen]
+pub fn reset_metrics() {
+    metrics::reset_metrics()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_uses_enochian_weighting() {
+        let config = StoryEngineConfig::default();
+        assert_eq!(config.tradition_weighting["Enochian"], ENOCHIAN_WEIGHTING);
+        assert!((config.tradition_weighting.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    // Genuine Enochian calibration passages, joined so the proof clears the
+    // default transition authenticity minimum
+    fn authentic_proof() -> String {
+        enochian_cyphers::calibration::CORPUS.iter()
+            .filter(|sample| sample.tradition == "Enochian" && sample.authentic)
+            .take(3)
+            .map(|sample| sample.text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn initialized_engine() -> EnochianStoryEngine {
+        let mut engine = EnochianStoryEngine::new();
+        engine.initialize(&serde_json::to_string(&StoryEngineConfig::default()).unwrap());
+        engine
+    }
+
+    fn request_json(governor_id: u32, tradition_focus: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "player_id": "seeker",
+            "governor_id": governor_id,
+            "player_context": {
+                "completed_quests": [],
+                "tradition_mastery": {"Enochian": 0.2},
+                "governor_relationships": {},
+                "current_energy": 20,
+                "sacred_items": [],
+                "aethyr_access": [1]
+            },
+            "quest_seed": 7,
+            "difficulty_preference": 3,
+            "tradition_focus": tradition_focus,
+        })
+    }
+
+    fn error_fields(response: &str) -> Vec<String> {
+        let value: serde_json::Value = serde_json::from_str(response).unwrap();
+        assert!(value.get("error").is_some(), "expected an error, got {}", response);
+        value["fields"].as_array().unwrap().iter()
+            .map(|f| f["field"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_out_of_range_governor_rejected() {
+        let engine = initialized_engine();
+        let response = engine.generate_quest(&request_json(92, &["Enochian"]).to_string());
+        assert_eq!(error_fields(&response), vec!["governor_id"]);
+        assert!(response.contains("Governor ID 92 out of range (1-91)"));
+    }
+
+    #[test]
+    fn test_unknown_tradition_rejected() {
+        let engine = initialized_engine();
+        let response = engine.generate_quest(&request_json(1, &["Enochian", "Atlantean"]).to_string());
+        assert_eq!(error_fields(&response), vec!["tradition_focus[1]"]);
+        assert!(response.contains("Unknown tradition Atlantean"));
+    }
+
+    #[test]
+    fn test_invalid_player_context_rejected() {
+        let engine = initialized_engine();
+        let mut request = request_json(1, &["Enochian"]);
+        request["player_context"]["current_energy"] = serde_json::json!(40);
+        request["player_context"]["aethyr_access"] = serde_json::json!([31]);
+        let response = engine.generate_quest(&request.to_string());
+        assert_eq!(
+            error_fields(&response),
+            vec!["player_context.current_energy", "player_context.aethyr_access[0]"]
+        );
+
+        // Negative energy cannot be represented and fails to parse
+        request["player_context"]["current_energy"] = serde_json::json!(-1);
+        let response = engine.generate_quest(&request.to_string());
+        assert!(response.contains("Request parsing error"));
+    }
+
+    #[test]
+    fn test_valid_request_generates_quest() {
+        let engine = initialized_engine();
+        let response = engine.generate_quest(&request_json(1, &["Enochian"]).to_string());
+        let quest: GeneratedQuest = serde_json::from_str(&response).unwrap();
+        assert_eq!(quest.quest_id, "quest_1_7");
+    }
+
+    #[test]
+    fn test_duration_estimate_tracks_objectives_and_difficulty() {
+        let engine = initialized_engine();
+        let response = engine.generate_quest(&request_json(1, &["Enochian"]).to_string());
+        let quest: GeneratedQuest = serde_json::from_str(&response).unwrap();
+        assert_eq!(
+            quest.estimated_duration,
+            estimate_duration(&quest.objectives, 3, quest.choice_branches.len())
+        );
+    }
+
+    #[test]
+    fn test_batch_reports_rejected_requests_by_index() {
+        let engine = initialized_engine();
+        let requests = serde_json::json!([
+            request_json(1, &["Enochian"]),
+            request_json(92, &["Enochian"]),
+            request_json(2, &["Enochian"]),
+        ]);
+        let batch: QuestBatch = serde_json::from_str(&engine.generate_quest_batch(&requests.to_string())).unwrap();
+        assert_eq!(batch.quests.len(), 2);
+        assert_eq!(batch.failures.len(), 1);
+        assert_eq!(batch.failures[0].index, 1);
+        assert_eq!(batch.failures[0].fields[0].field, "governor_id");
+        assert!(!batch.cancelled);
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_cancelled_batch_keeps_quests_produced_so_far() {
+        let engine = initialized_engine();
+        let requests: Vec<QuestGenerationRequest> = (0..20)
+            .map(|i| {
+                let mut request = request_json(1, &["Enochian"]);
+                request["quest_seed"] = serde_json::json!(i);
+                serde_json::from_value(request).unwrap()
+            })
+            .collect();
+        let cancel = CancellationToken::new();
+
+        // Cancel after a few scheduler turns, as a disconnecting client would
+        let canceller = async {
+            for _ in 0..3 {
+                tokio::task::yield_now().await;
+            }
+            cancel.cancel();
+        };
+        let (batch, _) = tokio::join!(engine.generate_quest_batch_async(&requests, &cancel), canceller);
+        let batch = batch.unwrap();
+
+        assert!(batch.cancelled);
+        assert!(!batch.quests.is_empty());
+        assert!(batch.quests.len() < requests.len());
+        assert!(batch.failures.is_empty());
+
+        // An already-cancelled token starts no work at all
+        let batch = engine.generate_quest_batch_async(&requests, &cancel).await.unwrap();
+        assert!(batch.cancelled);
+        assert!(batch.quests.is_empty());
+    }
+
+    #[test]
+    fn test_toggling_p2p_sync_keeps_state_and_reconciles() {
+        let mut engine = initialized_engine();
+        engine.initialize_player("seeker");
+        let choice = serde_json::json!({
+            "action_type": "CompleteQuest",
+            "quest_id": "quest_1_7",
+            "choice_id": null,
+            "parameters": {},
+            "authenticity_proof": authentic_proof()
+        });
+        assert_eq!(engine.set_p2p_sync(false), "P2P sync already disabled");
+        assert!(engine.set_p2p_sync(true).contains("\"player_id\":\"seeker\""));
+        let transition: serde_json::Value = serde_json::from_str(&engine.process_quest_choice(&choice.to_string())).unwrap();
+        let state = engine.get_player_state("seeker");
+        assert_eq!(engine.set_p2p_sync(false), "P2P sync paused; 1 pending transitions quarantined");
+        assert_eq!(engine.get_player_state("seeker"), state);
+
+        // Nothing new is created, accepted or signed while paused
+        let transition_id = transition["transition_id"].as_str().unwrap();
+        assert_eq!(engine.process_quest_choice(&choice.to_string()), "Error: P2P sync disabled: process_quest_choice refused");
+        assert_eq!(engine.validate_transition(transition_id, "validator"), "Error: P2P sync disabled: validate_transition refused");
+        assert_eq!(engine.trac_state_manager.borrow_mut().receive_transition(&transition.to_string()), "P2P sync paused");
+        assert!(engine.trac_state_manager.borrow().pending_report().is_empty());
+
+        let request: serde_json::Value = serde_json::from_str(&engine.set_p2p_sync(true)).unwrap();
+        assert_eq!(request["pending_transition_ids"].as_array().unwrap().len(), 1);
+        let queued: Vec<serde_json::Value> = serde_json::from_str(&engine.take_reconciliation_requests()).unwrap();
+        assert_eq!(queued.len(), 2);
+        assert_eq!(engine.get_player_state("seeker"), state);
+    }
+
+    #[test]
+    fn test_reentrant_state_change_rejected() {
+        let mut engine = initialized_engine();
+        engine.set_p2p_sync(true);
+        engine.initialize_player("seeker");
+        let choice = serde_json::json!({
+            "action_type": "CompleteQuest",
+            "quest_id": "quest_1_7",
+            "choice_id": null,
+            "parameters": {},
+            "authenticity_proof": authentic_proof()
+        }).to_string();
+
+        // A callback firing while a state change is still in progress
+        {
+            let _in_progress = engine.trac_state_manager.borrow_mut();
+            let response = engine.process_quest_choice(&choice);
+            assert!(response.starts_with("Error: Story Engine busy: process_quest_choice"), "{}", response);
+            assert!(engine.validate_transition("quest_1_7_0", "validator").starts_with("Error: Story Engine busy"));
+            assert!(engine.get_player_state("seeker").starts_with("Error: Story Engine busy"));
+        }
+        assert!(engine.trac_state_manager.borrow().pending_report().is_empty());
+
+        // Once it finishes the same calls go through
+        let transition: serde_json::Value = serde_json::from_str(&engine.process_quest_choice(&choice)).unwrap();
+        let transition_id = transition["transition_id"].as_str().unwrap();
+        assert!(engine.validate_transition(transition_id, "validator").contains("\"validator_id\":\"validator\""));
+        assert_eq!(engine.trac_state_manager.borrow().pending_report().len(), 1);
+    }
+
+    fn engine_with_threshold(threshold: f64) -> EnochianStoryEngine {
+        let mut engine = EnochianStoryEngine::new();
+        let config = StoryEngineConfig { authenticity_threshold: threshold, ..StoryEngineConfig::default() };
+        engine.initialize(&serde_json::to_string(&config).unwrap());
+        engine
+    }
+
+    #[test]
+    fn test_low_threshold_passes_first_attempt() {
+        let engine = engine_with_threshold(0.5);
+        let response = engine.generate_quest(&request_json(1, &["Enochian"]).to_string());
+        let quest: GeneratedQuest = serde_json::from_str(&response).unwrap();
+        assert_eq!(quest.generation_attempts, 1);
+        assert!(!quest.below_authenticity_floor);
+        assert_eq!(quest.quest_id, "quest_1_7");
+        assert_eq!(quest.effective_seed, 7);
+    }
+
+    #[test]
+    fn test_unreachable_threshold_exhausts_retries() {
+        let engine = engine_with_threshold(1.5);
+        let response = engine.generate_quest(&request_json(1, &["Enochian"]).to_string());
+        let quest: GeneratedQuest = serde_json::from_str(&response).unwrap();
+        assert_eq!(quest.generation_attempts, 1 + default_max_generation_retries());
+        assert!(quest.below_authenticity_floor);
+        assert!(quest.authenticity_score > 0.0);
+
+        // Retries perturb the seed, never the ID derived from the requested one
+        assert_eq!(quest.quest_id, "quest_1_7");
+        assert!((0..=default_max_generation_retries()).any(|attempt| perturbed_seed(7, attempt) == quest.effective_seed));
+    }
+
+    #[test]
+    fn test_excessive_retries_clamped() {
+        let mut engine = EnochianStoryEngine::new();
+        let config = StoryEngineConfig {
+            authenticity_threshold: 1.5,
+            max_generation_retries: u32::MAX,
+            ..StoryEngineConfig::default()
+        };
+        let response = engine.initialize(&serde_json::to_string(&config).unwrap());
+        assert!(response.ends_with(&format!("clamped to {}", MAX_GENERATION_RETRIES)), "{}", response);
+
+        let quest: GeneratedQuest = serde_json::from_str(&engine.generate_quest(&request_json(1, &["Enochian"]).to_string())).unwrap();
+        assert_eq!(quest.generation_attempts, 1 + MAX_GENERATION_RETRIES);
+    }
+
+    #[test]
+    fn test_aethyr_tier_scales_choice_difficulty() {
+        let mut engine = initialized_engine();
+        let request = request_json(1, &["Enochian"]).to_string();
+        let quest: GeneratedQuest = serde_json::from_str(&engine.generate_quest(&request)).unwrap();
+        assert_eq!(quest.aethyr_tier.as_deref(), Some("Foundation"));
+        assert!(!quest.choice_branches.is_empty());
+
+        // Doubling the tier's modifier from the default 0.9 rescales every branch
+        let mut tiers = serde_json::to_value(enochian_cyphers::AethyrTiers::default()).unwrap();
+        tiers[2]["challenge_difficulty_modifier"] = serde_json::json!(1.8);
+        assert_eq!(engine.set_aethyr_tiers(&tiers.to_string()), "3 Aethyr tiers loaded");
+        let harder: GeneratedQuest = serde_json::from_str(&engine.generate_quest(&request)).unwrap();
+        for (choice, harder_choice) in quest.choice_branches.iter().zip(&harder.choice_branches) {
+            assert!((harder_choice.difficulty_modifier - choice.difficulty_modifier * 2.0).abs() < 1e-9);
+        }
+
+        // Tiers leaving an Aethyr uncovered are refused and the current ones kept
+        tiers[2]["last_aethyr"] = serde_json::json!(29);
+        assert!(engine.set_aethyr_tiers(&tiers.to_string()).starts_with("Aethyr tier parsing error"));
+        let unchanged: GeneratedQuest = serde_json::from_str(&engine.generate_quest(&request)).unwrap();
+        assert_eq!(unchanged.choice_branches[0].difficulty_modifier, harder.choice_branches[0].difficulty_modifier);
+    }
+}
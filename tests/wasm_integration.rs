@@ -0,0 +1,79 @@
+//! WASM integration tests for the browser-facing bindings
+
+use enochian_cyphers::core::SystemConfig;
+use enochian_cyphers::{AuthenticityScorer, EnochianWasm, WasmLiveScorer};
+use wasm_bindgen_test::*;
+
+fn quest_json(quest_id: &str) -> String {
+    serde_json::json!({
+        "quest_id": quest_id,
+        "title": "Invocation of ABRIOND",
+        "description": "Approach the governor of creation through Enochian invocation",
+        "objectives": ["Study the Enochian tablets"],
+        "wisdom_taught": "Divine creation",
+        "choice_branches": [],
+        "authenticity_score": 0.96,
+        "estimated_duration": 30,
+        "tradition_integration": ["Enochian"],
+        "governor_name": "ABRIOND",
+        "difficulty_level": 2,
+        "required_energy": 5,
+        "rewards": {
+            "experience": 10,
+            "reputation_changes": {},
+            "tradition_mastery_gains": {},
+            "governor_relationship_changes": {},
+            "bitcoin_rewards": 0,
+            "sacred_items": [],
+            "hypertoken_rewards": [],
+            "aethyr_access_gained": []
+        },
+        "created_at": "2024-01-01T00:00:00Z"
+    })
+    .to_string()
+}
+
+fn history_len(stats_json: &str) -> u64 {
+    let stats: serde_json::Value = serde_json::from_str(stats_json).unwrap();
+    stats["state_history"].as_u64().unwrap()
+}
+
+#[wasm_bindgen_test]
+fn test_compact_reduces_history() {
+    let config = SystemConfig { max_state_history: 16, ..SystemConfig::default() };
+    let mut engine = EnochianWasm::new();
+    engine.initialize(Some(serde_json::to_string(&config).unwrap())).unwrap();
+    engine.create_player("seeker".to_string()).unwrap();
+    engine.register_quest(quest_json("quest_1")).unwrap();
+    engine.start_quest("seeker".to_string(), "quest_1".to_string()).unwrap();
+    engine.complete_quest("seeker".to_string(), "quest_1".to_string()).unwrap();
+    
+    let before = history_len(&engine.memory_stats());
+    let after = history_len(&engine.compact());
+    
+    assert!(before > after);
+    assert_eq!(after, 1);
+}
+
+#[wasm_bindgen_test]
+fn test_live_scorer_matches_full_rescore() {
+    let mut live = WasmLiveScorer::new();
+    let scorer = AuthenticityScorer::new();
+    
+    let edits = [
+        r#"{"type": "append", "text": "In 1582 John Dee "}"#,
+        r#"{"type": "append", "text": "began scrying the Aethyr."}"#,
+        r#"{"type": "replace_range", "start": 0, "end": 8, "text": ""}"#,
+        r#"{"type": "replace_range", "start": 20, "end": 22, "text": "ry"}"#,
+        r#"{"type": "append", "text": " A spiritual practice of divine communion."}"#,
+    ];
+    
+    for edit in edits {
+        let score = live.update(edit.to_string()).unwrap();
+        let expected = scorer.quick_score(&live.content());
+        assert!((score - expected).abs() < 1e-9);
+        assert_eq!(score, live.current_score());
+    }
+    
+    assert!(live.update(r#"{"type": "replace_range", "start": 500, "end": 501, "text": ""}"#.to_string()).is_err());
+}